@@ -0,0 +1,152 @@
+//! Generates the embedded pattern lexicon from `data/lexicon.txt`.
+//!
+//! The generated file defines the `LEXICON` constant and is included by `src/lexicon.rs`, so
+//! named patterns are compiled into the library and never loaded at runtime.
+
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const LEXICON_SOURCE: &str = "data/lexicon.txt";
+
+const CATEGORIES: [&str; 5] = ["StillLife", "Oscillator", "Spaceship", "Methuselah", "Gun"];
+
+struct Entry {
+    name: String,
+    aliases: Vec<String>,
+    category: String,
+    description: String,
+    rle: String,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", LEXICON_SOURCE);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let source: String = fs::read_to_string(LEXICON_SOURCE)
+        .unwrap_or_else(|error| panic!("Could not read {}: {}", LEXICON_SOURCE, error));
+    let entries: Vec<Entry> = parse_lexicon(&source)
+        .unwrap_or_else(|error| panic!("Invalid {}: {}", LEXICON_SOURCE, error));
+
+    let output_path = Path::new(&env::var("OUT_DIR").unwrap()).join("lexicon.rs");
+    fs::write(&output_path, generate_lexicon(&entries))
+        .unwrap_or_else(|error| panic!("Could not write {}: {}", output_path.display(), error));
+}
+
+/// Parses the blank-line separated entries of the lexicon source.
+fn parse_lexicon(source: &str) -> Result<Vec<Entry>, String> {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut names: HashSet<String> = HashSet::new();
+    let mut fields: Vec<(usize, &str, &str)> = Vec::new();
+
+    let lines = source.lines().map(str::trim).chain(std::iter::once(""));
+    for (index, line) in lines.enumerate() {
+        if line.starts_with('#') {
+            continue;
+        }
+        if !line.is_empty() {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or(format!("line {} is not a \"key: value\" pair", index + 1))?;
+            fields.push((index + 1, key.trim(), value.trim()));
+            continue;
+        }
+        if fields.is_empty() {
+            continue;
+        }
+
+        let entry: Entry = parse_entry(&fields)?;
+        for name in std::iter::once(&entry.name).chain(entry.aliases.iter()) {
+            if !names.insert(name.to_lowercase()) {
+                return Err(format!("the name \"{}\" is used more than once", name));
+            }
+        }
+        entries.push(entry);
+        fields.clear();
+    }
+
+    Ok(entries)
+}
+
+/// Builds a single entry from its fields, checking that each one is present and well formed.
+fn parse_entry(fields: &[(usize, &str, &str)]) -> Result<Entry, String> {
+    let line: usize = fields[0].0;
+    let field = |key: &str| -> Result<String, String> {
+        fields
+            .iter()
+            .find(|(_, field_key, _)| *field_key == key)
+            .map(|(_, _, value)| value.to_string())
+            .ok_or(format!("the entry at line {} has no {}", line, key))
+    };
+
+    for (field_line, key, _) in fields {
+        if !["name", "aliases", "category", "description", "rle"].contains(key) {
+            return Err(format!(
+                "line {} has an unknown key \"{}\"",
+                field_line, key
+            ));
+        }
+    }
+
+    let name: String = field("name")?;
+    let aliases: Vec<String> = field("aliases")
+        .map(|aliases| {
+            aliases
+                .split(',')
+                .map(|alias| alias.trim().to_string())
+                .filter(|alias| !alias.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let category: String = field("category")?;
+    if !CATEGORIES.contains(&category.as_str()) {
+        return Err(format!(
+            "\"{}\" has an unknown category \"{}\"",
+            name, category
+        ));
+    }
+
+    let rle: String = field("rle")?;
+    let valid_rle: bool = rle.ends_with('!')
+        && rle
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, 'b' | 'o' | '$' | '!'));
+    if !valid_rle {
+        return Err(format!("\"{}\" has invalid RLE \"{}\"", name, rle));
+    }
+
+    Ok(Entry {
+        name,
+        aliases,
+        category,
+        description: field("description")?,
+        rle,
+    })
+}
+
+/// Writes the entries out as the Rust source of the `LEXICON` constant.
+fn generate_lexicon(entries: &[Entry]) -> String {
+    let mut output: String = String::new();
+    writeln!(output, "// Generated by build.rs from {}.", LEXICON_SOURCE).unwrap();
+    writeln!(output).unwrap();
+    writeln!(
+        output,
+        "/// Every pattern in the lexicon, grouped by category."
+    )
+    .unwrap();
+    writeln!(output, "pub(crate) const LEXICON: &[LexiconEntry] = &[").unwrap();
+    for entry in entries {
+        writeln!(output, "    LexiconEntry {{").unwrap();
+        writeln!(output, "        name: {:?},", entry.name).unwrap();
+        writeln!(output, "        aliases: &{:?},", entry.aliases).unwrap();
+        writeln!(output, "        category: {},", entry.category).unwrap();
+        writeln!(output, "        description: {:?},", entry.description).unwrap();
+        writeln!(output, "        rle: {:?},", entry.rle).unwrap();
+        writeln!(output, "    }},").unwrap();
+    }
+    writeln!(output, "];").unwrap();
+    output
+}
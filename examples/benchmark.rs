@@ -0,0 +1,220 @@
+use simple_game_of_life::simulation::Simulation;
+use simple_game_of_life::simulation_builder::SimulationBuilder;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+// For this example, we want to measure how fast the simulation advances a
+// large random soup, compared to counting neighbors with a hash set lookup
+// for every neighbor of every cell. Both are checked to give the same result,
+// first on the spaceships of the lexicon on every surface type (including the
+// Möbius strip and Klein bottle, where crossing the left or right edge flips the
+// spaceship upside down), then on the soup.
+// Then, a lone glider is timed on a small grid and a large one, since only the
+// cells near alive cells are evaluated when few cells are alive.
+// Finally, a blinker is simulated with a large save history, checking whether it
+// is finished after every generation, to show the time per generation stays the
+// same once the history is full.
+// Run it with `cargo run --release --example benchmark` for meaningful timings.
+
+const SOUP_SIZE: u16 = 200; // The number of rows and columns of the random soup
+const SOUP_GENERATIONS: u128 = 1000; // The number of generations to time
+const GLIDER_SIZES: [u16; 2] = [50, 250]; // The number of rows and columns of each glider grid
+const MAXIMUM_SAVES: u128 = 100_000; // The number of saves kept for the blinker
+const SPACESHIPS: [&str; 4] = [
+    "glider",
+    "lightweight spaceship",
+    "middleweight spaceship",
+    "heavyweight spaceship",
+];
+
+// Whether a surface wraps vertically and horizontally, and whether wrapping
+// horizontally flips the rows, with a name to print
+const SURFACES: [(&str, bool, bool, bool); 6] = [
+    ("rectangle", false, false, false),
+    ("ball", true, true, false),
+    ("horizontal loop", false, true, false),
+    ("vertical loop", true, false, false),
+    ("möbius strip", false, true, true),
+    ("klein bottle", true, true, true),
+];
+
+fn main() {
+    // Check the spaceships on every surface type, for long enough to cross the edges
+    for (surface, wraps_vertically, wraps_horizontally, flips) in SURFACES {
+        for spaceship in SPACESHIPS {
+            let mut simulation: Simulation = build(20, 20, surface)
+                .seed_named(spaceship) // Placing the spaceship from the lexicon
+                .build()
+                .unwrap();
+            let expected: HashSet<(u16, u16)> = hash_set_generations(
+                &simulation,
+                (20, wraps_vertically),
+                (20, wraps_horizontally, flips),
+                100,
+            );
+            simulation.simulate_generations(100);
+            assert_eq!(
+                alive_cells(&simulation),
+                expected,
+                "{} on a {}",
+                spaceship,
+                surface
+            );
+        }
+        println!("The spaceships match on the {} surface", surface);
+    }
+
+    // Time the random soup with both ways of counting neighbors
+    let mut simulation: Simulation = SimulationBuilder::new() // Create a new simulation via a builder
+        .height(SOUP_SIZE) // 200 rows high
+        .width(SOUP_SIZE) // 200 columns wide
+        .surface_ball() // Ball (fully wrapping) surface
+        .maximum_saves(1) // Only keep the last generation, the history is not needed here
+        .build() // Build into a simulation with a random seed
+        .unwrap();
+    let start: Instant = Instant::now();
+    let expected: HashSet<(u16, u16)> = hash_set_generations(
+        &simulation,
+        (SOUP_SIZE, true),
+        (SOUP_SIZE, true, false),
+        SOUP_GENERATIONS,
+    );
+    let hash_set_time: Duration = start.elapsed();
+    let start: Instant = Instant::now();
+    simulation.simulate_generations(SOUP_GENERATIONS);
+    let simulation_time: Duration = start.elapsed();
+    assert_eq!(alive_cells(&simulation), expected);
+
+    println!(
+        "{} generations of a {}x{} random soup:",
+        SOUP_GENERATIONS, SOUP_SIZE, SOUP_SIZE
+    );
+    println!("  hash set lookups: {:?}", hash_set_time);
+    println!("  simulation:       {:?}", simulation_time);
+    println!(
+        "  speedup:          {:.1}x",
+        hash_set_time.as_secs_f64() / simulation_time.as_secs_f64()
+    );
+
+    // Time a lone glider on grids of different sizes, which should take about as long
+    for size in GLIDER_SIZES {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(size)
+            .width(size)
+            .surface_ball() // Ball (fully wrapping) surface, so the glider never hits an edge
+            .seed_named("glider") // Placing a glider from the lexicon
+            .maximum_saves(1)
+            .build()
+            .unwrap();
+        let start: Instant = Instant::now();
+        simulation.simulate_generations(SOUP_GENERATIONS);
+        println!(
+            "{} generations of a glider on a {}x{} grid: {:?}",
+            SOUP_GENERATIONS,
+            size,
+            size,
+            start.elapsed()
+        );
+    }
+
+    // Time a blinker while its save history fills up, then while it is full
+    let mut simulation: Simulation = SimulationBuilder::new()
+        .height(5)
+        .width(5)
+        .seed_named("blinker") // Placing a blinker from the lexicon
+        .maximum_saves(MAXIMUM_SAVES)
+        .build()
+        .unwrap();
+    // The blinker is finished once it returns to its seed
+    simulation.simulate_generation();
+    simulation.simulate_generation();
+    for phase in ["filling", "full"] {
+        let start: Instant = Instant::now();
+        for _ in 0..MAXIMUM_SAVES {
+            simulation.simulate_generation();
+            assert!(simulation.is_finished());
+        }
+        println!(
+            "{} generations of a blinker with a {} history of {} saves: {:?}",
+            MAXIMUM_SAVES,
+            phase,
+            MAXIMUM_SAVES,
+            start.elapsed()
+        );
+    }
+}
+
+// Returns a builder for a simulation of the given size on the surface with the given name
+fn build(rows: u16, columns: u16, surface: &str) -> SimulationBuilder {
+    let builder: SimulationBuilder = SimulationBuilder::new().height(rows).width(columns);
+    match surface {
+        "rectangle" => builder.surface_rectangle(),
+        "ball" => builder.surface_ball(),
+        "horizontal loop" => builder.surface_horizontal_loop(),
+        "vertical loop" => builder.surface_vertical_loop(),
+        "möbius strip" => builder.surface_mobius(),
+        _ => builder.surface_klein_bottle(),
+    }
+}
+
+// Returns the positions of the alive cells in the current generation of a simulation
+fn alive_cells(simulation: &Simulation) -> HashSet<(u16, u16)> {
+    simulation.alive_cells_iter().collect()
+}
+
+// Advances the simulation's current generation by Conway's rules, looking up each of the
+// eight neighbors of every cell in a hash set, and returns the resulting alive cells.
+// The number of rows and columns is given along with whether they wrap, and whether
+// wrapping around the columns flips the rows.
+fn hash_set_generations(
+    simulation: &Simulation,
+    (rows, wraps_vertically): (u16, bool),
+    (columns, wraps_horizontally, flips): (u16, bool, bool),
+    generations: u128,
+) -> HashSet<(u16, u16)> {
+    let rows: i32 = rows as i32;
+    let columns: i32 = columns as i32;
+    let mut generation: HashSet<(u16, u16)> = alive_cells(simulation);
+    for _ in 0..generations {
+        let mut next_generation: HashSet<(u16, u16)> = HashSet::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                let mut alive_neighbors: u8 = 0;
+                for (row_offset, column_offset) in [
+                    (-1, -1),
+                    (-1, 0),
+                    (-1, 1),
+                    (0, -1),
+                    (0, 1),
+                    (1, -1),
+                    (1, 0),
+                    (1, 1),
+                ] {
+                    let mut neighbor_row: i32 = row + row_offset;
+                    let mut neighbor_column: i32 = column + column_offset;
+                    if wraps_vertically {
+                        neighbor_row = neighbor_row.rem_euclid(rows);
+                    }
+                    if wraps_horizontally {
+                        if flips && !(0..columns).contains(&neighbor_column) {
+                            neighbor_row = rows - 1 - neighbor_row;
+                        }
+                        neighbor_column = neighbor_column.rem_euclid(columns);
+                    }
+                    if (0..rows).contains(&neighbor_row)
+                        && (0..columns).contains(&neighbor_column)
+                        && generation.contains(&(neighbor_row as u16, neighbor_column as u16))
+                    {
+                        alive_neighbors += 1;
+                    }
+                }
+                let alive: bool = generation.contains(&(row as u16, column as u16));
+                if alive_neighbors == 3 || (alive && alive_neighbors == 2) {
+                    next_generation.insert((row as u16, column as u16));
+                }
+            }
+        }
+        generation = next_generation;
+    }
+    generation
+}
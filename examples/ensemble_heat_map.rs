@@ -0,0 +1,60 @@
+use rand::{thread_rng, Rng};
+use simple_game_of_life::ensemble::alive_probability;
+use simple_game_of_life::simulation::Simulation;
+use simple_game_of_life::simulation_builder::SimulationBuilder;
+
+// For this example, we want to see where an R-pentomino is likely
+// to be alive after 30 generations if its start is slightly perturbed.
+// Each run adds a single extra alive cell somewhere near the R-pentomino
+// and the results of every run are combined into a probability map.
+
+const ROWS: u16 = 30; // The number of rows in each simulation
+const COLUMNS: u16 = 30; // The number of columns in each simulation
+
+// The characters used to shade the probability map, from least to most likely to be alive
+const SHADES: [char; 10] = [' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+fn main() {
+    let probabilities: Vec<f64> = alive_probability(
+        |_run| {
+            // The R-pentomino, placed in the middle of the grid
+            let mut alive_cells: Vec<(u16, u16)> =
+                vec![(14, 15), (14, 16), (15, 14), (15, 15), (16, 15)];
+            // The perturbation, a single alive cell within 3 cells of the middle
+            let mut rng = thread_rng();
+            alive_cells.push((rng.gen_range(12..=18), rng.gen_range(12..=18)));
+            // Build the seed string from the alive cells
+            let seed: String = (0..ROWS * COLUMNS)
+                .map(|index| {
+                    if alive_cells.contains(&(index / COLUMNS, index % COLUMNS)) {
+                        '*'
+                    } else {
+                        '-'
+                    }
+                })
+                .collect();
+            let simulation: Simulation = SimulationBuilder::new() // Create a new simulation via a builder
+                .height(ROWS) // 30 rows high
+                .width(COLUMNS) // 30 columns wide
+                .surface_rectangle() // Rectangle (non-wrapping) surface
+                .seed(&seed) // Declaring the simulation's initial seed
+                .build() // Build into a simulation
+                .unwrap();
+            simulation
+        },
+        200, // Run the ensemble 200 times
+        30,  // Simulate 30 generations in each run
+    )
+    .unwrap();
+
+    // Print the probability map, shading each cell by how often it was alive
+    for row in 0..ROWS as usize {
+        let line: String = (0..COLUMNS as usize)
+            .map(|column| {
+                let probability: f64 = probabilities[row * COLUMNS as usize + column];
+                SHADES[((probability * (SHADES.len() - 1) as f64).round()) as usize]
+            })
+            .collect();
+        println!("{}", line);
+    }
+}
@@ -1,4 +1,4 @@
-use simple_game_of_life::simulation::Simulation;
+use simple_game_of_life::simulation::{Simulation, SimulationOutcome};
 use simple_game_of_life::simulation_builder::SimulationBuilder;
 use std::time::Duration;
 
@@ -6,6 +6,7 @@ use std::time::Duration;
 // longest-lasting simulation (the most generations without encountering any periodic state)
 // within a rectangle. We will call this seed the "fittest" seed. We also might be
 // interested in the relationship between "fitness" and the initial alive proportion.
+// The random seeds are generated from a fixed RNG seed, so the results are reproducible.
 
 fn main() {
     let mut fittest_seed: String; // The best or "fittest" seed
@@ -22,6 +23,8 @@ fn main() {
         .height(15) // 15 rows high
         .width(15) // 15 columns wide
         .surface_rectangle() // Rectangle (non-wrapping) surface
+        // Seeding the random number generator, so every run tries the same 1000 seeds
+        .rng_seed(15)
         .build() // Build into a simulation
         .unwrap();
 
@@ -30,10 +33,11 @@ fn main() {
         alive_count = simulation.alive_count(); // Set the alive count
         alive_proportion = simulation.alive_proportion(); // Set the alive proportion
                                                           // Simulate every generation until the simulation is finished
-        simulation.simulate_continuous_generations(Duration::ZERO, true);
+        let outcome: SimulationOutcome =
+            simulation.simulate_continuous_generations(Duration::ZERO, true, None);
         // If this simulation lasted for longer than the current fittest, make it the new fittest
-        if simulation.iteration() - 1 > fittest_generations {
-            fittest_generations = simulation.iteration() - 1; // Set the new best iteration count (minus the initial seed)
+        if outcome.iterations_run - 1 > fittest_generations {
+            fittest_generations = outcome.iterations_run - 1; // Set the new best iteration count (minus the initial seed)
             fittest_seed = simulation.seed(); // Set the new fittest seed
             println!(
                 "The new fittest seed has lasted for {} generations with an alive proportion of {} ({}/{}):\n{}",
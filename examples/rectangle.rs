@@ -18,7 +18,7 @@ fn main() {
         .unwrap();
 
     // Simulate a generation every 250 milliseconds until it is finished
-    simulation.simulate_continuous_generations(Duration::from_millis(250), true);
+    simulation.simulate_continuous_generations(Duration::from_millis(250), true, None);
 
     // Quit and close the window
     simulation.quit_window();
@@ -21,10 +21,11 @@ fn main() {
 
     // We will simulate and roll back generations to equal 15 simulations for simulation A
     // 15 = 3 - 2 + 10 - 4 + 8
+    // Every simulated generation is saved, so each rollback goes back the full amount
     simulation_a.simulate_generations(3); // +3
-    simulation_a.rollback_generations(2); // -2
+    assert_eq!(simulation_a.rollback_generations(2), 2); // -2
     simulation_a.simulate_generations(10); // +10
-    simulation_a.rollback_generations(4); // -4
+    assert_eq!(simulation_a.rollback_generations(4), 4); // -4
     simulation_a.simulate_generations(8); // +8
 
     // Simulate 15 iterations for B
@@ -35,4 +36,31 @@ fn main() {
         simulation_a.generation_string(),
         simulation_b.generation_string()
     );
+    assert_eq!(simulation_a.iteration(), simulation_b.iteration());
+
+    // Rolling back further than the seed stops at the seed
+    let generation: String = simulation_b.generation_string();
+    let seed: String = simulation_b.seed();
+    assert_eq!(simulation_b.rollback_generations(100), 15);
+    assert_eq!(simulation_b.generation_string(), seed);
+    assert_eq!(simulation_b.rollback_generations(1), 0);
+
+    // Resetting clears the save history, so there is nothing to roll back to
+    simulation_a.reset();
+    assert_eq!(simulation_a.rollback_generations(5), 0);
+    assert_eq!(simulation_a.iteration(), 0);
+
+    // A simulation without a save history can be simulated, but not rolled back
+    let mut simulation_c: Simulation = SimulationBuilder::new()
+        .height(5)
+        .width(5)
+        .surface_rectangle()
+        .seed("-*-***--*--*-*-***-*-*-*-")
+        .maximum_saves(0) // Keep no saves
+        .build()
+        .unwrap();
+    simulation_c.simulate_generations(15);
+    assert_eq!(simulation_c.generation_string(), generation);
+    assert_eq!(simulation_c.rollback_generations(3), 0);
+    assert_eq!(simulation_c.iteration(), 15);
 }
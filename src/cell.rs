@@ -1,7 +1,10 @@
-use crate::cell::CellState::{ALIVE, DEAD};
+//! The cells that make up a generation of a `Simulation`.
+
+use crate::cell::CellState::ALIVE;
 
 /// Represents the state of a cell.
 #[derive(Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum CellState {
     /// A dead cell.
     DEAD,
@@ -11,6 +14,7 @@ pub(crate) enum CellState {
 
 /// Represents a single cell in a `Simulation`.
 #[derive(Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cell {
     /// The state of the cell (alive or dead).
     pub(crate) state: CellState,
@@ -29,23 +33,25 @@ pub const DEAD_CHAR: char = '-';
 
 impl Cell {
     /// Returns true if the cell is alive, false otherwise.
-    pub(crate) fn is_alive(&self) -> bool {
+    pub fn is_alive(&self) -> bool {
         if self.state == ALIVE {
             return true;
         }
         return false;
     }
 
-    /// Returns the character representation of the cell's state.
-    pub(crate) fn as_char(&self) -> char {
-        match self.state.clone() {
-            ALIVE => ALIVE_CHAR,
-            DEAD => DEAD_CHAR,
-        }
-    }
-
     /// Creates a new `Cell` instance with the given state, row, and column.
     pub(crate) fn new(state: CellState, row: u16, column: u16) -> Cell {
         Cell { state, row, column }
     }
+
+    /// Returns the row index of the cell.
+    pub fn row(&self) -> u16 {
+        self.row
+    }
+
+    /// Returns the column index of the cell.
+    pub fn column(&self) -> u16 {
+        self.column
+    }
 }
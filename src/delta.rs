@@ -0,0 +1,128 @@
+//! The cells that changed between one generation and the next.
+//!
+//! Each generation simulated records which cells were born and which died, so anything that
+//! mirrors a simulation (such as an external renderer) can update only the cells that changed,
+//! and replay the changes on another simulation with `apply_delta`.
+
+use std::collections::HashSet;
+
+use crate::cell::Cell;
+use crate::cell::CellState::ALIVE;
+use crate::simulation::Simulation;
+
+/// The cells born and the cells that died between two generations.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenerationDelta {
+    /// The `(row, column)` positions of the cells that were born, in ascending (row-major)
+    /// order.
+    pub born: Vec<(u16, u16)>,
+    /// The `(row, column)` positions of the cells that died, in ascending (row-major) order.
+    pub died: Vec<(u16, u16)>,
+}
+
+impl GenerationDelta {
+    /// Finds the cells born and the cells that died going from one generation to another.
+    pub(crate) fn between(
+        old_generation: &HashSet<Cell>,
+        new_generation: &HashSet<Cell>,
+    ) -> GenerationDelta {
+        let mut born: Vec<(u16, u16)> = new_generation
+            .difference(old_generation)
+            .map(|cell| (cell.row, cell.column))
+            .collect();
+        let mut died: Vec<(u16, u16)> = old_generation
+            .difference(new_generation)
+            .map(|cell| (cell.row, cell.column))
+            .collect();
+        born.sort_unstable();
+        died.sort_unstable();
+        GenerationDelta { born, died }
+    }
+
+    /// Returns true if no cells were born or died.
+    pub fn is_empty(&self) -> bool {
+        self.born.is_empty() && self.died.is_empty()
+    }
+}
+
+impl Simulation {
+    /// Returns the cells born and the cells that died in the most recently simulated generation.
+    ///
+    /// # Note
+    /// The delta is empty before the first generation is simulated, and after the simulation
+    /// is reset or rolled back. Cells changed by editing the generation directly (such as with
+    /// `set_cell_alive`) are not included.
+    pub fn generation_delta(&self) -> &GenerationDelta {
+        &self.generation_delta
+    }
+
+    /// Advances the simulation by one generation by applying the given changes, without
+    /// evaluating the simulation's rule.
+    ///
+    /// # Description
+    /// This replays a delta recorded with `generation_delta`, usually from another simulation,
+    /// as if the generation had been simulated with `simulate_generation`. The current
+    /// generation is saved to the save history, the cells that died are removed, the cells that
+    /// were born are added, and the iteration counter and statistics are updated. The delta
+    /// becomes the simulation's `generation_delta`.
+    ///
+    /// Every position is checked before any cell is changed, so either the whole delta is
+    /// applied or none of it is. If the simulation is set to display in a window or print to
+    /// the console, the new generation is drawn or printed afterwards.
+    ///
+    /// # Arguments
+    /// * `delta` - The cells born and the cells that died.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the delta was applied.
+    /// * `Err(String)` - An error message if any of the positions is outside of the grid.
+    ///
+    /// # Note
+    /// Boundary effects are not tracked for applied deltas, since they depend on the rule.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .seed_named("glider")
+    ///     .build()
+    ///     .unwrap();
+    /// let mut mirror: Simulation = simulation.clone();
+    ///
+    /// for _ in 0..8 {
+    ///     simulation.simulate_generation();
+    ///     mirror.apply_delta(simulation.generation_delta()).unwrap();
+    /// }
+    /// assert_eq!(mirror.generation_string(), simulation.generation_string());
+    /// ```
+    pub fn apply_delta(&mut self, delta: &GenerationDelta) -> Result<(), String> {
+        for (row, column) in delta.born.iter().chain(&delta.died) {
+            self.check_bounds(*row, *column)?;
+        }
+        let mut new_generation: HashSet<Cell> = self.generation.clone();
+        for (row, column) in &delta.died {
+            new_generation.remove(&Cell::new(ALIVE, *row, *column));
+        }
+        for (row, column) in &delta.born {
+            new_generation.insert(Cell::new(ALIVE, *row, *column));
+        }
+        self.save_generation();
+        self.statistics
+            .record(&self.generation, &new_generation, self.columns);
+        self.generation = new_generation;
+        self.generation_delta = delta.clone();
+        self.iteration += 1;
+        if self.display {
+            self.draw_generation()
+        }
+        if self.print {
+            println!("{}", self)
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+//! Ensemble statistics gathered from many runs of similar simulations.
+//!
+//! # Example
+//! ```rust,no_run
+//! use simple_game_of_life::ensemble::alive_probability;
+//! use simple_game_of_life::simulation_builder::SimulationBuilder;
+//!
+//! // Run 100 random 20x20 soups for 50 generations each
+//! let probabilities: Vec<f64> = alive_probability(
+//!     |_run| {
+//!         SimulationBuilder::new()
+//!             .height(20)
+//!             .width(20)
+//!             .surface_ball()
+//!             .build()
+//!             .unwrap()
+//!     },
+//!     100,
+//!     50,
+//! )
+//! .unwrap();
+//!
+//! // The probability that the top-left cell is alive after 50 generations
+//! println!("{}", probabilities[0]);
+//! ```
+
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::simulation::Simulation;
+
+/// The dimensions of a run's grid, along with the alive cells of its final generation.
+type RunOutcome = ((u16, u16), Vec<(u16, u16)>);
+
+/// Estimates, for every cell of the grid, the probability that it is alive after the given
+/// number of generations.
+///
+/// # Description
+/// This function runs an ensemble of simulations, each created by the `seed_generator`
+/// closure, and simulates every one of them for the given number of generations. The
+/// generator receives the index of the run, so it can vary the random seed or apply a small
+/// perturbation to a known starting pattern for each run.
+///
+/// For each cell, the fraction of runs in which that cell was alive in the final generation is
+/// recorded, which acts as an ensemble forecast heat map of where the simulation is likely to
+/// be alive.
+///
+/// With the `parallel` feature, the runs are created and simulated in parallel on Rayon's
+/// thread pool, which is why the generator must be `Sync`.
+///
+/// # Arguments
+/// * `seed_generator` - A closure that creates the simulation for the run with the given index.
+/// * `runs` - The number of simulations in the ensemble.
+/// * `generations` - The number of generations to simulate in each run.
+///
+/// # Returns
+/// * `Ok(Vec<f64>)` - The alive probability of every cell in row-major order, indexed by
+///   `row * columns + column`. The `Vec` is empty if `runs` is 0.
+/// * `Err(String)` - An error message if the generated simulations do not all have the same
+///   dimensions.
+pub fn alive_probability<F: Fn(usize) -> Simulation + Sync>(
+    seed_generator: F,
+    runs: usize,
+    generations: u128,
+) -> Result<Vec<f64>, String> {
+    let simulate_run = |run: usize| -> RunOutcome {
+        let mut simulation: Simulation = seed_generator(run);
+        simulation.simulate_generations(generations);
+        (
+            (simulation.rows, simulation.columns),
+            simulation
+                .generation
+                .iter()
+                .map(|cell| (cell.row, cell.column))
+                .collect(),
+        )
+    };
+    #[cfg(feature = "parallel")]
+    let outcomes: Vec<RunOutcome> = (0..runs).into_par_iter().map(simulate_run).collect();
+    #[cfg(not(feature = "parallel"))]
+    let outcomes: Vec<RunOutcome> = (0..runs).map(simulate_run).collect();
+
+    let mut dimensions: Option<(u16, u16)> = None;
+    let mut alive_runs: Vec<u64> = Vec::new();
+    for (run, ((run_rows, run_columns), alive_cells)) in outcomes.into_iter().enumerate() {
+        match dimensions {
+            None => {
+                dimensions = Some((run_rows, run_columns));
+                alive_runs = vec![0; run_rows as usize * run_columns as usize];
+            }
+            Some((rows, columns)) => {
+                if rows != run_rows || columns != run_columns {
+                    return Err(format!(
+                        "Every simulation in an ensemble must have the same dimensions, expected {}x{} but run {} was {}x{}",
+                        rows, columns, run, run_rows, run_columns
+                    ));
+                }
+            }
+        }
+        for (row, column) in alive_cells {
+            alive_runs[row as usize * run_columns as usize + column as usize] += 1;
+        }
+    }
+    Ok(alive_runs
+        .into_iter()
+        .map(|alive_count| alive_count as f64 / runs as f64)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation_builder::SimulationBuilder;
+
+    fn build(rows: u16, columns: u16, seed: &str) -> Simulation {
+        SimulationBuilder::new()
+            .height(rows)
+            .width(columns)
+            .seed(seed)
+            .display(false)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn identical_runs_are_certain() {
+        // A blinker that is vertical after one generation
+        let probabilities: Vec<f64> =
+            alive_probability(|_run| build(3, 3, "---***---"), 8, 1).unwrap();
+        assert_eq!(
+            probabilities,
+            vec![0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn probabilities_are_the_fraction_of_runs() {
+        // Every fourth run is a block, which survives, and the rest are empty
+        let probabilities: Vec<f64> = alive_probability(
+            |run| {
+                if run % 4 == 0 {
+                    build(2, 2, "****")
+                } else {
+                    build(2, 2, "----")
+                }
+            },
+            8,
+            5,
+        )
+        .unwrap();
+        assert_eq!(probabilities, vec![0.25; 4]);
+    }
+
+    #[test]
+    fn runs_must_have_the_same_dimensions() {
+        let result: Result<Vec<f64>, String> = alive_probability(
+            |run| {
+                if run == 2 {
+                    build(3, 2, "------")
+                } else {
+                    build(2, 3, "------")
+                }
+            },
+            4,
+            1,
+        );
+        assert!(result.unwrap_err().contains("run 2 was 3x2"));
+    }
+
+    #[test]
+    fn no_runs_is_empty() {
+        assert!(alive_probability(|_run| build(2, 2, "----"), 0, 1)
+            .unwrap()
+            .is_empty());
+    }
+}
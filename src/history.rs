@@ -0,0 +1,506 @@
+//! The save history of previous generations.
+//!
+//! Simulations can keep a very large number of saves, so the history is a ring buffer (saving
+//! past the maximum drops the oldest save without moving the others), and every save is indexed
+//! by a hash of its cells. Finding the saves that match a generation then only compares the
+//! generation to the saves with the same hash, instead of to every save.
+//!
+//! Most generations differ from the one before them by only a few cells, so most saves only
+//! store the cells that were born and died since the previous save (a `GenerationDiff`). Every
+//! `KEYFRAME_INTERVAL` saves, and whenever a diff would be larger than the generation itself, the
+//! full generation is stored instead (a keyframe). A saved generation is rebuilt from the
+//! nearest keyframe before it, or from the most recent save, whichever is closer.
+//!
+//! Generations replaced by an edit (such as a flip) are saved too, so the edit can be rolled
+//! back, but they are marked as edits. The edited generation does not follow from them by the
+//! simulation's rule, so saves up to the most recent edit are never matched when detecting
+//! cycles.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::mem::size_of;
+
+use crate::cell::Cell;
+
+/// The largest number of diffs stored in a row before a full generation is stored again, which
+/// bounds the number of diffs applied to rebuild a saved generation.
+const KEYFRAME_INTERVAL: usize = 64;
+
+/// The cells born and the cells that died between a saved generation and the save before it.
+#[derive(Clone)]
+pub(crate) struct GenerationDiff {
+    /// The cells that were born.
+    born: Vec<Cell>,
+    /// The cells that died.
+    died: Vec<Cell>,
+}
+
+impl GenerationDiff {
+    /// Finds the cells born and the cells that died going from one generation to another.
+    fn between(old_generation: &HashSet<Cell>, new_generation: &HashSet<Cell>) -> GenerationDiff {
+        GenerationDiff {
+            born: new_generation.difference(old_generation).cloned().collect(),
+            died: old_generation.difference(new_generation).cloned().collect(),
+        }
+    }
+
+    /// Returns the number of cells that changed.
+    fn len(&self) -> usize {
+        self.born.len() + self.died.len()
+    }
+
+    /// Turns the generation the diff was found from into the generation after it.
+    fn apply(&self, generation: &mut HashSet<Cell>) {
+        for cell in &self.died {
+            generation.remove(cell);
+        }
+        generation.extend(self.born.iter().cloned());
+    }
+
+    /// Turns the generation after the diff back into the generation it was found from.
+    fn revert(&self, generation: &mut HashSet<Cell>) {
+        for cell in &self.born {
+            generation.remove(cell);
+        }
+        generation.extend(self.died.iter().cloned());
+    }
+}
+
+/// A single save in the history.
+#[derive(Clone)]
+enum Save {
+    /// The full generation.
+    Keyframe(HashSet<Cell>),
+    /// The changes from the save before it.
+    Diff(GenerationDiff),
+}
+
+/// The previous generations of a simulation, from oldest to most recent.
+#[derive(Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<HashSet<Cell>>", into = "Vec<HashSet<Cell>>")
+)]
+pub(crate) struct SaveHistory {
+    /// The saves, from oldest to most recent. The oldest save is always a keyframe.
+    saves: VecDeque<Save>,
+    /// The most recently saved generation in full if the most recent save is a diff, so that
+    /// saving only needs to compare the new generation against it. It is empty otherwise, since
+    /// the most recent save is then the full generation.
+    latest: HashSet<Cell>,
+    /// The `generation_hash` and `translated_generation_hash` of each saved generation.
+    hashes: VecDeque<(u64, u64)>,
+    /// The sequence numbers of the saves with each `generation_hash`, in ascending order.
+    index: HashMap<u64, VecDeque<u64>>,
+    /// The sequence numbers of the saves with each `translated_generation_hash`, in ascending
+    /// order.
+    translated_index: HashMap<u64, VecDeque<u64>>,
+    /// The sequence number of the oldest save. Each save is numbered one higher than the save
+    /// before it, so the position of a save is its sequence number minus this number.
+    first_sequence: u64,
+    /// The sequence numbers of the saves of generations that were replaced by an edit, in
+    /// ascending order. These are not serialized, so deserialized saves are never edits.
+    edits: VecDeque<u64>,
+}
+
+impl SaveHistory {
+    /// Returns the number of saved generations.
+    pub(crate) fn len(&self) -> usize {
+        self.saves.len()
+    }
+
+    /// Returns the most recently saved generation, if any.
+    pub(crate) fn last(&self) -> Option<&HashSet<Cell>> {
+        match self.saves.back()? {
+            Save::Keyframe(keyframe) => Some(keyframe),
+            Save::Diff(_) => Some(&self.latest),
+        }
+    }
+
+    /// Returns the saved generation at the given position, where 0 is the oldest save.
+    ///
+    /// # Note
+    /// The generation is rebuilt from the keyframe before it, or by reverting the diffs since
+    /// it from the most recent save if that is closer and no keyframe is in between, so at most
+    /// `KEYFRAME_INTERVAL` diffs are applied.
+    ///
+    /// # Panics
+    /// Panics if the position is not less than `len`.
+    pub(crate) fn get(&self, index: usize) -> HashSet<Cell> {
+        let keyframe_index: usize = self.keyframe_before(index);
+        let from_latest: usize = self.saves.len() - 1 - index;
+        let later_saves = || self.saves.range(index + 1..).rev();
+        if from_latest < index - keyframe_index
+            && later_saves().all(|save| matches!(save, Save::Diff(_)))
+        {
+            let mut generation: HashSet<Cell> = self.latest.clone();
+            // The most recent save is a diff, so the latest generation is kept in full
+            for save in later_saves() {
+                if let Save::Diff(diff) = save {
+                    diff.revert(&mut generation);
+                }
+            }
+            generation
+        } else {
+            self.rebuild_from_keyframe(keyframe_index, index)
+        }
+    }
+
+    /// Returns true if the saved generation at the given position is equal to the given
+    /// generation, only rebuilding it if their hashes match.
+    pub(crate) fn matches(&self, index: usize, generation: &HashSet<Cell>) -> bool {
+        self.hashes[index].0 == generation_hash(generation) && self.get(index) == *generation
+    }
+
+    /// Returns an iterator over the saved generations, from oldest to most recent.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = HashSet<Cell>> + '_ {
+        let mut generation: HashSet<Cell> = HashSet::new();
+        self.saves.iter().map(move |save| {
+            match save {
+                Save::Keyframe(keyframe) => generation = keyframe.clone(),
+                Save::Diff(diff) => diff.apply(&mut generation),
+            }
+            generation.clone()
+        })
+    }
+
+    /// Saves a generation as the most recent one, dropping the oldest saves so that at most
+    /// `maximum_saves` generations are kept. Nothing is saved if `maximum_saves` is 0.
+    pub(crate) fn push(&mut self, generation: HashSet<Cell>, maximum_saves: usize) {
+        if maximum_saves == 0 {
+            return;
+        }
+        while self.saves.len() >= maximum_saves {
+            self.pop_front();
+        }
+        let sequence: u64 = self.first_sequence + self.saves.len() as u64;
+        let hashes: (u64, u64) = (
+            generation_hash(&generation),
+            translated_generation_hash(&generation),
+        );
+        self.index.entry(hashes.0).or_default().push_back(sequence);
+        self.translated_index
+            .entry(hashes.1)
+            .or_default()
+            .push_back(sequence);
+        let diff: Option<GenerationDiff> = match self.last() {
+            Some(latest) if self.diffs_since_keyframe() < KEYFRAME_INTERVAL => {
+                Some(GenerationDiff::between(latest, &generation))
+            }
+            _ => None,
+        };
+        match diff {
+            Some(diff) if diff.len() < generation.len() => {
+                self.saves.push_back(Save::Diff(diff));
+                self.latest = generation;
+            }
+            _ => {
+                self.saves.push_back(Save::Keyframe(generation));
+                self.latest = HashSet::new();
+            }
+        }
+        self.hashes.push_back(hashes);
+    }
+
+    /// Saves a generation that is about to be replaced by an edit as the most recent one (see
+    /// `push`), marking it as an edit.
+    pub(crate) fn push_edit(&mut self, generation: HashSet<Cell>, maximum_saves: usize) {
+        if maximum_saves == 0 {
+            return;
+        }
+        self.push(generation, maximum_saves);
+        self.edits
+            .push_back(self.first_sequence + self.saves.len() as u64 - 1);
+    }
+
+    /// Returns true if the most recent save is of a generation that was replaced by an edit.
+    pub(crate) fn last_is_edit(&self) -> bool {
+        !self.saves.is_empty()
+            && self.edits.back() == Some(&(self.first_sequence + self.saves.len() as u64 - 1))
+    }
+
+    /// Returns the position of the oldest save made after the most recent edit, which is where
+    /// the generations that followed from each other by the simulation's rule start.
+    pub(crate) fn trajectory_start(&self) -> usize {
+        self.edits
+            .back()
+            .map_or(0, |sequence| (sequence - self.first_sequence) as usize + 1)
+    }
+
+    /// Drops the oldest saves so that at most `maximum_saves` generations are kept.
+    pub(crate) fn truncate(&mut self, maximum_saves: usize) {
+        while self.saves.len() > maximum_saves {
+            self.pop_front();
+        }
+    }
+
+    /// Removes and returns the most recently saved generation, if any.
+    pub(crate) fn pop(&mut self) -> Option<HashSet<Cell>> {
+        if self.last_is_edit() {
+            self.edits.pop_back();
+        }
+        let (hash, translated_hash) = self.hashes.pop_back()?;
+        // The most recent save has the highest sequence number, so it is last in both lists
+        remove_sequence(&mut self.index, hash, VecDeque::pop_back);
+        remove_sequence(
+            &mut self.translated_index,
+            translated_hash,
+            VecDeque::pop_back,
+        );
+        let generation: HashSet<Cell> = match self.saves.pop_back()? {
+            Save::Keyframe(keyframe) => keyframe,
+            Save::Diff(diff) => {
+                let generation: HashSet<Cell> = self.latest.clone();
+                diff.revert(&mut self.latest);
+                generation
+            }
+        };
+        // Keep the latest generation in full only if the new most recent save is a diff
+        match self.saves.back() {
+            Some(Save::Diff(_)) if self.latest.is_empty() => {
+                let index: usize = self.saves.len() - 1;
+                self.latest = self.rebuild_from_keyframe(self.keyframe_before(index), index);
+            }
+            Some(Save::Diff(_)) => {}
+            _ => self.latest = HashSet::new(),
+        }
+        Some(generation)
+    }
+
+    /// Returns an estimate of the number of bytes of memory used by the saves, including the
+    /// hashes used to find them.
+    pub(crate) fn memory_estimate(&self) -> usize {
+        let set_bytes =
+            |generation: &HashSet<Cell>| generation.capacity() * (size_of::<Cell>() + 1);
+        let saves_bytes: usize = self
+            .saves
+            .iter()
+            .map(|save| match save {
+                Save::Keyframe(keyframe) => set_bytes(keyframe),
+                Save::Diff(diff) => {
+                    (diff.born.capacity() + diff.died.capacity()) * size_of::<Cell>()
+                }
+            })
+            .sum();
+        // Each save also has a slot in the list of saves, its hashes, and a sequence number in
+        // both indexes
+        let per_save_bytes: usize =
+            size_of::<Save>() + size_of::<(u64, u64)>() + 2 * size_of::<u64>();
+        saves_bytes + set_bytes(&self.latest) + self.saves.len() * per_save_bytes
+    }
+
+    /// Removes the oldest saved generation.
+    fn pop_front(&mut self) {
+        if let Some((hash, translated_hash)) = self.hashes.pop_front() {
+            // The oldest save has the lowest sequence number, so it is first in both lists
+            remove_sequence(&mut self.index, hash, VecDeque::pop_front);
+            remove_sequence(
+                &mut self.translated_index,
+                translated_hash,
+                VecDeque::pop_front,
+            );
+            // The oldest save must be a keyframe, so the diff after it becomes one
+            if let Some(Save::Keyframe(mut generation)) = self.saves.pop_front() {
+                if let Some(Save::Diff(diff)) = self.saves.front() {
+                    diff.apply(&mut generation);
+                    self.saves[0] = Save::Keyframe(generation);
+                }
+            }
+            if self.saves.len() <= 1 {
+                self.latest = HashSet::new();
+            }
+            if self.edits.front() == Some(&self.first_sequence) {
+                self.edits.pop_front();
+            }
+            self.first_sequence += 1;
+        }
+    }
+
+    /// Returns the position of the closest keyframe at or before the given position.
+    fn keyframe_before(&self, index: usize) -> usize {
+        (0..=index)
+            .rev()
+            .find(|&index| matches!(self.saves[index], Save::Keyframe(_)))
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of diffs saved since the most recent keyframe.
+    fn diffs_since_keyframe(&self) -> usize {
+        self.saves
+            .iter()
+            .rev()
+            .take_while(|save| matches!(save, Save::Diff(_)))
+            .count()
+    }
+
+    /// Rebuilds the saved generation at the given position by applying the diffs after the
+    /// keyframe at the given position.
+    fn rebuild_from_keyframe(&self, keyframe_index: usize, index: usize) -> HashSet<Cell> {
+        let mut generation: HashSet<Cell> = match &self.saves[keyframe_index] {
+            Save::Keyframe(keyframe) => keyframe.clone(),
+            Save::Diff(_) => unreachable!("the save is not a keyframe"),
+        };
+        for save in self.saves.range(keyframe_index + 1..=index) {
+            if let Save::Diff(diff) = save {
+                diff.apply(&mut generation);
+            }
+        }
+        generation
+    }
+
+    /// Returns the positions of the saves that may match the given generation, from most recent
+    /// to oldest.
+    ///
+    /// # Description
+    /// The saves are found by hash, so every save equal to the generation is included, but a
+    /// save with the same hash that is not equal may be included too. Callers must compare each
+    /// save to the generation. Saves up to the most recent edit are never included (see
+    /// `trajectory_start`).
+    ///
+    /// # Arguments
+    /// * `generation` - The generation to find saves for.
+    /// * `translated` - Whether saves that are translated copies of the generation should be
+    ///   included, along with the saves equal to it.
+    pub(crate) fn candidates(
+        &self,
+        generation: &HashSet<Cell>,
+        translated: bool,
+    ) -> impl Iterator<Item = usize> + '_ {
+        let sequences: Option<&VecDeque<u64>> = if translated {
+            self.translated_index
+                .get(&translated_generation_hash(generation))
+        } else {
+            self.index.get(&generation_hash(generation))
+        };
+        let trajectory_start: usize = self.trajectory_start();
+        sequences
+            .into_iter()
+            .flat_map(|sequences| sequences.iter().rev())
+            .map(|sequence| (sequence - self.first_sequence) as usize)
+            .take_while(move |&index| index >= trajectory_start)
+    }
+}
+
+impl From<Vec<HashSet<Cell>>> for SaveHistory {
+    /// Creates a save history from a list of generations, from oldest to most recent.
+    fn from(generations: Vec<HashSet<Cell>>) -> SaveHistory {
+        let mut history: SaveHistory = SaveHistory::default();
+        for generation in generations {
+            history.push(generation, usize::MAX);
+        }
+        history
+    }
+}
+
+impl From<SaveHistory> for Vec<HashSet<Cell>> {
+    /// Lists the generations of a save history, from oldest to most recent.
+    fn from(history: SaveHistory) -> Vec<HashSet<Cell>> {
+        history.iter().collect()
+    }
+}
+
+/// Removes a sequence number from the list of the given hash with the given function, and
+/// removes the list once it is empty.
+fn remove_sequence<F: FnOnce(&mut VecDeque<u64>) -> Option<u64>>(
+    index: &mut HashMap<u64, VecDeque<u64>>,
+    hash: u64,
+    remove: F,
+) {
+    if let Some(sequences) = index.get_mut(&hash) {
+        remove(sequences);
+        if sequences.is_empty() {
+            index.remove(&hash);
+        }
+    }
+}
+
+/// Returns a hash of the alive cells of a generation, which does not depend on the order the
+/// cells are stored in.
+fn generation_hash(generation: &HashSet<Cell>) -> u64 {
+    generation.iter().fold(0, |hash, cell| {
+        hash.wrapping_add(cell_hash(cell.row, cell.column))
+    })
+}
+
+/// Returns a hash of the alive cells of a generation that is the same for every translated
+/// copy of the generation, since the cells are hashed relative to its bounding box.
+fn translated_generation_hash(generation: &HashSet<Cell>) -> u64 {
+    let minimum_row: u16 = generation.iter().map(|cell| cell.row).min().unwrap_or(0);
+    let minimum_column: u16 = generation.iter().map(|cell| cell.column).min().unwrap_or(0);
+    generation.iter().fold(0, |hash, cell| {
+        hash.wrapping_add(cell_hash(
+            cell.row - minimum_row,
+            cell.column - minimum_column,
+        ))
+    })
+}
+
+/// Mixes the position of a cell into a 64-bit hash (the SplitMix64 finalizer), so that the
+/// sums of the hashes of different generations rarely collide.
+fn cell_hash(row: u16, column: u16) -> u64 {
+    let mut hash: u64 = ((row as u64) << 16 | column as u64).wrapping_add(0x9e3779b97f4a7c15);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d049bb133111eb);
+    hash ^ (hash >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::CellState::ALIVE;
+
+    /// Returns a list of generations that each differ from the one before them by a few cells,
+    /// long enough to need more than one keyframe.
+    fn generations(count: usize) -> Vec<HashSet<Cell>> {
+        (0..count)
+            .map(|index| {
+                (0..6)
+                    .map(|cell| Cell::new(ALIVE, cell, ((index / (cell as usize + 1)) % 9) as u16))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn truncate_drops_the_oldest_saves() {
+        let generations: Vec<HashSet<Cell>> = generations(3 * KEYFRAME_INTERVAL);
+        let mut history: SaveHistory = SaveHistory::from(generations.clone());
+        history.truncate(100);
+        assert_eq!(history.len(), 100);
+        let kept: &[HashSet<Cell>] = &generations[generations.len() - 100..];
+        for (index, generation) in kept.iter().enumerate() {
+            assert!(history.get(index) == *generation, "save {}", index);
+            assert!(history.matches(index, generation));
+        }
+        assert!(history.iter().eq(kept.iter().cloned()));
+
+        // The dropped saves can no longer be found by their hash
+        let dropped: &HashSet<Cell> = &generations[0];
+        assert!(history
+            .candidates(dropped, false)
+            .all(|index| history.get(index) != *dropped));
+    }
+
+    #[test]
+    fn truncate_to_more_saves_than_are_kept_changes_nothing() {
+        let generations: Vec<HashSet<Cell>> = generations(80);
+        let mut history: SaveHistory = SaveHistory::from(generations.clone());
+        history.truncate(80);
+        history.truncate(usize::MAX);
+        assert_eq!(history.len(), 80);
+        assert!(history.iter().eq(generations.iter().cloned()));
+    }
+
+    #[test]
+    fn truncated_saves_are_popped_from_newest_to_oldest() {
+        let generations: Vec<HashSet<Cell>> = generations(2 * KEYFRAME_INTERVAL);
+        let mut history: SaveHistory = SaveHistory::from(generations.clone());
+        history.truncate(KEYFRAME_INTERVAL + 3);
+        for generation in generations.iter().rev().take(KEYFRAME_INTERVAL + 3) {
+            assert!(history.pop().as_ref() == Some(generation));
+        }
+        assert!(history.pop().is_none());
+        history.truncate(0);
+        assert_eq!(history.len(), 0);
+    }
+}
@@ -0,0 +1,274 @@
+//! Iterating over the generations of a simulation.
+//!
+//! A `Simulation` is an iterator over the string representations of its generations, so it
+//! can be used with iterator adapters such as `take`, `map`, and `for_each`. Each call to `next`
+//! simulates one generation, so the seed is not yielded. It can be read beforehand with
+//! `generation_string`.
+//!
+//! # Example
+//! ```rust
+//! use simple_game_of_life::simulation::Simulation;
+//! use simple_game_of_life::simulation_builder::SimulationBuilder;
+//!
+//! let simulation: Simulation = SimulationBuilder::new()
+//!     .height(5)
+//!     .width(5)
+//!     .seed_named("blinker")
+//!     .build()
+//!     .unwrap();
+//!
+//! let seed: String = simulation.generation_string();
+//! // The blinker returns to its seed after 2 generations, which finishes the simulation
+//! let generations: Vec<String> = simulation.into_generation_iter(true).collect();
+//! assert_eq!(generations.len(), 2);
+//! assert_eq!(generations[1], seed);
+//! ```
+//!
+//! To keep ownership of the simulation, `generations` borrows it instead and yields a
+//! `GenerationSnapshot` of each generation.
+
+use crate::simulation::Simulation;
+
+impl Iterator for Simulation {
+    type Item = String;
+
+    /// Simulates one generation with `simulate_generation` and returns its
+    /// `generation_string`.
+    ///
+    /// # Note
+    /// This never returns `None`, so the simulation is an endless iterator. Use adapters such
+    /// as `take` to limit it (with `by_ref` to keep using the simulation afterwards), or
+    /// `into_generation_iter` to stop once the simulation is finished.
+    fn next(&mut self) -> Option<String> {
+        self.simulate_generation();
+        Some(self.generation_string())
+    }
+}
+
+/// An iterator that owns a simulation and yields the string representation of each generation
+/// it simulates, created with `Simulation::into_generation_iter`.
+pub struct SimulationIter {
+    /// The simulation that generations are simulated on.
+    simulation: Simulation,
+    /// A flag indicating whether iteration should stop once the simulation is finished.
+    stop_when_finished: bool,
+}
+
+impl SimulationIter {
+    /// Returns the simulation being iterated over.
+    pub fn simulation(&self) -> &Simulation {
+        &self.simulation
+    }
+
+    /// Stops iterating and returns the simulation at the last generation that was yielded.
+    pub fn into_simulation(self) -> Simulation {
+        self.simulation
+    }
+}
+
+impl Iterator for SimulationIter {
+    type Item = String;
+
+    /// Simulates one generation and returns its `generation_string`, or returns `None` if the
+    /// iterator stops when finished and the simulation is finished (see `is_finished`).
+    ///
+    /// # Note
+    /// The generation that finishes the simulation is still yielded, the same as the last
+    /// generation simulated by `simulate_continuous_generations`.
+    fn next(&mut self) -> Option<String> {
+        if self.stop_when_finished && self.simulation.is_finished() {
+            return None;
+        }
+        self.simulation.next()
+    }
+}
+
+/// A snapshot of a generation yielded by `Generations`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GenerationSnapshot {
+    /// The iteration of the simulation when the generation was simulated.
+    pub iteration: u128,
+    /// The `(row, column)` positions of the alive cells, in ascending (row-major) order.
+    pub alive: Vec<(u16, u16)>,
+    /// The string representation of the generation (see `Simulation::generation_string`).
+    pub string: String,
+}
+
+/// An iterator that borrows a simulation and yields a snapshot of each generation it
+/// simulates, created with `Simulation::generations`.
+pub struct Generations<'a> {
+    /// The simulation that generations are simulated on.
+    simulation: &'a mut Simulation,
+}
+
+impl Iterator for Generations<'_> {
+    type Item = GenerationSnapshot;
+
+    /// Simulates one generation and returns a snapshot of it, or returns `None` if the
+    /// simulation is finished (see `is_finished`).
+    fn next(&mut self) -> Option<GenerationSnapshot> {
+        if self.simulation.is_finished() {
+            return None;
+        }
+        self.simulation.simulate_generation();
+        Some(GenerationSnapshot {
+            iteration: self.simulation.iteration,
+            alive: self.simulation.alive_cells_as_vec(),
+            string: self.simulation.generation_string(),
+        })
+    }
+}
+
+impl Simulation {
+    /// Turns the simulation into an iterator over the string representation of each generation.
+    ///
+    /// # Description
+    /// Each call to `next` simulates one generation with `simulate_generation` and returns its
+    /// `generation_string`, so the current generation is not yielded. Read it with
+    /// `generation_string` before calling this function if it is needed.
+    ///
+    /// # Arguments
+    /// * `stop_when_finished` - Whether the iterator should end once the simulation reaches a
+    ///   finished (periodic) state. If false, the iterator never ends.
+    ///
+    /// # Returns
+    /// A `SimulationIter` that owns the simulation. The simulation can be taken back with
+    /// `SimulationIter::into_simulation`.
+    pub fn into_generation_iter(self, stop_when_finished: bool) -> SimulationIter {
+        SimulationIter {
+            simulation: self,
+            stop_when_finished,
+        }
+    }
+
+    /// Returns an iterator that simulates one generation per call to `next` and yields a
+    /// `GenerationSnapshot` of it.
+    ///
+    /// # Description
+    /// Generations are simulated with `simulate_generation`, so they are displayed, printed,
+    /// and saved the same way. The iterator ends once the simulation is finished (see
+    /// `is_finished`), and the generation that finishes it is still yielded. Since the
+    /// simulation is only borrowed, it can be simulated manually between iterators and the
+    /// iteration counter stays consistent.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::iteration::GenerationSnapshot;
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(5)
+    ///     .width(5)
+    ///     .seed_named("blinker")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let first: GenerationSnapshot = simulation.generations().next().unwrap();
+    /// assert_eq!(first.iteration, 1);
+    /// assert_eq!(first.alive, vec![(1, 2), (2, 2), (3, 2)]);
+    ///
+    /// simulation.simulate_generation();
+    /// // The blinker returned to its seed, so the simulation is finished
+    /// assert_eq!(simulation.generations().count(), 0);
+    /// assert_eq!(simulation.iteration(), 2);
+    /// ```
+    pub fn generations(&mut self) -> Generations<'_> {
+        Generations { simulation: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// Returns a headless simulation of a glider on a 10x10 ball, which never finishes.
+    fn glider() -> Simulation {
+        SimulationBuilder::new()
+            .height(10)
+            .width(10)
+            .surface_ball()
+            .seed_named("glider")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn take_simulates_one_generation_per_snapshot() {
+        let mut simulation: Simulation = glider();
+        let mut expected: Simulation = glider();
+        let snapshots: Vec<GenerationSnapshot> = simulation.generations().take(7).collect();
+        assert_eq!(snapshots.len(), 7);
+        assert_eq!(simulation.iteration(), 7);
+        for (index, snapshot) in snapshots.iter().enumerate() {
+            expected.simulate_generation();
+            assert_eq!(snapshot.iteration, index as u128 + 1);
+            assert_eq!(snapshot.string, expected.generation_string());
+            assert_eq!(snapshot.alive, expected.alive_cells_as_vec());
+        }
+        assert_eq!(simulation.save_history_size(), expected.save_history_size());
+    }
+
+    #[test]
+    fn iteration_ends_once_the_simulation_is_finished() {
+        let mut block: Simulation = SimulationBuilder::new()
+            .height(4)
+            .width(4)
+            .seed("-----**--**-----")
+            .build()
+            .unwrap();
+        // The first generation repeats the seed, which finishes the simulation
+        let snapshots: Vec<GenerationSnapshot> = block.generations().collect();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].string, "-----**--**-----");
+        assert!(block.is_finished());
+        assert!(block.generations().next().is_none());
+        assert_eq!(block.iteration(), 1);
+
+        let mut blinker: Simulation = SimulationBuilder::new()
+            .height(5)
+            .width(5)
+            .seed_named("blinker")
+            .build()
+            .unwrap();
+        assert_eq!(blinker.generations().take(10).count(), 2);
+        assert_eq!(blinker.iteration(), 2);
+    }
+
+    #[test]
+    fn manual_steps_and_iteration_share_the_counter() {
+        let mut simulation: Simulation = glider();
+        simulation.simulate_generations(3);
+        let snapshot: GenerationSnapshot = simulation.generations().next().unwrap();
+        assert_eq!(snapshot.iteration, 4);
+        simulation.simulate_generation();
+        let iterations: Vec<u128> = simulation
+            .generations()
+            .take(3)
+            .map(|snapshot| snapshot.iteration)
+            .collect();
+        assert_eq!(iterations, vec![6, 7, 8]);
+        simulation.rollback_generations(2);
+        assert_eq!(simulation.generations().next().unwrap().iteration, 7);
+        assert_eq!(simulation.iteration(), 7);
+    }
+
+    #[test]
+    fn generation_iterators_do_not_open_a_window() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(10)
+            .width(10)
+            .seed_named("glider")
+            .display(false)
+            .print(false)
+            .build()
+            .unwrap();
+        assert_eq!(simulation.generations().take(3).count(), 3);
+        assert!(simulation.window_data.is_none());
+        let mut iter: SimulationIter = simulation.into_generation_iter(false);
+        assert_eq!(iter.by_ref().take(2).count(), 2);
+        assert!(iter.simulation().window_data.is_none());
+        assert_eq!(iter.into_simulation().iteration(), 5);
+    }
+}
@@ -0,0 +1,26 @@
+//! A compact subset of the Life Lexicon, embedded at compile time.
+//!
+//! Each entry stores the pattern in RLE form along with its metadata, so looking up a named
+//! pattern never needs to load anything at runtime. Patterns are looked up by name through
+//! `patterns::lookup`.
+//!
+//! The entries are generated by `build.rs` from `data/lexicon.txt`; add patterns there rather
+//! than here.
+
+use crate::patterns::PatternCategory::{self, *};
+
+/// A single named pattern in the lexicon.
+pub(crate) struct LexiconEntry {
+    /// The canonical name of the pattern.
+    pub(crate) name: &'static str,
+    /// Other common names the pattern is known by.
+    pub(crate) aliases: &'static [&'static str],
+    /// The kind of pattern.
+    pub(crate) category: PatternCategory,
+    /// A short description of the pattern.
+    pub(crate) description: &'static str,
+    /// The pattern data in RLE form (without a header).
+    pub(crate) rle: &'static str,
+}
+
+include!(concat!(env!("OUT_DIR"), "/lexicon.rs"));
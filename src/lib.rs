@@ -24,14 +24,37 @@
 //! // This will run the entire simulation with a display window,
 //! // updating the display with each generation every 250 milliseconds
 //! // until it detects a still or periodic simulation
-//! simulation.simulate_continuous_generations(Duration::from_millis(250), true)
+//! simulation.simulate_continuous_generations(Duration::from_millis(250), true, None);
 //! ```
 
 extern crate core;
 extern crate rand;
 extern crate simple;
 
-pub(crate) mod cell;
+pub mod cell;
+pub mod delta;
+pub mod ensemble;
+pub(crate) mod history;
+pub mod iteration;
+pub(crate) mod lexicon;
+pub(crate) mod neighbors;
+pub mod observer;
+#[cfg(feature = "parallel")]
+pub(crate) mod parallel;
+pub mod patterns;
+pub(crate) mod raster;
+#[cfg(feature = "gif")]
+pub mod recording;
+pub mod rule;
+pub mod seeding;
 pub mod simulation;
 pub mod simulation_builder;
 pub(crate) mod simulation_window;
+pub(crate) mod snapshot;
+pub mod statistics;
+pub(crate) mod svg;
+pub(crate) mod terminal;
+#[cfg(feature = "image")]
+pub mod timelapse;
+pub mod tournament;
+pub mod verification;
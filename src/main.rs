@@ -0,0 +1,216 @@
+//! A command line interface for running simulations of named patterns.
+//!
+//! ```text
+//! game_of_life --pattern glider-gun --height 40 --width 60 --generations 200
+//! game_of_life --pattern r-pentomino --height 50 --width 50 --display
+//! game_of_life --list
+//! ```
+
+use simple_game_of_life::patterns;
+use simple_game_of_life::simulation::Simulation;
+use simple_game_of_life::simulation_builder::SimulationBuilder;
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage: game_of_life --pattern <name> [options]
+       game_of_life --list
+
+Options:
+    --pattern <name>      The lexicon pattern to seed the grid with, centered on the grid
+    --height <rows>       The number of rows (defaults to the pattern's height)
+    --width <columns>     The number of columns (defaults to the pattern's width)
+    --generations <n>     The maximum number of generations to simulate (default 100)
+    --cooldown <ms>       The delay between generations in milliseconds (default 100)
+    --display             Display the simulation in a window instead of printing it
+    --list                List the names of every pattern in the lexicon
+    --help                Print this message";
+
+/// The options parsed from the command line arguments.
+#[derive(Debug, PartialEq)]
+struct Options {
+    pattern: Option<String>,
+    height: Option<u16>,
+    width: Option<u16>,
+    generations: u128,
+    cooldown: u64,
+    display: bool,
+    list: bool,
+    help: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            pattern: None,
+            height: None,
+            width: None,
+            generations: 100,
+            cooldown: 100,
+            display: false,
+            list: false,
+            help: false,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    match parse_arguments(&arguments).and_then(run) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses the command line arguments (without the program name) into `Options`.
+fn parse_arguments(arguments: &[String]) -> Result<Options, String> {
+    let mut options: Options = Options::default();
+    let mut arguments = arguments.iter();
+    while let Some(argument) = arguments.next() {
+        let mut value = |flag: &str| -> Result<String, String> {
+            arguments
+                .next()
+                .cloned()
+                .ok_or(format!("{} requires a value", flag))
+        };
+        match argument.as_str() {
+            "--pattern" => options.pattern = Some(value("--pattern")?),
+            "--height" => options.height = Some(parse_number("--height", &value("--height")?)?),
+            "--width" => options.width = Some(parse_number("--width", &value("--width")?)?),
+            "--generations" => {
+                options.generations = parse_number("--generations", &value("--generations")?)?
+            }
+            "--cooldown" => options.cooldown = parse_number("--cooldown", &value("--cooldown")?)?,
+            "--display" => options.display = true,
+            "--list" => options.list = true,
+            "--help" | "-h" => options.help = true,
+            _ => return Err(format!("unknown argument {:?}", argument)),
+        }
+    }
+    if options.pattern.is_none() && !options.list && !options.help {
+        return Err(String::from("a pattern must be given with --pattern"));
+    }
+    Ok(options)
+}
+
+/// Parses the value of a numeric flag.
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} must be a non-negative number, not {:?}", flag, value))
+}
+
+/// Lists the lexicon, or runs the simulation described by the options.
+fn run(options: Options) -> Result<(), String> {
+    if options.help {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+    if options.list {
+        for name in patterns::names() {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    let mut builder: SimulationBuilder = SimulationBuilder::new()
+        .seed_named(options.pattern.as_deref().unwrap_or_default())
+        .display(options.display)
+        .print(!options.display);
+    if let Some(height) = options.height {
+        builder = builder.height(height);
+    }
+    if let Some(width) = options.width {
+        builder = builder.width(width);
+    }
+    let mut simulation: Simulation = builder.build()?;
+
+    if !options.display {
+        simulation.print_seed_generation(None);
+    }
+    simulation.simulate_continuous_generations(
+        Duration::from_millis(options.cooldown),
+        true,
+        Some(options.generations),
+    );
+    if options.display {
+        simulation.quit_window();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses arguments given as string slices.
+    fn parse(arguments: &[&str]) -> Result<Options, String> {
+        let arguments: Vec<String> = arguments.iter().map(|a| a.to_string()).collect();
+        parse_arguments(&arguments)
+    }
+
+    #[test]
+    fn parses_the_pattern_and_grid_size() {
+        let options: Options = parse(&[
+            "--pattern",
+            "glider-gun",
+            "--height",
+            "40",
+            "--width",
+            "60",
+            "--generations",
+            "25",
+        ])
+        .unwrap();
+        assert_eq!(
+            options,
+            Options {
+                pattern: Some(String::from("glider-gun")),
+                height: Some(40),
+                width: Some(60),
+                generations: 25,
+                ..Options::default()
+            }
+        );
+    }
+
+    #[test]
+    fn a_pattern_is_required_unless_listing() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--display"]).is_err());
+        assert!(parse(&["--list"]).unwrap().list);
+        assert!(parse(&["--help"]).unwrap().help);
+    }
+
+    #[test]
+    fn rejects_missing_and_invalid_values() {
+        assert!(parse(&["--pattern"]).is_err());
+        assert!(parse(&["--pattern", "block", "--height"]).is_err());
+        assert!(parse(&["--pattern", "block", "--width", "-3"]).is_err());
+        assert!(parse(&["--pattern", "block", "--generations", "many"]).is_err());
+        assert!(parse(&["--pattern", "block", "--speed", "2"]).is_err());
+    }
+
+    #[test]
+    fn the_pattern_is_centered_on_the_grid() {
+        let options: Options =
+            parse(&["--pattern", "glider-gun", "--height", "15", "--width", "40"]).unwrap();
+        let simulation: Simulation = SimulationBuilder::new()
+            .seed_named(options.pattern.as_deref().unwrap())
+            .height(options.height.unwrap())
+            .width(options.width.unwrap())
+            .build()
+            .unwrap();
+        // The Gosper glider gun is 9 rows by 36 columns
+        assert_eq!(simulation.alive_count(), 36);
+        let rows: Vec<u16> = simulation.alive_cells().map(|(row, _)| row).collect();
+        let columns: Vec<u16> = simulation.alive_cells().map(|(_, column)| column).collect();
+        assert_eq!(*rows.iter().min().unwrap(), 3);
+        assert_eq!(*rows.iter().max().unwrap(), 11);
+        assert_eq!(*columns.iter().min().unwrap(), 2);
+        assert_eq!(*columns.iter().max().unwrap(), 37);
+    }
+}
@@ -0,0 +1,851 @@
+//! Counting the alive neighbors of cells.
+//!
+//! A generation is stored as a set of alive cells, which is compact but makes every neighbor
+//! lookup a hash. Rules with a large radius look at up to 120 neighbors per cell, so
+//! generations are first laid out as a flat grid where each lookup is a direct index, and the
+//! alive neighbors of every cell are counted up front. The counts are found by adding one to
+//! each neighbor of every alive cell, so the work grows with the number of alive cells rather
+//! than the size of the grid, which is usually far larger.
+//!
+//! When only a few cells are alive, even laying out the grid costs more than counting, so the
+//! counts can instead be kept in a map with an entry for each cell that has an alive neighbor
+//! (see `sparse_neighbor_counts`). These are the only cells that can be born, so the work of a
+//! generation then grows with the number of alive cells alone.
+//!
+//! The neighbors of a cell are the distinct cells around it, so on wrapping grids that are
+//! smaller than the neighborhood (such as a 1-row `Ball`), a cell reached in more than one way
+//! is counted once, and a cell is never its own neighbor.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::cell::Cell;
+use crate::simulation::{Neighborhood, SurfaceType, SurfaceWrap};
+
+/// The alive cells of a generation laid out in row-major order, along with the number of alive
+/// neighbors of every cell.
+pub(crate) struct NeighborGrid {
+    /// Whether each cell is alive, indexed by `row * columns + column`.
+    alive: Vec<bool>,
+    /// The number of alive neighbors of each cell, indexed by `row * columns + column`.
+    alive_neighbors: Vec<u8>,
+    /// The number of columns in the grid.
+    columns: u16,
+}
+
+impl NeighborGrid {
+    /// Lays out a generation as a grid and counts the alive neighbors of every cell on the
+    /// given surface, within the given neighborhood and radius (see `for_each_neighbor`).
+    pub(crate) fn new(
+        generation: &HashSet<Cell>,
+        rows: u16,
+        columns: u16,
+        surface_type: &SurfaceType,
+        neighborhood: Neighborhood,
+        radius: u8,
+    ) -> NeighborGrid {
+        let area: usize = rows as usize * columns as usize;
+        let mut alive: Vec<bool> = vec![false; area];
+        for cell in generation {
+            alive[cell.row as usize * columns as usize + cell.column as usize] = true;
+        }
+        let mut alive_neighbors: Vec<u8> = vec![0; area];
+        for_each_neighbor(
+            generation,
+            (rows, columns),
+            surface_type,
+            neighborhood,
+            radius,
+            |row, column| alive_neighbors[row as usize * columns as usize + column as usize] += 1,
+        );
+        NeighborGrid {
+            alive,
+            alive_neighbors,
+            columns,
+        }
+    }
+
+    /// Returns true if the cell at the given row and column is alive.
+    pub(crate) fn is_alive(&self, row: u16, column: u16) -> bool {
+        self.alive[row as usize * self.columns as usize + column as usize]
+    }
+
+    /// Returns the number of alive neighbors of the cell at the given row and column.
+    pub(crate) fn alive_neighbors(&self, row: u16, column: u16) -> u8 {
+        self.alive_neighbors[row as usize * self.columns as usize + column as usize]
+    }
+}
+
+/// Counts the alive neighbors of the cells that have at least one, on the given surface,
+/// within the given neighborhood and radius (see `for_each_neighbor`).
+///
+/// # Returns
+/// A `HashMap` from the `(row, column)` position of each cell with an alive neighbor to its
+/// number of alive neighbors. Cells that are not in the map have no alive neighbors.
+pub(crate) fn sparse_neighbor_counts(
+    generation: &HashSet<Cell>,
+    rows: u16,
+    columns: u16,
+    surface_type: &SurfaceType,
+    neighborhood: Neighborhood,
+    radius: u8,
+) -> HashMap<(u16, u16), u8> {
+    let mut counts: HashMap<(u16, u16), u8> = HashMap::new();
+    for_each_neighbor(
+        generation,
+        (rows, columns),
+        surface_type,
+        neighborhood,
+        radius,
+        |row, column| *counts.entry((row, column)).or_insert(0) += 1,
+    );
+    counts
+}
+
+/// Returns true if a generation with the given number of alive cells is sparse enough that
+/// counting neighbors with `sparse_neighbor_counts` is faster than laying out a `NeighborGrid`.
+///
+/// # Description
+/// A `NeighborGrid` costs a little for every cell in the grid, while the map costs much more,
+/// but only for each neighbor of an alive cell. The map is used while each alive cell's
+/// neighbors are fewer than a sixteenth of the grid between them, which keeps it from being
+/// used for random soups, and lets a lone spaceship on a huge grid take the same time per
+/// generation as on a small one.
+pub(crate) fn is_sparse(
+    population: usize,
+    area: usize,
+    neighborhood: Neighborhood,
+    radius: u8,
+) -> bool {
+    population * neighbor_offsets(neighborhood, radius).len() * 16 < area
+}
+
+/// Calls the given function with the position of every alive neighbor of every cell.
+///
+/// # Description
+/// Each offset in the neighbor offset table (see `neighbor_offsets`) is applied to every alive
+/// cell in reverse with `wrap_coordinate`, and the function is called with the cell it reaches,
+/// which has the alive cell as a neighbor. Neighbors past an edge that the surface wraps on are
+/// found on the opposite edge, and neighbors past a bounded edge are outside the grid and are
+/// skipped. On a `Mobius` or `KleinBottle` surface, neighbors past the left or right edge are
+/// also flipped vertically (see `for_each_flipped_neighbor`), and on a `Custom` surface, the
+/// neighbors of every cell are found with the surface's function (see
+/// `for_each_custom_neighbor`).
+///
+/// # Arguments
+/// * `generation` - The alive cells of the generation.
+/// * `(rows, columns)` - The number of rows and columns in the grid.
+/// * `surface_type` - The surface type of the grid, which decides which edges wrap.
+/// * `neighborhood` - The cells that count as the neighbors of each cell.
+/// * `radius` - The radius of the neighborhood.
+/// * `count` - The function called with the row and column of a cell for each of its alive
+///   neighbors.
+fn for_each_neighbor<F: FnMut(u16, u16)>(
+    generation: &HashSet<Cell>,
+    (rows, columns): (u16, u16),
+    surface_type: &SurfaceType,
+    neighborhood: Neighborhood,
+    radius: u8,
+    mut count: F,
+) {
+    if let SurfaceType::Custom(wrap) = surface_type {
+        for_each_custom_neighbor(
+            generation,
+            (rows, columns),
+            wrap,
+            neighborhood,
+            radius,
+            count,
+        );
+        return;
+    }
+    if surface_type.flips_on_horizontal_wrap() {
+        for_each_flipped_neighbor(
+            generation,
+            (rows, columns),
+            surface_type,
+            neighborhood,
+            radius,
+            count,
+        );
+        return;
+    }
+    let wraps_vertically: bool = surface_type.wraps_vertically();
+    let wraps_horizontally: bool = surface_type.wraps_horizontally();
+    let offsets: Vec<(i32, i32)> = distinct_offsets(
+        neighbor_offsets(neighborhood, radius),
+        (rows, wraps_vertically),
+        (columns, wraps_horizontally),
+    );
+    for cell in generation {
+        // A cell is a neighbor of the cell it reaches with the opposite offset
+        for &(row_offset, column_offset) in &offsets {
+            let neighbor_row: Option<u16> =
+                wrap_coordinate(cell.row, -row_offset, rows, wraps_vertically);
+            let neighbor_column: Option<u16> =
+                wrap_coordinate(cell.column, -column_offset, columns, wraps_horizontally);
+            if let (Some(neighbor_row), Some(neighbor_column)) = (neighbor_row, neighbor_column) {
+                count(neighbor_row, neighbor_column);
+            }
+        }
+    }
+}
+
+/// Calls a function for each neighbor of every alive cell on a `Mobius` or `KleinBottle`
+/// surface, the same as `for_each_neighbor` but with the neighbors past the left or right edge
+/// flipped vertically (see `move_cell`).
+///
+/// # Note
+/// Offsets that reach the same column around the flipping edge reach different rows, unless
+/// the cell is in the middle row, so whether two offsets reach the same cell depends on the
+/// cell. Only a grid at most twice as wide as the radius can be reached in more than one way,
+/// so only then is each cell checked for neighbors it already reached.
+fn for_each_flipped_neighbor<F: FnMut(u16, u16)>(
+    generation: &HashSet<Cell>,
+    (rows, columns): (u16, u16),
+    surface_type: &SurfaceType,
+    neighborhood: Neighborhood,
+    radius: u8,
+    mut count: F,
+) {
+    let wraps_vertically: bool = surface_type.wraps_vertically();
+    let offsets: Vec<(i32, i32)> = distinct_offsets(
+        neighbor_offsets(neighborhood, radius),
+        (rows, wraps_vertically),
+        (columns, false),
+    );
+    let check_each_cell: bool = 2 * radius as u32 >= columns as u32;
+    let mut reached: Vec<(u16, u16)> = Vec::new();
+    for cell in generation {
+        reached.clear();
+        for &(row_offset, column_offset) in &offsets {
+            let neighbor: Option<(u16, u16)> = move_cell(
+                (cell.row, cell.column),
+                (-row_offset, -column_offset),
+                (rows, wraps_vertically),
+                columns,
+            );
+            if let Some((neighbor_row, neighbor_column)) = neighbor {
+                if check_each_cell {
+                    if (neighbor_row, neighbor_column) == (cell.row, cell.column)
+                        || reached.contains(&(neighbor_row, neighbor_column))
+                    {
+                        continue;
+                    }
+                    reached.push((neighbor_row, neighbor_column));
+                }
+                count(neighbor_row, neighbor_column);
+            }
+        }
+    }
+}
+
+/// Calls a function for each alive neighbor of every cell on a `Custom` surface, the same as
+/// `for_each_neighbor`.
+///
+/// # Description
+/// The function of a custom surface can wrap neighbors anywhere, so the neighbors of a cell
+/// can not be found by moving an alive cell backwards. Instead, every cell of the grid looks up
+/// each of its neighbors with `custom_neighbors`, and the function is called once for each
+/// alive one, so this takes time for every cell in the grid.
+fn for_each_custom_neighbor<F: FnMut(u16, u16)>(
+    generation: &HashSet<Cell>,
+    (rows, columns): (u16, u16),
+    wrap: &SurfaceWrap,
+    neighborhood: Neighborhood,
+    radius: u8,
+    mut count: F,
+) {
+    let mut alive: Vec<bool> = vec![false; rows as usize * columns as usize];
+    for cell in generation {
+        alive[cell.row as usize * columns as usize + cell.column as usize] = true;
+    }
+    let offsets: Vec<(i32, i32)> = neighbor_offsets(neighborhood, radius);
+    let mut neighbors: Vec<(u16, u16)> = Vec::new();
+    for row in 0..rows {
+        for column in 0..columns {
+            custom_neighbors(
+                (row, column),
+                (rows, columns),
+                wrap,
+                &offsets,
+                &mut neighbors,
+            );
+            for &(neighbor_row, neighbor_column) in &neighbors {
+                if alive[neighbor_row as usize * columns as usize + neighbor_column as usize] {
+                    count(row, column);
+                }
+            }
+        }
+    }
+}
+
+/// Finds the distinct neighbors of a cell on a `Custom` surface.
+///
+/// # Description
+/// The function of the surface is called with the position of the cell moved by each offset,
+/// including the positions within the grid. Positions it maps to `None` or outside of the grid
+/// have no neighbor, and the cell itself and positions it already mapped to are skipped, so
+/// each neighbor is only counted once.
+///
+/// # Arguments
+/// * `(row, column)` - The cell to find the neighbors of.
+/// * `(rows, columns)` - The number of rows and columns in the grid.
+/// * `wrap` - The function of the surface.
+/// * `offsets` - The offsets to each neighbor (see `neighbor_offsets`).
+/// * `neighbors` - The list to fill with the neighbors, which is cleared first.
+pub(crate) fn custom_neighbors(
+    (row, column): (u16, u16),
+    (rows, columns): (u16, u16),
+    wrap: &SurfaceWrap,
+    offsets: &[(i32, i32)],
+    neighbors: &mut Vec<(u16, u16)>,
+) {
+    neighbors.clear();
+    for &(row_offset, column_offset) in offsets {
+        let neighbor: Option<(u16, u16)> = wrap(
+            row as i32 + row_offset,
+            column as i32 + column_offset,
+            rows,
+            columns,
+        );
+        if let Some(neighbor) = neighbor {
+            if neighbor.0 < rows
+                && neighbor.1 < columns
+                && neighbor != (row, column)
+                && !neighbors.contains(&neighbor)
+            {
+                neighbors.push(neighbor);
+            }
+        }
+    }
+}
+
+/// Moves a cell by the given offset on a `Mobius` or `KleinBottle` surface.
+///
+/// # Description
+/// Each time the cell goes past the left or right edge, it comes back on the opposite edge
+/// flipped vertically, so a cell that goes past the right edge at row `r` comes back on the
+/// left edge at row `rows - 1 - r`. Going around an odd number of times flips the cell. The
+/// row is moved with `wrap_coordinate` before it is flipped.
+///
+/// # Arguments
+/// * `(row, column)` - The cell to move.
+/// * `(row_offset, column_offset)` - How far to move, which can be negative.
+/// * `(rows, wraps_vertically)` - The number of rows, and whether they wrap.
+/// * `columns` - The number of columns.
+///
+/// # Returns
+/// The moved cell, or `None` if it falls off the top or bottom edge.
+fn move_cell(
+    (row, column): (u16, u16),
+    (row_offset, column_offset): (i32, i32),
+    (rows, wraps_vertically): (u16, bool),
+    columns: u16,
+) -> Option<(u16, u16)> {
+    let moved_row: u16 = wrap_coordinate(row, row_offset, rows, wraps_vertically)?;
+    let moved_column: i32 = column as i32 + column_offset;
+    let laps: i32 = moved_column.div_euclid(columns as i32);
+    let moved_column: u16 = moved_column.rem_euclid(columns as i32) as u16;
+    if laps % 2 != 0 {
+        Some((rows - 1 - moved_row, moved_column))
+    } else {
+        Some((moved_row, moved_column))
+    }
+}
+
+/// Returns the `(row, column)` offsets from a cell to each of its neighbors.
+///
+/// # Description
+/// Every cell within the radius of a cell, other than the cell itself, is a neighbor in the
+/// `Moore` neighborhood. In the `VonNeumann` neighborhood, only the cells whose row and column
+/// distances add up to at most the radius are neighbors, so diagonal cells are not neighbors
+/// with a radius of 1.
+///
+/// The offsets are in row-major order, so a radius of 1 in the `Moore` neighborhood gives the
+/// eight offsets from `(-1, -1)` to `(1, 1)`, skipping `(0, 0)`.
+pub(crate) fn neighbor_offsets(neighborhood: Neighborhood, radius: u8) -> Vec<(i32, i32)> {
+    let radius: i32 = radius as i32;
+    let mut offsets: Vec<(i32, i32)> = Vec::new();
+    for row_offset in -radius..=radius {
+        for column_offset in -radius..=radius {
+            let in_neighborhood: bool = match neighborhood {
+                Neighborhood::Moore => true,
+                Neighborhood::VonNeumann => row_offset.abs() + column_offset.abs() <= radius,
+            };
+            if in_neighborhood && (row_offset, column_offset) != (0, 0) {
+                offsets.push((row_offset, column_offset));
+            }
+        }
+    }
+    offsets
+}
+
+/// Removes the offsets that reach the cell itself or the same cell as an earlier offset.
+///
+/// # Description
+/// Along a wrapping axis that is shorter than the neighborhood is wide, offsets that differ by
+/// a multiple of the axis length reach the same cell. For example, on a 1-row `Ball`, the cells
+/// above and below a cell are the cell itself, and on a 2-row `Ball`, they are the same cell.
+/// Each offset is reduced modulo the length of every wrapping axis, and the offsets that reduce
+/// to `(0, 0)` or to an earlier offset are dropped, so each distinct neighbor is counted once.
+///
+/// Which offsets collide does not depend on the cell, so this is only done once per grid.
+///
+/// # Arguments
+/// * `offsets` - The offsets to each neighbor (see `neighbor_offsets`).
+/// * `(rows, wraps_vertically)` - The number of rows, and whether they wrap.
+/// * `(columns, wraps_horizontally)` - The number of columns, and whether they wrap.
+///
+/// # Returns
+/// The offsets to each distinct neighbor, in their original order.
+fn distinct_offsets(
+    offsets: Vec<(i32, i32)>,
+    (rows, wraps_vertically): (u16, bool),
+    (columns, wraps_horizontally): (u16, bool),
+) -> Vec<(i32, i32)> {
+    let reduce = |offset: i32, length: u16, wraps: bool| {
+        // An empty grid has no cells to count neighbors for
+        if wraps && length > 0 {
+            offset.rem_euclid(length as i32)
+        } else {
+            offset
+        }
+    };
+    let mut seen: HashSet<(i32, i32)> = HashSet::new();
+    offsets
+        .into_iter()
+        .map(|(row_offset, column_offset)| {
+            (
+                reduce(row_offset, rows, wraps_vertically),
+                reduce(column_offset, columns, wraps_horizontally),
+            )
+        })
+        .filter(|&offset| offset != (0, 0) && seen.insert(offset))
+        .collect()
+}
+
+/// Moves a coordinate along one axis by the given offset, wrapping around the edges if the axis
+/// wraps.
+///
+/// # Arguments
+/// * `coordinate` - The row or column to move from.
+/// * `offset` - How far to move, which can be negative.
+/// * `length` - The number of rows or columns along the axis.
+/// * `wraps` - Whether the axis wraps around between its edges.
+///
+/// # Returns
+/// The moved coordinate, or `None` if it falls off a bounded axis.
+fn wrap_coordinate(coordinate: u16, offset: i32, length: u16, wraps: bool) -> Option<u16> {
+    let moved: i32 = coordinate as i32 + offset;
+    if (0..length as i32).contains(&moved) {
+        Some(moved as u16)
+    } else if wraps {
+        Some(moved.rem_euclid(length as i32) as u16)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::cell::CellState::ALIVE;
+    use crate::simulation::Simulation;
+    use crate::simulation::SurfaceType::{
+        Ball, HorizontalLoop, KleinBottle, Mobius, Rectangle, VerticalLoop,
+    };
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// The spaceships of the lexicon, which cross the edges of a grid if they wrap.
+    const SPACESHIPS: [&str; 4] = [
+        "glider",
+        "lightweight spaceship",
+        "middleweight spaceship",
+        "heavyweight spaceship",
+    ];
+
+    /// Every surface type other than `Custom`.
+    fn surfaces() -> [SurfaceType; 6] {
+        [
+            Rectangle,
+            Ball,
+            HorizontalLoop,
+            VerticalLoop,
+            Mobius,
+            KleinBottle,
+        ]
+    }
+
+    /// Returns a builder for a simulation of the given size on the given surface.
+    fn builder(rows: u16, columns: u16, surface_type: &SurfaceType) -> SimulationBuilder {
+        let builder: SimulationBuilder = SimulationBuilder::new().height(rows).width(columns);
+        match surface_type {
+            Ball => builder.surface_ball(),
+            HorizontalLoop => builder.surface_horizontal_loop(),
+            VerticalLoop => builder.surface_vertical_loop(),
+            Mobius => builder.surface_mobius(),
+            KleinBottle => builder.surface_klein_bottle(),
+            _ => builder.surface_rectangle(),
+        }
+    }
+
+    /// Returns the distinct neighbors of a cell, found by moving the cell by each offset on its
+    /// own, without any of the shortcuts `for_each_neighbor` takes.
+    fn reference_neighbors(
+        (row, column): (u16, u16),
+        (rows, columns): (u16, u16),
+        surface_type: &SurfaceType,
+        neighborhood: Neighborhood,
+        radius: u8,
+    ) -> Vec<(u16, u16)> {
+        let (rows, columns): (i32, i32) = (rows as i32, columns as i32);
+        let mut neighbors: Vec<(u16, u16)> = Vec::new();
+        for (row_offset, column_offset) in neighbor_offsets(neighborhood, radius) {
+            let mut neighbor_row: i32 = row as i32 + row_offset;
+            let mut neighbor_column: i32 = column as i32 + column_offset;
+            if surface_type.wraps_vertically() {
+                neighbor_row = neighbor_row.rem_euclid(rows);
+            }
+            if surface_type.wraps_horizontally() {
+                let laps: i32 = neighbor_column.div_euclid(columns);
+                if surface_type.flips_on_horizontal_wrap() && laps % 2 != 0 {
+                    neighbor_row = rows - 1 - neighbor_row;
+                }
+                neighbor_column = neighbor_column.rem_euclid(columns);
+            }
+            let neighbor: (i32, i32) = (neighbor_row, neighbor_column);
+            if (0..rows).contains(&neighbor_row)
+                && (0..columns).contains(&neighbor_column)
+                && neighbor != (row as i32, column as i32)
+                && !neighbors.contains(&(neighbor_row as u16, neighbor_column as u16))
+            {
+                neighbors.push((neighbor_row as u16, neighbor_column as u16));
+            }
+        }
+        neighbors
+    }
+
+    /// Counts the alive neighbors of every cell with `reference_neighbors`, leaving out the
+    /// cells without any.
+    fn reference_counts(
+        alive: &HashSet<(u16, u16)>,
+        (rows, columns): (u16, u16),
+        surface_type: &SurfaceType,
+        neighborhood: Neighborhood,
+        radius: u8,
+    ) -> HashMap<(u16, u16), u8> {
+        let mut counts: HashMap<(u16, u16), u8> = HashMap::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                let count: usize = reference_neighbors(
+                    (row, column),
+                    (rows, columns),
+                    surface_type,
+                    neighborhood,
+                    radius,
+                )
+                .iter()
+                .filter(|neighbor| alive.contains(neighbor))
+                .count();
+                if count > 0 {
+                    counts.insert((row, column), count as u8);
+                }
+            }
+        }
+        counts
+    }
+
+    /// Returns the next generation under Conway's rules, counting neighbors with
+    /// `reference_counts`.
+    fn reference_next_generation(
+        alive: &HashSet<(u16, u16)>,
+        dimensions: (u16, u16),
+        surface_type: &SurfaceType,
+    ) -> HashSet<(u16, u16)> {
+        reference_counts(alive, dimensions, surface_type, Neighborhood::Moore, 1)
+            .into_iter()
+            .filter(|&(cell, count)| count == 3 || (count == 2 && alive.contains(&cell)))
+            .map(|(cell, _)| cell)
+            .collect()
+    }
+
+    fn positions(generation: &HashSet<Cell>) -> HashSet<(u16, u16)> {
+        generation
+            .iter()
+            .map(|cell| (cell.row, cell.column))
+            .collect()
+    }
+
+    fn cells(positions: &HashSet<(u16, u16)>) -> HashSet<Cell> {
+        positions
+            .iter()
+            .map(|&(row, column)| Cell::new(ALIVE, row, column))
+            .collect()
+    }
+
+    #[test]
+    fn coordinates_inside_the_axis_do_not_wrap() {
+        assert_eq!(wrap_coordinate(3, 1, 5, false), Some(4));
+        assert_eq!(wrap_coordinate(3, -3, 5, false), Some(0));
+        assert_eq!(wrap_coordinate(3, 1, 5, true), Some(4));
+    }
+
+    #[test]
+    fn coordinates_past_a_wrapping_edge_come_back_on_the_other_edge() {
+        assert_eq!(wrap_coordinate(4, 1, 5, true), Some(0));
+        assert_eq!(wrap_coordinate(0, -1, 5, true), Some(4));
+        assert_eq!(wrap_coordinate(0, -2, 1, true), Some(0));
+        // Offsets longer than the axis go around more than once
+        assert_eq!(wrap_coordinate(1, 12, 5, true), Some(3));
+        assert_eq!(wrap_coordinate(1, -12, 5, true), Some(4));
+    }
+
+    #[test]
+    fn coordinates_past_a_bounded_edge_fall_off() {
+        assert_eq!(wrap_coordinate(4, 1, 5, false), None);
+        assert_eq!(wrap_coordinate(0, -1, 5, false), None);
+        assert_eq!(wrap_coordinate(0, 1, 1, false), None);
+    }
+
+    #[test]
+    fn spaceships_match_the_reference_on_every_surface() {
+        for surface_type in surfaces() {
+            for spaceship in SPACESHIPS {
+                let mut simulation: Simulation = builder(20, 20, &surface_type)
+                    .seed_named(spaceship)
+                    .build()
+                    .unwrap();
+                let mut expected: HashSet<(u16, u16)> = positions(&simulation.generation);
+                for generation in 1..=100 {
+                    expected = reference_next_generation(&expected, (20, 20), &surface_type);
+                    simulation.simulate_generation();
+                    assert_eq!(
+                        positions(&simulation.generation),
+                        expected,
+                        "{} on a {:?} surface at generation {}",
+                        spaceship,
+                        surface_type,
+                        generation
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn glider_wraps_around_a_ball() {
+        // A glider moves one cell diagonally every 4 generations, so it crosses an 8x8 grid in
+        // 32 generations
+        let mut simulation: Simulation = builder(8, 8, &Ball).seed_named("glider").build().unwrap();
+        let seed: HashSet<(u16, u16)> = positions(&simulation.generation);
+        for _ in 0..8 {
+            simulation.simulate_generations(4);
+            assert_eq!(simulation.alive_count(), 5);
+        }
+        assert_eq!(positions(&simulation.generation), seed);
+    }
+
+    #[test]
+    fn glider_crashes_into_the_corner_of_a_rectangle() {
+        let mut simulation: Simulation = builder(8, 8, &Rectangle)
+            .seed_named("glider")
+            .build()
+            .unwrap();
+        simulation.simulate_generations(40);
+        let block: HashSet<(u16, u16)> = HashSet::from([(6, 6), (6, 7), (7, 6), (7, 7)]);
+        assert_eq!(positions(&simulation.generation), block);
+        assert!(simulation.is_finished());
+    }
+
+    /// Checks the neighbor counts of every possible generation of a grid against the reference
+    /// on every surface, in both neighborhoods with a radius of 1 and 2.
+    fn assert_every_generation_matches(rows: u16, columns: u16) {
+        let area: u32 = rows as u32 * columns as u32;
+        for surface_type in surfaces() {
+            for neighborhood in [Neighborhood::Moore, Neighborhood::VonNeumann] {
+                for radius in [1, 2] {
+                    for mask in 0..(1u32 << area) {
+                        let generation: HashSet<Cell> = (0..area)
+                            .filter(|index| mask & (1 << index) != 0)
+                            .map(|index| {
+                                Cell::new(
+                                    ALIVE,
+                                    (index / columns as u32) as u16,
+                                    (index % columns as u32) as u16,
+                                )
+                            })
+                            .collect();
+                        assert_eq!(
+                            sparse_neighbor_counts(
+                                &generation,
+                                rows,
+                                columns,
+                                &surface_type,
+                                neighborhood,
+                                radius
+                            ),
+                            reference_counts(
+                                &positions(&generation),
+                                (rows, columns),
+                                &surface_type,
+                                neighborhood,
+                                radius
+                            ),
+                            "{:?} {:?} radius {} on a {}x{} grid with mask {:b}",
+                            surface_type,
+                            neighborhood,
+                            radius,
+                            rows,
+                            columns,
+                            mask
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_row_grids_count_each_neighbor_once() {
+        assert_every_generation_matches(1, 5);
+        // On a 1-row ball, the cells above and below a cell are the cell itself
+        let generation: HashSet<Cell> = HashSet::from([Cell::new(ALIVE, 0, 1)]);
+        let counts: HashMap<(u16, u16), u8> =
+            sparse_neighbor_counts(&generation, 1, 3, &Ball, Neighborhood::Moore, 1);
+        assert_eq!(counts, HashMap::from([((0, 0), 1), ((0, 2), 1)]));
+    }
+
+    #[test]
+    fn single_column_grids_count_each_neighbor_once() {
+        assert_every_generation_matches(5, 1);
+        let generation: HashSet<Cell> = HashSet::from([Cell::new(ALIVE, 1, 0)]);
+        let counts: HashMap<(u16, u16), u8> =
+            sparse_neighbor_counts(&generation, 3, 1, &Ball, Neighborhood::Moore, 1);
+        assert_eq!(counts, HashMap::from([((0, 0), 1), ((2, 0), 1)]));
+    }
+
+    #[test]
+    fn two_by_two_grids_count_each_neighbor_once() {
+        assert_every_generation_matches(2, 2);
+        // Every cell of a 2x2 ball is a neighbor of every other cell exactly once
+        let generation: HashSet<Cell> = (0..2)
+            .flat_map(|row| (0..2).map(move |column| Cell::new(ALIVE, row, column)))
+            .collect();
+        let counts: HashMap<(u16, u16), u8> =
+            sparse_neighbor_counts(&generation, 2, 2, &Ball, Neighborhood::Moore, 1);
+        assert!(counts.values().all(|&count| count == 3));
+    }
+
+    #[test]
+    fn neighbor_grid_matches_the_reference_for_spaceships_on_every_surface() {
+        for surface_type in surfaces() {
+            for spaceship in SPACESHIPS {
+                let simulation: Simulation = builder(20, 20, &surface_type)
+                    .seed_named(spaceship)
+                    .build()
+                    .unwrap();
+                let mut alive: HashSet<(u16, u16)> = positions(&simulation.generation);
+                for generation in 0..100 {
+                    let grid: NeighborGrid = NeighborGrid::new(
+                        &cells(&alive),
+                        20,
+                        20,
+                        &surface_type,
+                        Neighborhood::Moore,
+                        1,
+                    );
+                    let counts: HashMap<(u16, u16), u8> =
+                        reference_counts(&alive, (20, 20), &surface_type, Neighborhood::Moore, 1);
+                    for row in 0..20 {
+                        for column in 0..20 {
+                            assert_eq!(
+                                (
+                                    grid.is_alive(row, column),
+                                    grid.alive_neighbors(row, column)
+                                ),
+                                (
+                                    alive.contains(&(row, column)),
+                                    counts.get(&(row, column)).copied().unwrap_or(0)
+                                ),
+                                "{} on a {:?} surface at generation {}, cell ({}, {})",
+                                spaceship,
+                                surface_type,
+                                generation,
+                                row,
+                                column
+                            );
+                        }
+                    }
+                    alive = reference_next_generation(&alive, (20, 20), &surface_type);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sparse_counts_match_the_neighbor_grid_on_random_soups() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        for surface_type in surfaces() {
+            for neighborhood in [Neighborhood::Moore, Neighborhood::VonNeumann] {
+                for radius in [1, 2, 3] {
+                    for alive_probability in [0.02, 0.1, 0.5] {
+                        let generation: HashSet<Cell> = (0..17)
+                            .flat_map(|row| (0..23).map(move |column| (row, column)))
+                            .filter(|_| rng.gen_bool(alive_probability))
+                            .map(|(row, column)| Cell::new(ALIVE, row, column))
+                            .collect();
+                        let grid: NeighborGrid = NeighborGrid::new(
+                            &generation,
+                            17,
+                            23,
+                            &surface_type,
+                            neighborhood,
+                            radius,
+                        );
+                        let dense: HashMap<(u16, u16), u8> = (0..17)
+                            .flat_map(|row| (0..23).map(move |column| (row, column)))
+                            .map(|(row, column)| ((row, column), grid.alive_neighbors(row, column)))
+                            .filter(|&(_, count)| count > 0)
+                            .collect();
+                        assert_eq!(
+                            sparse_neighbor_counts(
+                                &generation,
+                                17,
+                                23,
+                                &surface_type,
+                                neighborhood,
+                                radius
+                            ),
+                            dense,
+                            "{:?} {:?} radius {} with density {}",
+                            surface_type,
+                            neighborhood,
+                            radius,
+                            alive_probability
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn only_generations_with_few_alive_cells_are_sparse() {
+        // A glider on a huge grid is sparse, but not on a grid barely larger than itself
+        assert!(is_sparse(5, 1000 * 1000, Neighborhood::Moore, 1));
+        assert!(!is_sparse(5, 10 * 10, Neighborhood::Moore, 1));
+        // A random soup is never sparse
+        assert!(!is_sparse(
+            1000 * 1000 / 2,
+            1000 * 1000,
+            Neighborhood::Moore,
+            1
+        ));
+        // Larger neighborhoods reach more cells, so they need fewer alive cells to be sparse
+        assert!(is_sparse(50, 100 * 100, Neighborhood::Moore, 1));
+        assert!(!is_sparse(50, 100 * 100, Neighborhood::Moore, 3));
+    }
+}
@@ -0,0 +1,237 @@
+//! Callbacks that observe every generation a simulation simulates.
+//!
+//! Observers are registered with `Simulation::on_generation` and called after each generation
+//! simulated by `simulate_generations` (and everything built on it, such as
+//! `simulate_generation` and `simulate_continuous_generations`), so population counts can be
+//! logged or frames exported without writing a custom simulation loop.
+//!
+//! # Example
+//! ```rust
+//! use simple_game_of_life::observer::ObserverId;
+//! use simple_game_of_life::simulation::Simulation;
+//! use simple_game_of_life::simulation_builder::SimulationBuilder;
+//! use std::sync::{Arc, Mutex};
+//!
+//! let mut simulation: Simulation = SimulationBuilder::new()
+//!     .height(10)
+//!     .width(10)
+//!     .seed_named("glider")
+//!     .build()
+//!     .unwrap();
+//!
+//! let iterations: Arc<Mutex<Vec<u128>>> = Arc::new(Mutex::new(Vec::new()));
+//! let log: Arc<Mutex<Vec<u128>>> = Arc::clone(&iterations);
+//! let id: ObserverId = simulation.on_generation(Box::new(move |simulation: &Simulation| {
+//!     log.lock().unwrap().push(simulation.iteration())
+//! }));
+//!
+//! // The observer is called once per generation, even within a single call
+//! simulation.simulate_generations(3);
+//! simulation.simulate_generation();
+//! assert_eq!(*iterations.lock().unwrap(), vec![1, 2, 3, 4]);
+//!
+//! assert!(simulation.remove_observer(id));
+//! simulation.simulate_generation();
+//! assert_eq!(iterations.lock().unwrap().len(), 4);
+//! ```
+
+use crate::simulation::Simulation;
+
+/// A function called with the simulation after each generation it simulates.
+pub type Observer = Box<dyn FnMut(&Simulation) + Send>;
+
+/// Identifies an observer registered with `Simulation::on_generation`, so it can be removed
+/// with `Simulation::remove_observer`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ObserverId(u64);
+
+/// The observers registered on a simulation, in the order they were registered.
+#[derive(Default)]
+pub(crate) struct Observers {
+    /// The identifier given to the next observer registered.
+    next_id: u64,
+    /// The registered observers along with their identifiers.
+    observers: Vec<(ObserverId, Observer)>,
+}
+
+impl Observers {
+    /// Registers an observer and returns its identifier.
+    fn add(&mut self, observer: Observer) -> ObserverId {
+        let id: ObserverId = ObserverId(self.next_id);
+        self.next_id += 1;
+        self.observers.push((id, observer));
+        id
+    }
+
+    /// Removes the observer with the given identifier, returning true if it was registered.
+    fn remove(&mut self, id: ObserverId) -> bool {
+        let count: usize = self.observers.len();
+        self.observers.retain(|(observer_id, _)| *observer_id != id);
+        self.observers.len() != count
+    }
+}
+
+impl Simulation {
+    /// Registers a function to be called with the simulation after every generation it
+    /// simulates.
+    ///
+    /// # Description
+    /// The observer is called once per generation by `simulate_generations`, so every function
+    /// that simulates through it (such as `simulate_generation`,
+    /// `simulate_continuous_generations`, and the `Iterator` implementation) notifies it too.
+    /// Observers are called in the order they were registered, after the iteration counter is
+    /// incremented and before the generation is drawn or printed.
+    ///
+    /// Observers only receive an immutable view of the simulation, so they can not change it
+    /// (or simulate it) while it is being simulated. Generations changed without being
+    /// simulated, such as by `apply_delta`, `rollback_generations`, or editing cells, do not
+    /// notify observers.
+    ///
+    /// # Arguments
+    /// * `observer` - The function to call with the simulation after each generation.
+    ///
+    /// # Returns
+    /// An `ObserverId` that removes the observer when passed to `remove_observer`.
+    ///
+    /// # Note
+    /// Observers are not cloned or serialized, so clones of the simulation (and deserialized
+    /// simulations) start without any. They are kept by `simulate_generations_async_with_channel`,
+    /// which is why they must be `Send`.
+    pub fn on_generation(&mut self, observer: Observer) -> ObserverId {
+        self.observers.add(observer)
+    }
+
+    /// Removes the observer with the given identifier, returning true if it was registered.
+    pub fn remove_observer(&mut self, id: ObserverId) -> bool {
+        self.observers.remove(id)
+    }
+
+    /// Calls every observer with the simulation.
+    pub(crate) fn notify_observers(&mut self) {
+        if self.observers.observers.is_empty() {
+            return;
+        }
+        // The observers are moved out while they are called, since they borrow the simulation
+        let mut observers: Observers = std::mem::take(&mut self.observers);
+        for (_, observer) in &mut observers.observers {
+            observer(self);
+        }
+        self.observers = observers;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation_builder::SimulationBuilder;
+    use std::sync::mpsc::Receiver;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    /// Returns a headless simulation of a glider on a 10x10 ball, which never finishes.
+    fn glider() -> Simulation {
+        SimulationBuilder::new()
+            .height(10)
+            .width(10)
+            .surface_ball()
+            .seed_named("glider")
+            .build()
+            .unwrap()
+    }
+
+    /// Registers an observer that records the iteration of every generation it is called with.
+    fn record_iterations(simulation: &mut Simulation) -> (ObserverId, Arc<Mutex<Vec<u128>>>) {
+        let iterations: Arc<Mutex<Vec<u128>>> = Arc::new(Mutex::new(Vec::new()));
+        let log: Arc<Mutex<Vec<u128>>> = Arc::clone(&iterations);
+        let id: ObserverId = simulation.on_generation(Box::new(move |simulation: &Simulation| {
+            log.lock().unwrap().push(simulation.iteration())
+        }));
+        (id, iterations)
+    }
+
+    #[test]
+    fn observers_are_called_once_per_generation() {
+        let mut simulation: Simulation = glider();
+        let (_, iterations) = record_iterations(&mut simulation);
+        simulation.simulate_generations(5);
+        assert_eq!(*iterations.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+
+        simulation.simulate_generation();
+        simulation.simulate_generations(0);
+        simulation.simulate_continuous_generations(Duration::ZERO, false, Some(3));
+        simulation.by_ref().take(2).for_each(drop);
+        assert_eq!(*iterations.lock().unwrap(), (1..=11).collect::<Vec<u128>>());
+    }
+
+    #[test]
+    fn observers_are_called_in_the_order_they_were_registered() {
+        let mut simulation: Simulation = glider();
+        let calls: Arc<Mutex<Vec<&str>>> = Arc::new(Mutex::new(Vec::new()));
+        for name in ["first", "second", "third"] {
+            let log: Arc<Mutex<Vec<&str>>> = Arc::clone(&calls);
+            simulation.on_generation(Box::new(move |_| log.lock().unwrap().push(name)));
+        }
+        simulation.simulate_generations(2);
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec!["first", "second", "third", "first", "second", "third"]
+        );
+    }
+
+    #[test]
+    fn removed_observers_are_not_called() {
+        let mut simulation: Simulation = glider();
+        let (first, first_iterations) = record_iterations(&mut simulation);
+        let (second, second_iterations) = record_iterations(&mut simulation);
+        assert_ne!(first, second);
+        simulation.simulate_generation();
+
+        assert!(simulation.remove_observer(first));
+        assert!(!simulation.remove_observer(first));
+        simulation.simulate_generations(2);
+        assert_eq!(*first_iterations.lock().unwrap(), vec![1]);
+        assert_eq!(*second_iterations.lock().unwrap(), vec![1, 2, 3]);
+
+        // Identifiers are not reused once their observer is removed
+        let (third, _) = record_iterations(&mut simulation);
+        assert_ne!(third, first);
+        assert!(simulation.remove_observer(second));
+        assert!(simulation.remove_observer(third));
+        assert!(simulation.observers.observers.is_empty());
+    }
+
+    #[test]
+    fn edits_do_not_notify_observers() {
+        let mut simulation: Simulation = glider();
+        let (_, iterations) = record_iterations(&mut simulation);
+        simulation.simulate_generations(2);
+        simulation.rollback_generations(1);
+        simulation.set_cell(0, 0, true).unwrap();
+        simulation.shift(1, 1);
+        simulation.flip_horizontal();
+        simulation.reset();
+        assert_eq!(*iterations.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn clones_start_without_observers() {
+        let mut simulation: Simulation = glider();
+        let (_, iterations) = record_iterations(&mut simulation);
+        let mut clone: Simulation = simulation.clone();
+        clone.simulate_generations(3);
+        assert!(iterations.lock().unwrap().is_empty());
+        simulation.simulate_generation();
+        assert_eq!(*iterations.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn observers_are_kept_by_asynchronous_simulations() {
+        let mut simulation: Simulation = glider();
+        let (_, iterations) = record_iterations(&mut simulation);
+        let receiver: Receiver<String> =
+            simulation.simulate_generations_async_with_channel(4, Duration::ZERO);
+        assert_eq!(receiver.iter().count(), 4);
+        // The channel is closed after the observers of the last generation are called
+        assert_eq!(*iterations.lock().unwrap(), vec![1, 2, 3, 4]);
+    }
+}
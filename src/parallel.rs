@@ -0,0 +1,84 @@
+//! Parallel computation of generations, enabled with the `parallel` feature.
+//!
+//! The next state of every cell only depends on the previous generation, so the grid is split
+//! into rows that are evaluated on Rayon's thread pool. Each row produces a list of the cells
+//! that are born or die, and the lists are applied to a copy of the previous generation once
+//! every row has been evaluated.
+
+use std::collections::HashSet;
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::cell::Cell;
+use crate::cell::CellState::{ALIVE, DEAD};
+use crate::neighbors::NeighborGrid;
+use crate::rule::Rule;
+use crate::simulation::{Neighborhood, SurfaceType};
+
+/// Computes the next generation of a grid in parallel.
+///
+/// # Description
+/// This produces exactly the same generation as `Simulation::next_generation` does without the
+/// `parallel` feature. The rows of the grid are evaluated in parallel, and for each cell the
+/// following steps are performed:
+///
+/// 1. Count the number of alive neighbors for the cell within the rule's radius, wrapping
+///    around the edges the surface type wraps on.
+/// 2. If the cell is alive and the rule does not allow it to survive, record that it dies.
+/// 3. If the cell is dead and the rule allows it to be born, record that it is born.
+///
+/// The recorded changes are then applied to a copy of the generation.
+///
+/// # Arguments
+/// * `generation` - The alive cells of the current generation.
+/// * `rows` - The number of rows in the grid.
+/// * `columns` - The number of columns in the grid.
+/// * `surface_type` - The surface type of the grid.
+/// * `rule` - The rule used to compute the next generation.
+/// * `neighborhood` - The neighborhood used to count alive neighbors.
+///
+/// # Returns
+/// A `HashSet` of `Cell` instances representing the alive cells in the next generation.
+pub(crate) fn next_generation(
+    generation: &HashSet<Cell>,
+    rows: u16,
+    columns: u16,
+    surface_type: &SurfaceType,
+    rule: &Rule,
+    neighborhood: Neighborhood,
+) -> HashSet<Cell> {
+    let grid: NeighborGrid = NeighborGrid::new(
+        generation,
+        rows,
+        columns,
+        surface_type,
+        neighborhood,
+        rule.radius,
+    );
+    let grid: &NeighborGrid = &grid;
+    let changes: Vec<Cell> = (0..rows)
+        .into_par_iter()
+        .flat_map_iter(|row| {
+            (0..columns).filter_map(move |column| {
+                let is_alive: bool = grid.is_alive(row, column);
+                let alive_neighbors: u8 = grid.alive_neighbors(row, column);
+                if is_alive && !rule.survives(alive_neighbors) {
+                    Some(Cell::new(DEAD, row, column))
+                } else if !is_alive && rule.births(alive_neighbors) {
+                    Some(Cell::new(ALIVE, row, column))
+                } else {
+                    None
+                }
+            })
+        })
+        .collect();
+    let mut new_generation: HashSet<Cell> = generation.clone();
+    for cell in changes {
+        if cell.is_alive() {
+            new_generation.insert(cell);
+        } else {
+            new_generation.remove(&Cell::new(ALIVE, cell.row, cell.column));
+        }
+    }
+    new_generation
+}
@@ -0,0 +1,1664 @@
+//! Extraction, transformation, and comparison of Game of Life patterns.
+//!
+//! # Example
+//! ```rust,no_run
+//! use simple_game_of_life::patterns::{align, Pattern, PatternDef};
+//!
+//! // A toad (period 2 oscillator) in its first phase
+//! let toad: PatternDef = PatternDef::from_seed_string("toad", "-******-", 4).unwrap();
+//!
+//! // The same toad in its second phase, mirrored left to right
+//! let candidate: Pattern =
+//!     Pattern::from_seed_string("-*--*--**--*--*-", 4).unwrap();
+//!
+//! // Find which phase of the toad the candidate is in and how it is oriented
+//! println!("{:?}", align(&candidate, &toad));
+//! ```
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::cell::CellState::ALIVE;
+use crate::cell::{Cell, ALIVE_CHAR, DEAD_CHAR};
+use crate::lexicon::{LexiconEntry, LEXICON};
+use crate::simulation::{generation_from_string, normalize_seed, Simulation, SurfaceType};
+use crate::simulation_builder::SimulationBuilder;
+
+/// The number of dead cells placed around a pattern when simulating its phases.
+const PHASE_PADDING: u16 = 16;
+/// The maximum number of generations simulated when searching for a pattern's period.
+const MAXIMUM_PERIOD: u16 = 64;
+
+/// Represents one of the eight rotations and reflections of a square.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Transform {
+    /// No transformation.
+    Identity,
+    /// A rotation of 90 degrees clockwise.
+    Rotate90,
+    /// A rotation of 180 degrees.
+    Rotate180,
+    /// A rotation of 270 degrees clockwise (90 degrees counterclockwise).
+    Rotate270,
+    /// A reflection across the vertical axis (left and right are swapped).
+    FlipHorizontal,
+    /// A reflection across the horizontal axis (top and bottom are swapped).
+    FlipVertical,
+    /// A reflection across the main diagonal (rows and columns are swapped).
+    FlipDiagonal,
+    /// A reflection across the anti-diagonal.
+    FlipAntiDiagonal,
+}
+
+impl Transform {
+    /// Every rotation and reflection, starting with the identity.
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::Rotate90,
+        Transform::Rotate180,
+        Transform::Rotate270,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+        Transform::FlipDiagonal,
+        Transform::FlipAntiDiagonal,
+    ];
+
+    /// Returns true if the transform swaps the number of rows and columns.
+    pub fn swaps_dimensions(&self) -> bool {
+        matches!(
+            self,
+            Transform::Rotate90
+                | Transform::Rotate270
+                | Transform::FlipDiagonal
+                | Transform::FlipAntiDiagonal
+        )
+    }
+
+    /// Returns the single transform equivalent to applying this transform followed by the
+    /// given transform.
+    pub fn then(self, next: Transform) -> Transform {
+        // An L-tetromino has no symmetry, so each of its eight images identifies a transform
+        let probe: Pattern = Pattern::from_cells([(0, 0), (1, 0), (1, 1), (1, 2)]);
+        let composed: Pattern = probe.transformed(self).transformed(next);
+        Transform::ALL
+            .into_iter()
+            .find(|transform| probe.transformed(*transform) == composed)
+            .unwrap()
+    }
+
+    /// Maps a cell position within a grid of the given size to its transformed position.
+    pub(crate) fn apply(&self, row: u16, column: u16, rows: u16, columns: u16) -> (u16, u16) {
+        let last_row: u16 = rows - 1;
+        let last_column: u16 = columns - 1;
+        match self {
+            Transform::Identity => (row, column),
+            Transform::Rotate90 => (column, last_row - row),
+            Transform::Rotate180 => (last_row - row, last_column - column),
+            Transform::Rotate270 => (last_column - column, row),
+            Transform::FlipHorizontal => (row, last_column - column),
+            Transform::FlipVertical => (last_row - row, column),
+            Transform::FlipDiagonal => (column, row),
+            Transform::FlipAntiDiagonal => (last_column - column, last_row - row),
+        }
+    }
+}
+
+/// Describes how a pattern inserted into a simulation treats the cells already there.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CollisionMode {
+    /// The pattern's bounding box is cleared before the pattern is inserted.
+    Overwrite,
+    /// The pattern's alive cells are added, and alive cells already there are kept.
+    Merge,
+    /// The pattern is not inserted if any of its alive cells are already alive.
+    Reject,
+}
+
+/// A pattern of alive cells cropped to its bounding box.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pattern {
+    /// The number of rows in the pattern's bounding box.
+    pub(crate) rows: u16,
+    /// The number of columns in the pattern's bounding box.
+    pub(crate) columns: u16,
+    /// The `(row, column)` positions of the alive cells relative to the bounding box.
+    pub(crate) cells: HashSet<(u16, u16)>,
+}
+
+/// The former name of `Pattern`, kept so that existing code continues to compile.
+pub type ExtractedPattern = Pattern;
+
+impl Pattern {
+    /// Creates a new `Pattern` from alive cell positions, moving the cells so that the
+    /// top-left corner of their bounding box is at `(0, 0)`.
+    pub fn from_cells<I: IntoIterator<Item = (u16, u16)>>(cells: I) -> Pattern {
+        let cells: Vec<(u16, u16)> = cells.into_iter().collect();
+        if cells.is_empty() {
+            return Pattern {
+                rows: 0,
+                columns: 0,
+                cells: HashSet::new(),
+            };
+        }
+        let minimum_row: u16 = cells.iter().map(|(row, _)| *row).min().unwrap();
+        let maximum_row: u16 = cells.iter().map(|(row, _)| *row).max().unwrap();
+        let minimum_column: u16 = cells.iter().map(|(_, column)| *column).min().unwrap();
+        let maximum_column: u16 = cells.iter().map(|(_, column)| *column).max().unwrap();
+        Pattern {
+            rows: maximum_row - minimum_row + 1,
+            columns: maximum_column - minimum_column + 1,
+            cells: cells
+                .into_iter()
+                .map(|(row, column)| (row - minimum_row, column - minimum_column))
+                .collect(),
+        }
+    }
+
+    /// Creates a new `Pattern` from the alive cells of a seed string with the given
+    /// number of columns, in any `SeedAlphabet` (see `generation_from_string`).
+    pub fn from_seed_string(seed: &str, columns: u16) -> Result<Pattern, String> {
+        if columns == 0 {
+            return Err("A seed string must have at least one column".to_string());
+        }
+        let seed: String = normalize_seed(seed)?;
+        let rows: u16 = (seed.len() / columns as usize)
+            .try_into()
+            .map_err(|_| format!("The seed string has more than {} rows", u16::MAX))?;
+        let generation = generation_from_string(seed, rows, columns)?;
+        Ok(Pattern::from_cells(
+            generation.iter().map(|cell| (cell.row, cell.column)),
+        ))
+    }
+
+    /// Creates a new `Pattern` from a pattern in the RLE format (such as `"bo$2bo$3o!"`), in
+    /// the same way as `from_golly_clipboard`.
+    pub fn from_rle(rle: &str) -> Result<Pattern, String> {
+        let (_rows, _columns, cells) = decode_rle(rle)?;
+        Ok(Pattern::from_cells(cells))
+    }
+
+    /// Creates a new `Pattern` from the current generation of a simulation.
+    pub fn from_simulation(simulation: &Simulation) -> Pattern {
+        Pattern::from_cells(
+            simulation
+                .generation
+                .iter()
+                .map(|cell| (cell.row, cell.column)),
+        )
+    }
+
+    /// Returns the number of rows in the pattern's bounding box.
+    pub fn height(&self) -> u16 {
+        self.rows
+    }
+
+    /// Returns the number of columns in the pattern's bounding box.
+    pub fn width(&self) -> u16 {
+        self.columns
+    }
+
+    /// Returns the number of alive cells in the pattern.
+    pub fn alive_count(&self) -> u64 {
+        self.cells.len() as u64
+    }
+
+    /// Returns true if the cell at the given position of the pattern is alive.
+    pub fn is_alive(&self, row: u16, column: u16) -> bool {
+        self.cells.contains(&(row, column))
+    }
+
+    /// Renders the pattern as a seed string for a grid of the given size, with the top-left
+    /// corner of the pattern placed at the given offset. Cells that fall outside of the grid
+    /// are left out.
+    pub(crate) fn seed_string(
+        &self,
+        rows: u16,
+        columns: u16,
+        row_offset: u16,
+        column_offset: u16,
+    ) -> String {
+        (0..rows)
+            .flat_map(|row| (0..columns).map(move |column| (row, column)))
+            .map(|(row, column)| {
+                let alive: bool = row >= row_offset
+                    && column >= column_offset
+                    && self.is_alive(row - row_offset, column - column_offset);
+                if alive {
+                    ALIVE_CHAR
+                } else {
+                    DEAD_CHAR
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a copy of the pattern with the given rotation or reflection applied.
+    pub fn transformed(&self, transform: Transform) -> Pattern {
+        let (rows, columns) = if transform.swaps_dimensions() {
+            (self.columns, self.rows)
+        } else {
+            (self.rows, self.columns)
+        };
+        Pattern {
+            rows,
+            columns,
+            cells: self
+                .cells
+                .iter()
+                .map(|(row, column)| transform.apply(*row, *column, self.rows, self.columns))
+                .collect(),
+        }
+    }
+
+    /// Returns a copy of the pattern rotated 90 degrees clockwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::patterns::Pattern;
+    ///
+    /// let glider: Pattern = Pattern::from_rle("bo$2bo$3o!").unwrap();
+    /// let rotated: Pattern = glider.rotate_cw();
+    /// assert_eq!(rotated, Pattern::from_rle("o$obo$2o!").unwrap());
+    /// assert_eq!(rotated.rotate_cw().rotate_cw().rotate_cw(), glider);
+    /// assert_eq!(glider.rotate_ccw(), rotated.rotate_180());
+    /// assert_eq!(glider.flip_vertical().flip_horizontal(), glider.rotate_180());
+    /// ```
+    pub fn rotate_cw(&self) -> Pattern {
+        self.transformed(Transform::Rotate90)
+    }
+
+    /// Returns a copy of the pattern rotated 90 degrees counterclockwise.
+    pub fn rotate_ccw(&self) -> Pattern {
+        self.transformed(Transform::Rotate270)
+    }
+
+    /// Returns a copy of the pattern rotated 180 degrees.
+    pub fn rotate_180(&self) -> Pattern {
+        self.transformed(Transform::Rotate180)
+    }
+
+    /// Returns a copy of the pattern mirrored left to right.
+    pub fn flip_horizontal(&self) -> Pattern {
+        self.transformed(Transform::FlipHorizontal)
+    }
+
+    /// Returns a copy of the pattern mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Pattern {
+        self.transformed(Transform::FlipVertical)
+    }
+}
+
+impl Simulation {
+    /// Inserts a pattern into the current generation with its top-left corner at the given
+    /// position.
+    ///
+    /// # Description
+    /// On edges that wrap, cells of the pattern that extend past the edge wrap around to the
+    /// other side of the grid. On bounded edges, the pattern must fit within the grid (see
+    /// `insert_pattern_clipped` to leave out the cells that do not fit instead). If the
+    /// simulation is set to display in a window, the current generation is redrawn afterwards.
+    ///
+    /// # Arguments
+    /// * `pattern` - The pattern to insert.
+    /// * `row` - The row of the pattern's top-left corner.
+    /// * `column` - The column of the pattern's top-left corner.
+    /// * `collision_mode` - How the pattern treats the cells already in the grid.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the pattern was inserted.
+    /// * `Err(String)` - An error message if the position is outside of the grid, the pattern
+    ///   does not fit on a bounded edge, or the collision mode is `Reject` and the pattern
+    ///   overlaps alive cells.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::patterns::{CollisionMode, Pattern};
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(5)
+    ///     .width(5)
+    ///     .seed(&"-".repeat(25))
+    ///     .build()
+    ///     .unwrap();
+    /// let glider: Pattern = Pattern::from_rle("bo$2bo$3o!").unwrap();
+    ///
+    /// // The glider does not fit in the bottom-right corner of a rectangle
+    /// assert!(simulation
+    ///     .insert_pattern(&glider, 3, 3, CollisionMode::Merge)
+    ///     .is_err());
+    /// simulation
+    ///     .insert_pattern_clipped(&glider, 3, 3, CollisionMode::Merge)
+    ///     .unwrap();
+    /// assert_eq!(simulation.generation_string(), "-".repeat(19) + "*" + &"-".repeat(5));
+    /// ```
+    pub fn insert_pattern(
+        &mut self,
+        pattern: &Pattern,
+        row: u16,
+        column: u16,
+        collision_mode: CollisionMode,
+    ) -> Result<(), String> {
+        self.stamp_pattern(pattern, row, column, collision_mode, false)
+    }
+
+    /// Inserts a pattern into the current generation with its top-left corner at the given
+    /// position, leaving out the cells of the pattern that do not fit within the grid.
+    ///
+    /// # Description
+    /// This is the same as `insert_pattern`, except that cells of the pattern that extend past
+    /// a bounded edge (or, on a `Custom` surface, that its function maps to `None`) are clipped
+    /// instead of causing an error. On edges that wrap, cells still wrap around.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the pattern was inserted.
+    /// * `Err(String)` - An error message if the position is outside of the grid, or the
+    ///   collision mode is `Reject` and the pattern overlaps alive cells.
+    pub fn insert_pattern_clipped(
+        &mut self,
+        pattern: &Pattern,
+        row: u16,
+        column: u16,
+        collision_mode: CollisionMode,
+    ) -> Result<(), String> {
+        self.stamp_pattern(pattern, row, column, collision_mode, true)
+    }
+
+    /// Inserts a pattern into the current generation, clipping the cells that do not fit if
+    /// `clip` is true (see `insert_pattern` and `insert_pattern_clipped`).
+    fn stamp_pattern(
+        &mut self,
+        pattern: &Pattern,
+        row: u16,
+        column: u16,
+        collision_mode: CollisionMode,
+        clip: bool,
+    ) -> Result<(), String> {
+        let box_cells: Vec<(u16, u16)> = self.place_cells(
+            (0..pattern.rows).flat_map(|row| (0..pattern.columns).map(move |column| (row, column))),
+            pattern,
+            row,
+            column,
+            clip,
+        )?;
+        let alive_cells: Vec<(u16, u16)> =
+            self.place_cells(pattern.cells.iter().copied(), pattern, row, column, clip)?;
+        match collision_mode {
+            CollisionMode::Overwrite => {
+                for (row, column) in box_cells {
+                    self.generation.remove(&Cell::new(ALIVE, row, column));
+                }
+            }
+            CollisionMode::Merge => {}
+            CollisionMode::Reject => {
+                if let Some((row, column)) = alive_cells.iter().find(|(row, column)| {
+                    self.generation.contains(&Cell::new(ALIVE, *row, *column))
+                }) {
+                    return Err(format!(
+                        "The pattern overlaps the alive cell at ({}, {})",
+                        row, column
+                    ));
+                }
+            }
+        }
+        for (row, column) in alive_cells {
+            self.generation.insert(Cell::new(ALIVE, row, column));
+        }
+        if self.display {
+            self.draw_generation();
+        }
+        Ok(())
+    }
+
+    /// Maps positions within a pattern to their positions in the grid when the pattern's
+    /// top-left corner is placed at the given position (see `insert_pattern`).
+    ///
+    /// On a `Mobius` or `KleinBottle` surface, the cells that wrap past the right edge are
+    /// flipped vertically, the same as cells that move past it while simulating. On a `Custom`
+    /// surface, every position is wrapped with the surface's function, and the pattern does not
+    /// fit if any of them is mapped to `None`.
+    ///
+    /// If `clip` is true, the positions that do not fit are left out instead of causing an
+    /// error.
+    pub(crate) fn place_cells<I: IntoIterator<Item = (u16, u16)>>(
+        &self,
+        cells: I,
+        pattern: &Pattern,
+        row: u16,
+        column: u16,
+        clip: bool,
+    ) -> Result<Vec<(u16, u16)>, String> {
+        self.check_bounds(row, column)?;
+        let does_not_fit: String = format!(
+            "The {}x{} pattern does not fit within the grid at ({}, {})",
+            pattern.rows, pattern.columns, row, column
+        );
+        if let SurfaceType::Custom(wrap) = &self.surface_type {
+            let placed = cells.into_iter().map(|(pattern_row, pattern_column)| {
+                wrap(
+                    row as i32 + pattern_row as i32,
+                    column as i32 + pattern_column as i32,
+                    self.rows,
+                    self.columns,
+                )
+                .filter(|&(row, column)| row < self.rows && column < self.columns)
+            });
+            return if clip {
+                Ok(placed.flatten().collect())
+            } else {
+                placed
+                    .map(|position| position.ok_or_else(|| does_not_fit.clone()))
+                    .collect()
+            };
+        }
+        let wraps_vertically: bool = self.surface_type.wraps_vertically();
+        let wraps_horizontally: bool = self.surface_type.wraps_horizontally();
+        let fits_vertically: bool = row as u32 + pattern.rows as u32 <= self.rows as u32;
+        let fits_horizontally: bool = column as u32 + pattern.columns as u32 <= self.columns as u32;
+        if !clip
+            && ((!fits_vertically && !wraps_vertically)
+                || (!fits_horizontally && !wraps_horizontally))
+        {
+            return Err(does_not_fit);
+        }
+        let flips: bool = self.surface_type.flips_on_horizontal_wrap();
+        Ok(cells
+            .into_iter()
+            .filter(|(pattern_row, pattern_column)| {
+                (wraps_vertically || row as u32 + (*pattern_row as u32) < self.rows as u32)
+                    && (wraps_horizontally
+                        || column as u32 + (*pattern_column as u32) < self.columns as u32)
+            })
+            .map(|(pattern_row, pattern_column)| {
+                let placed_row: u16 = ((row as u32 + pattern_row as u32) % self.rows as u32) as u16;
+                let placed_column: u32 = column as u32 + pattern_column as u32;
+                let laps: u32 = placed_column / self.columns as u32;
+                let placed_column: u16 = (placed_column % self.columns as u32) as u16;
+                if flips && !laps.is_multiple_of(2) {
+                    (self.rows - 1 - placed_row, placed_column)
+                } else {
+                    (placed_row, placed_column)
+                }
+            })
+            .collect())
+    }
+}
+
+/// The kinds of patterns found in the pattern lexicon.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PatternCategory {
+    /// A pattern that does not change from one generation to the next.
+    StillLife,
+    /// A pattern that repeats itself in place after a fixed number of generations.
+    Oscillator,
+    /// A pattern that repeats itself at a different position after a fixed number of
+    /// generations.
+    Spaceship,
+    /// A small pattern that takes a long time to stabilize.
+    Methuselah,
+    /// A pattern that repeatedly emits spaceships.
+    Gun,
+}
+
+/// A reference pattern, such as an oscillator or spaceship, with every phase of its period.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatternDef {
+    /// The name of the pattern.
+    pub(crate) name: String,
+    /// A short description of the pattern.
+    pub(crate) description: String,
+    /// The kind of pattern, if known.
+    pub(crate) category: Option<PatternCategory>,
+    /// Each phase of the pattern in the order they occur, starting with the defining phase.
+    pub(crate) phases: Vec<Pattern>,
+}
+
+impl PatternDef {
+    /// Creates a new `PatternDef` from a name and a list of phases.
+    pub fn new(name: &str, phases: Vec<Pattern>) -> PatternDef {
+        PatternDef {
+            name: String::from(name),
+            description: String::new(),
+            category: None,
+            phases,
+        }
+    }
+
+    /// Creates a new `PatternDef` from a seed string, simulating it to find each of its phases.
+    ///
+    /// # Description
+    /// This function places the pattern described by the seed string in the middle of a padded
+    /// `Rectangle` simulation and simulates it until its bounding-box-cropped shape repeats the
+    /// initial phase. Every distinct phase seen along the way is recorded in order.
+    ///
+    /// Since phases are compared after cropping, spaceships are treated the same as
+    /// oscillators: their phases are recorded without their displacement.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the pattern.
+    /// * `seed` - A string representation of the pattern's initial phase.
+    /// * `columns` - The number of columns in the seed string.
+    ///
+    /// # Returns
+    /// * `Ok(PatternDef)` - The pattern with all of its phases.
+    /// * `Err(String)` - An error message if the seed string is invalid or the pattern does not
+    ///   return to its initial phase within 64 generations.
+    pub fn from_seed_string(name: &str, seed: &str, columns: u16) -> Result<PatternDef, String> {
+        let initial_phase: Pattern = Pattern::from_seed_string(seed, columns)?;
+        match simulate_phases(&initial_phase)? {
+            Some(phases) => Ok(PatternDef::new(name, phases)),
+            None => Err(format!(
+                "The pattern \"{}\" did not return to its initial phase within {} generations",
+                name, MAXIMUM_PERIOD
+            )),
+        }
+    }
+
+    /// Returns the name of the pattern.
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Returns the short description of the pattern.
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    /// Returns the kind of pattern, if known.
+    pub fn category(&self) -> Option<PatternCategory> {
+        self.category
+    }
+
+    /// Returns the period of the pattern (the number of phases).
+    pub fn period(&self) -> usize {
+        self.phases.len()
+    }
+
+    /// Returns every phase of the pattern in the order they occur.
+    pub fn phases(&self) -> Vec<Pattern> {
+        self.phases.clone()
+    }
+}
+
+/// Simulates a pattern in the middle of a padded `Rectangle` simulation until its
+/// bounding-box-cropped shape repeats the initial phase.
+///
+/// # Returns
+/// * `Ok(Some(Vec<Pattern>))` - Every phase of the pattern, starting with the initial
+///   phase.
+/// * `Ok(None)` - The pattern did not return to its initial phase within 64 generations.
+/// * `Err(String)` - An error message if the padded simulation could not be built.
+fn simulate_phases(initial_phase: &Pattern) -> Result<Option<Vec<Pattern>>, String> {
+    let rows: u16 = initial_phase.rows + 2 * PHASE_PADDING;
+    let columns: u16 = initial_phase.columns + 2 * PHASE_PADDING;
+    let mut simulation: Simulation = SimulationBuilder::new()
+        .height(rows)
+        .width(columns)
+        .surface_rectangle()
+        .seed(&initial_phase.seed_string(rows, columns, PHASE_PADDING, PHASE_PADDING))
+        .build()?;
+    let mut phases: Vec<Pattern> = vec![initial_phase.clone()];
+    for _ in 0..MAXIMUM_PERIOD {
+        simulation.generation = simulation.next_generation();
+        let phase: Pattern = Pattern::from_simulation(&simulation);
+        if phase == *initial_phase {
+            return Ok(Some(phases));
+        }
+        phases.push(phase);
+    }
+    Ok(None)
+}
+
+/// Describes how a candidate pattern lines up with a reference pattern.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Alignment {
+    /// The index of the reference pattern's phase that the candidate matches.
+    pub phase: usize,
+    /// The transform that makes the candidate identical to that phase.
+    pub transform: Transform,
+}
+
+/// Finds the phase, rotation, and reflection that make a candidate pattern identical to a
+/// reference pattern.
+///
+/// # Description
+/// This function searches every phase of the reference pattern and, for each phase, every one
+/// of the four rotations and two reflections (eight transforms in total) of the candidate. The
+/// first transform that makes the candidate identical to a phase is returned.
+///
+/// Phases are searched in order and transforms are searched in the order of `Transform::ALL`,
+/// so an untransformed match in the earliest phase is always preferred.
+///
+/// # Arguments
+/// * `candidate` - The pattern to identify, such as one extracted from a simulation.
+/// * `reference` - The library pattern to compare the candidate against.
+///
+/// # Returns
+/// The `Alignment` describing the matching phase and transform, or `None` if the candidate
+/// does not match any phase of the reference in any orientation.
+pub fn align(candidate: &Pattern, reference: &PatternDef) -> Option<Alignment> {
+    for (phase_index, phase) in reference.phases.iter().enumerate() {
+        if phase.alive_count() != candidate.alive_count() {
+            continue;
+        }
+        for transform in Transform::ALL {
+            if candidate.transformed(transform) == *phase {
+                return Some(Alignment {
+                    phase: phase_index,
+                    transform,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// The maximum length of a line of pattern data in Golly's clipboard format.
+const GOLLY_LINE_LENGTH: usize = 70;
+
+/// The number of rows, the number of columns, and the alive cells of a decoded RLE string.
+pub(crate) type DecodedRle = (u16, u16, HashSet<(u16, u16)>);
+
+/// Encodes the alive cells of a grid as the pattern data of an RLE string.
+///
+/// # Description
+/// This function run-length encodes each row of the grid with `b` for dead cells, `o` for
+/// alive cells, and `$` at the end of each row, terminating the pattern with `!`. Runs longer
+/// than one cell are prefixed with their length.
+///
+/// Dead cells at the end of a row are omitted, consecutive empty rows are combined into a
+/// single run of `$`, and empty rows at the end of the grid are omitted entirely.
+///
+/// # Arguments
+/// * `cells` - The `(row, column)` positions of the alive cells.
+/// * `rows` - The number of rows in the grid.
+/// * `columns` - The number of columns in the grid.
+///
+/// # Returns
+/// A `Vec` of the encoded runs (such as `"3o"` or `"2$"`) in order, ending with `"!"`, so
+/// callers can wrap lines without splitting a run.
+pub(crate) fn encode_rle_runs(cells: &HashSet<(u16, u16)>, rows: u16, columns: u16) -> Vec<String> {
+    let mut runs: Vec<String> = Vec::new();
+    let mut pending_rows: u32 = 0;
+    for row in 0..rows {
+        let last_alive_column: Option<u16> = (0..columns)
+            .rev()
+            .find(|column| cells.contains(&(row, *column)));
+        let last_alive_column: u16 = match last_alive_column {
+            Some(column) => column,
+            None => {
+                pending_rows += 1;
+                continue;
+            }
+        };
+        if pending_rows > 0 {
+            runs.push(rle_run(pending_rows, '$'));
+        }
+        let mut column: u16 = 0;
+        while column <= last_alive_column {
+            let alive: bool = cells.contains(&(row, column));
+            let mut length: u32 = 0;
+            while column <= last_alive_column && cells.contains(&(row, column)) == alive {
+                length += 1;
+                column += 1;
+            }
+            runs.push(rle_run(length, if alive { 'o' } else { 'b' }));
+        }
+        pending_rows = 1;
+    }
+    runs.push(String::from("!"));
+    runs
+}
+
+/// Formats a single RLE run, omitting the length when it is 1.
+fn rle_run(length: u32, tag: char) -> String {
+    if length == 1 {
+        tag.to_string()
+    } else {
+        format!("{}{}", length, tag)
+    }
+}
+
+/// Decodes an RLE string into its dimensions and alive cells.
+///
+/// # Description
+/// This function is liberal in what it accepts so that patterns copied from other tools can be
+/// pasted directly:
+///
+/// * Lines starting with `#` are treated as comments and ignored.
+/// * The `x = <columns>, y = <rows>` header line is optional, and any other header entries
+///   (such as the rule) are ignored.
+/// * Whitespace and line breaks within the pattern data are ignored.
+/// * `b` and `.` are dead cells, `o` and any other letter are alive cells, and `$` ends a row.
+/// * Anything after the terminating `!` is ignored, and the `!` itself is optional.
+///
+/// If there is no header, or the header is smaller than the pattern data, the dimensions are
+/// grown to fit every alive cell.
+///
+/// # Arguments
+/// * `rle` - The RLE string to decode.
+///
+/// # Returns
+/// * `Ok((u16, u16, HashSet<(u16, u16)>))` - The number of rows, the number of columns, and
+///   the `(row, column)` positions of the alive cells.
+/// * `Err(String)` - An error message if the header or pattern data is malformed.
+pub(crate) fn decode_rle(rle: &str) -> Result<DecodedRle, String> {
+    let mut header_rows: u32 = 0;
+    let mut header_columns: u32 = 0;
+    let mut data: String = String::new();
+    for line in rle.lines() {
+        let line: &str = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('x') {
+            for entry in line.split(',') {
+                let (key, value) = match entry.split_once('=') {
+                    Some((key, value)) => (key.trim(), value.trim()),
+                    None => return Err(format!("Unexpected RLE header entry of \"{}\"", entry)),
+                };
+                let parse_dimension = |value: &str| {
+                    value
+                        .parse::<u32>()
+                        .map_err(|_| format!("Unexpected RLE dimension of \"{}\"", value))
+                };
+                match key {
+                    "x" => header_columns = parse_dimension(value)?,
+                    "y" => header_rows = parse_dimension(value)?,
+                    _ => {}
+                }
+            }
+            continue;
+        }
+        data.push_str(line);
+    }
+    let mut cells: HashSet<(u16, u16)> = HashSet::new();
+    let mut row: u32 = 0;
+    let mut column: u32 = 0;
+    let mut rows: u32 = header_rows;
+    let mut columns: u32 = header_columns;
+    let mut count: Option<u32> = None;
+    for character in data.chars() {
+        if let Some(digit) = character.to_digit(10) {
+            count = Some(count.unwrap_or(0) * 10 + digit);
+            if count > Some(u16::MAX as u32) {
+                return Err(String::from("RLE run lengths must fit within the grid"));
+            }
+            continue;
+        }
+        let length: u32 = count.take().unwrap_or(1);
+        match character {
+            '!' => break,
+            '$' => {
+                row += length;
+                column = 0;
+            }
+            'b' | '.' => column += length,
+            character if character.is_ascii_alphabetic() => {
+                for _ in 0..length {
+                    if row > u16::MAX as u32 || column > u16::MAX as u32 {
+                        return Err(String::from("RLE patterns must fit within the grid"));
+                    }
+                    cells.insert((row as u16, column as u16));
+                    column += 1;
+                }
+                rows = rows.max(row + 1);
+                columns = columns.max(column);
+            }
+            character if character.is_whitespace() => {}
+            _ => {
+                return Err(format!(
+                    "Unexpected RLE character of \'{}\', patterns must only contain run counts, letters, \'$\', or \'!\'",
+                    character
+                ));
+            }
+        }
+    }
+    if rows > u16::MAX as u32 || columns > u16::MAX as u32 {
+        return Err(String::from("RLE patterns must fit within the grid"));
+    }
+    Ok((rows as u16, columns as u16, cells))
+}
+
+/// Renders the current generation of a simulation in the RLE flavor accepted by Golly's
+/// clipboard.
+///
+/// # Description
+/// The output starts with a `#C` comment crediting this crate, followed by the
+/// `x = <columns>, y = <rows>, rule = <rule>` header, followed by the pattern data wrapped so
+/// that no line is longer than 70 characters. Runs are never split across lines.
+///
+/// The whole grid is encoded, so pasting the result into Golly places every cell at the same
+/// position relative to the top-left corner of the grid.
+pub fn to_golly_clipboard(simulation: &Simulation) -> String {
+    let cells: HashSet<(u16, u16)> = simulation
+        .generation
+        .iter()
+        .map(|cell| (cell.row, cell.column))
+        .collect();
+    golly_clipboard(&cells, simulation.rows, simulation.columns, simulation)
+}
+
+/// Renders a rectangular region of the current generation of a simulation in the RLE flavor
+/// accepted by Golly's clipboard.
+///
+/// # Arguments
+/// * `simulation` - The simulation to copy the region from.
+/// * `row` - The row index of the top-left corner of the region.
+/// * `column` - The column index of the top-left corner of the region.
+/// * `rows` - The number of rows in the region.
+/// * `columns` - The number of columns in the region.
+///
+/// # Returns
+/// * `Ok(String)` - The region in the same format as `to_golly_clipboard`.
+/// * `Err(String)` - An error message if the region extends past the edges of the grid.
+pub fn region_to_golly_clipboard(
+    simulation: &Simulation,
+    row: u16,
+    column: u16,
+    rows: u16,
+    columns: u16,
+) -> Result<String, String> {
+    if row as u32 + rows as u32 > simulation.rows as u32
+        || column as u32 + columns as u32 > simulation.columns as u32
+    {
+        return Err(format!(
+            "The region of {}x{} at ({}, {}) does not fit within the {}x{} grid",
+            rows, columns, row, column, simulation.rows, simulation.columns
+        ));
+    }
+    let cells: HashSet<(u16, u16)> = simulation
+        .generation
+        .iter()
+        .filter(|cell| {
+            cell.row >= row
+                && cell.row < row + rows
+                && cell.column >= column
+                && cell.column < column + columns
+        })
+        .map(|cell| (cell.row - row, cell.column - column))
+        .collect();
+    Ok(golly_clipboard(&cells, rows, columns, simulation))
+}
+
+/// Reads a pattern from the RLE text of Golly's clipboard or any other RLE source.
+///
+/// # Description
+/// The header line and comments are optional, so both full RLE files and bare pattern data
+/// (such as `bo$2bo$3o!`) are accepted. See `to_golly_clipboard` for the output format.
+///
+/// # Returns
+/// * `Ok(Pattern)` - The alive cells of the pattern, cropped to their bounding box.
+/// * `Err(String)` - An error message if the text is not valid RLE.
+pub fn from_golly_clipboard(text: &str) -> Result<Pattern, String> {
+    Pattern::from_rle(text)
+}
+
+/// Assembles the comment, header, and wrapped pattern data of Golly's clipboard format.
+fn golly_clipboard(
+    cells: &HashSet<(u16, u16)>,
+    rows: u16,
+    columns: u16,
+    simulation: &Simulation,
+) -> String {
+    String::from("#C Generated by simple_game_of_life\n") + &rle(cells, rows, columns, simulation)
+}
+
+/// Assembles the header and the pattern data (wrapped to lines of at most 70 characters) of
+/// an RLE string.
+fn rle(cells: &HashSet<(u16, u16)>, rows: u16, columns: u16, simulation: &Simulation) -> String {
+    let mut lines: Vec<String> = vec![format!(
+        "x = {}, y = {}, rule = {}",
+        columns, rows, simulation.rule
+    )];
+    let mut line: String = String::new();
+    for run in encode_rle_runs(cells, rows, columns) {
+        if line.len() + run.len() > GOLLY_LINE_LENGTH {
+            lines.push(line);
+            line = String::new();
+        }
+        line.push_str(&run);
+    }
+    lines.push(line);
+    lines.join("\n") + "\n"
+}
+
+impl Simulation {
+    /// Renders the current generation as an RLE string.
+    ///
+    /// # Description
+    /// The output is the `x = <columns>, y = <rows>, rule = <rule>` header followed by the
+    /// run-length encoded rows of the whole grid, wrapped so that no line is longer than 70
+    /// characters and ending with `!`. Dead cells at the end of a row are omitted, and runs of
+    /// empty rows are encoded as a single run of `$` (such as `3$`).
+    ///
+    /// The result can be read back with `patterns::from_golly_clipboard` or pasted into other
+    /// tools such as Golly.
+    pub fn generation_rle(&self) -> String {
+        let cells: HashSet<(u16, u16)> = self
+            .generation
+            .iter()
+            .map(|cell| (cell.row, cell.column))
+            .collect();
+        rle(&cells, self.rows, self.columns, self)
+    }
+
+    /// Renders the current generation as an RLE string with a `#C Generated by
+    /// simple_game_of_life` comment before the header, ready to be pasted into Golly or
+    /// LifeWiki (the same output as `to_golly_clipboard`).
+    ///
+    /// # Note
+    /// Reading the result back with `simulation::generation_from_rle` (with the same number of
+    /// columns) produces the same alive cells as the current generation.
+    pub fn generation_to_rle(&self) -> String {
+        to_golly_clipboard(self)
+    }
+
+    /// Returns the number of separate groups of alive cells in the current generation.
+    ///
+    /// # Description
+    /// Alive cells are in the same group if they are connected through any of their eight
+    /// neighbors, following the wrapping of the simulation's surface (the same objects that
+    /// `census` counts).
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let seed: &str = "**---\n**--*\n----*\n-*--*\n-----";
+    /// let rectangle: Simulation = SimulationBuilder::new().seed(seed).build().unwrap();
+    /// assert_eq!(rectangle.connected_components(), 3);
+    /// assert_eq!(rectangle.component_sizes(), vec![4, 3, 1]);
+    ///
+    /// // On a horizontal loop, the block and the line touch across the edge
+    /// let horizontal_loop: Simulation = SimulationBuilder::new()
+    ///     .seed(seed)
+    ///     .surface_horizontal_loop()
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(horizontal_loop.component_sizes(), vec![7, 1]);
+    /// ```
+    pub fn connected_components(&self) -> u32 {
+        connected_objects(self).len() as u32
+    }
+
+    /// Returns the number of alive cells in each separate group of alive cells in the current
+    /// generation (see `connected_components`), from the largest group to the smallest.
+    pub fn component_sizes(&self) -> Vec<u32> {
+        let mut sizes: Vec<u32> = connected_objects(self)
+            .iter()
+            .map(|object| object.alive_count() as u32)
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+}
+
+/// Returns the names of every pattern in the built-in pattern lexicon.
+pub fn names() -> Vec<&'static str> {
+    LEXICON.iter().map(|entry| entry.name).collect()
+}
+
+/// Looks up a pattern from the built-in pattern lexicon by name.
+///
+/// # Description
+/// Names are matched loosely: letter case, spaces, dashes, and other punctuation are ignored,
+/// and common aliases (such as `"lwss"`) are recognized. If no name matches exactly, the
+/// shortest name containing the given name is used (so `"glider-gun"` finds the Gosper glider
+/// gun), and failing that, the closest name within a small number of typos is used.
+///
+/// The phases of periodic patterns are computed when the pattern is looked up. Patterns that
+/// do not repeat (such as methuselahs and guns) have only their defining phase.
+///
+/// # Arguments
+/// * `name` - The name of the pattern to look up.
+///
+/// # Returns
+/// The matching `PatternDef`, or `None` if no pattern in the lexicon matches the name.
+pub fn lookup(name: &str) -> Option<PatternDef> {
+    let entry: &LexiconEntry = find_lexicon_entry(name)?;
+    let initial_phase: Pattern = from_golly_clipboard(entry.rle).ok()?;
+    let phases: Vec<Pattern> = simulate_phases(&initial_phase)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| vec![initial_phase]);
+    Some(PatternDef {
+        name: String::from(entry.name),
+        description: String::from(entry.description),
+        category: Some(entry.category),
+        phases,
+    })
+}
+
+/// Finds the lexicon entry best matching the given name.
+fn find_lexicon_entry(name: &str) -> Option<&'static LexiconEntry> {
+    let query: String = normalize_name(name);
+    if query.is_empty() {
+        return None;
+    }
+    let entry_names = || {
+        LEXICON.iter().flat_map(|entry| {
+            std::iter::once(entry.name)
+                .chain(entry.aliases.iter().copied())
+                .map(move |entry_name| (entry, normalize_name(entry_name)))
+        })
+    };
+    if let Some((entry, _)) = entry_names().find(|(_, entry_name)| *entry_name == query) {
+        return Some(entry);
+    }
+    if let Some((entry, _)) = entry_names()
+        .filter(|(_, entry_name)| entry_name.contains(&query))
+        .min_by_key(|(_, entry_name)| entry_name.len())
+    {
+        return Some(entry);
+    }
+    let maximum_typos: usize = (query.len() / 4).max(1);
+    entry_names()
+        .map(|(entry, entry_name)| (entry, edit_distance(&query, &entry_name)))
+        .filter(|(_, distance)| *distance <= maximum_typos)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(entry, _)| entry)
+}
+
+/// Lowercases a pattern name and removes everything except letters and digits.
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|character| character.is_ascii_alphanumeric())
+        .map(|character| character.to_ascii_lowercase())
+        .collect()
+}
+
+/// Returns the Levenshtein distance (the number of single character insertions, deletions, or
+/// substitutions) between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_character) in a.chars().enumerate() {
+        let mut current_row: Vec<usize> = vec![i + 1];
+        for (j, b_character) in b.iter().enumerate() {
+            let substitution: usize = previous_row[j] + usize::from(a_character != *b_character);
+            let insertion: usize = current_row[j] + 1;
+            let deletion: usize = previous_row[j + 1] + 1;
+            current_row.push(substitution.min(insertion).min(deletion));
+        }
+        previous_row = current_row;
+    }
+    previous_row[b.len()]
+}
+
+/// Creates a seed string for a grid with the defining phase of a named pattern centered on it.
+///
+/// # Description
+/// If the number of rows or columns is not given, the grid is made exactly as large as the
+/// pattern in that dimension. When the pattern can not be centered exactly, it is placed one
+/// cell closer to the top-left corner.
+///
+/// # Returns
+/// * `Ok((u16, u16, String))` - The number of rows, the number of columns, and the seed string.
+/// * `Err(String)` - An error message if no pattern matches the name or the pattern does not
+///   fit within the grid.
+pub(crate) fn named_seed(
+    name: &str,
+    rows: Option<u16>,
+    columns: Option<u16>,
+) -> Result<(u16, u16, String), String> {
+    let pattern: PatternDef =
+        lookup(name).ok_or_else(|| format!("No pattern named \"{}\" was found", name))?;
+    let phase: &Pattern = &pattern.phases[0];
+    let rows: u16 = rows.unwrap_or(phase.rows);
+    let columns: u16 = columns.unwrap_or(phase.columns);
+    if phase.rows > rows || phase.columns > columns {
+        return Err(format!(
+            "The pattern \"{}\" ({}x{}) does not fit within the {}x{} grid",
+            pattern.name, phase.rows, phase.columns, rows, columns
+        ));
+    }
+    let seed: String = phase.seed_string(
+        rows,
+        columns,
+        (rows - phase.rows) / 2,
+        (columns - phase.columns) / 2,
+    );
+    Ok((rows, columns, seed))
+}
+
+/// The census name used for objects that do not match any pattern in the lexicon.
+pub const UNIDENTIFIED: &str = "unidentified";
+
+/// Counts the objects in the current generation of a simulation by pattern name.
+///
+/// # Description
+/// The alive cells are split into objects, where each object is a group of alive cells
+/// connected through any of their eight neighbors (following the wrapping of the simulation's
+/// surface). Each object is then compared against every phase and orientation of the patterns
+/// in the built-in pattern lexicon.
+///
+/// # Arguments
+/// * `simulation` - The simulation to take the census of.
+///
+/// # Returns
+/// The number of objects of each pattern, keyed by the pattern's name. Objects that do not
+/// match any pattern are counted under `UNIDENTIFIED`.
+///
+/// # Note
+/// Patterns whose cells are not all connected (such as the pulsar) and objects that touch
+/// each other are not recognized.
+pub fn census(simulation: &Simulation) -> BTreeMap<String, u64> {
+    census_with(simulation, &lexicon_patterns())
+}
+
+/// Returns every pattern in the built-in pattern lexicon with its phases.
+pub(crate) fn lexicon_patterns() -> Vec<PatternDef> {
+    LEXICON
+        .iter()
+        .filter_map(|entry| lookup(entry.name))
+        .collect()
+}
+
+/// Counts the objects in the current generation of a simulation against the given reference
+/// patterns (see `census`).
+pub(crate) fn census_with(
+    simulation: &Simulation,
+    references: &[PatternDef],
+) -> BTreeMap<String, u64> {
+    let mut counts: BTreeMap<String, u64> = BTreeMap::new();
+    for object in connected_objects(simulation) {
+        let name: String = references
+            .iter()
+            .find(|reference| align(&object, reference).is_some())
+            .map(|reference| reference.name.clone())
+            .unwrap_or_else(|| String::from(UNIDENTIFIED));
+        *counts.entry(name).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Splits the alive cells of a simulation into groups of cells connected through any of their
+/// eight neighbors, following the wrapping of the simulation's surface.
+///
+/// # Note
+/// Objects that cross a wrapping edge are unwrapped so that they are extracted whole. On a
+/// `Mobius` or `KleinBottle` surface, the part of an object past the flipping seam is flipped
+/// back as it is unwrapped. On a `Custom` surface, neighbors are found with the surface's
+/// function, and objects are unwrapped without any flips it may make.
+fn connected_objects(simulation: &Simulation) -> Vec<Pattern> {
+    let rows: i32 = simulation.rows as i32;
+    let columns: i32 = simulation.columns as i32;
+    let wraps_vertically: bool = simulation.surface_type.wraps_vertically();
+    let wraps_horizontally: bool = simulation.surface_type.wraps_horizontally();
+    let flips: bool = simulation.surface_type.flips_on_horizontal_wrap();
+    let alive: HashSet<(i32, i32)> = simulation
+        .generation
+        .iter()
+        .map(|cell| (cell.row as i32, cell.column as i32))
+        .collect();
+    let mut starts: Vec<(i32, i32)> = alive.iter().copied().collect();
+    starts.sort();
+    let mut visited: HashSet<(i32, i32)> = HashSet::new();
+    let mut objects: Vec<Pattern> = Vec::new();
+    for start in starts {
+        if !visited.insert(start) {
+            continue;
+        }
+        // Each entry is the cell's position on the grid, its unwrapped position, and whether
+        // it was reached across the flipping seam an odd number of times (so its rows are
+        // mirrored relative to the start)
+        type Entry = ((i32, i32), (i32, i32), bool);
+        let mut stack: Vec<Entry> = vec![(start, start, false)];
+        let mut unwrapped: Vec<(i32, i32)> = Vec::new();
+        while let Some(((row, column), (unwrapped_row, unwrapped_column), flipped)) = stack.pop() {
+            unwrapped.push((unwrapped_row, unwrapped_column));
+            for row_offset in -1..=1 {
+                for column_offset in -1..=1 {
+                    let mut neighbor_row: i32 = row + row_offset;
+                    let mut neighbor_column: i32 = column + column_offset;
+                    let mut neighbor_flipped: bool = flipped;
+                    if let SurfaceType::Custom(wrap) = &simulation.surface_type {
+                        match wrap(neighbor_row, neighbor_column, rows as u16, columns as u16) {
+                            Some((wrapped_row, wrapped_column)) => {
+                                neighbor_row = wrapped_row as i32;
+                                neighbor_column = wrapped_column as i32;
+                            }
+                            None => continue,
+                        }
+                    }
+                    if wraps_vertically {
+                        neighbor_row = neighbor_row.rem_euclid(rows);
+                    }
+                    if wraps_horizontally {
+                        if flips && !(0..columns).contains(&neighbor_column) {
+                            neighbor_row = rows - 1 - neighbor_row;
+                            neighbor_flipped = !neighbor_flipped;
+                        }
+                        neighbor_column = neighbor_column.rem_euclid(columns);
+                    }
+                    let neighbor: (i32, i32) = (neighbor_row, neighbor_column);
+                    // Moving down the grid moves up the object where its rows are mirrored
+                    let unwrapped_row_offset: i32 = if flipped { -row_offset } else { row_offset };
+                    if alive.contains(&neighbor) && visited.insert(neighbor) {
+                        stack.push((
+                            neighbor,
+                            (
+                                unwrapped_row + unwrapped_row_offset,
+                                unwrapped_column + column_offset,
+                            ),
+                            neighbor_flipped,
+                        ));
+                    }
+                }
+            }
+        }
+        let minimum_row: i32 = unwrapped.iter().map(|(row, _)| *row).min().unwrap();
+        let minimum_column: i32 = unwrapped.iter().map(|(_, column)| *column).min().unwrap();
+        objects.push(Pattern::from_cells(unwrapped.into_iter().map(
+            |(row, column)| ((row - minimum_row) as u16, (column - minimum_column) as u16),
+        )));
+    }
+    objects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::Cell;
+    use crate::simulation::{generation_from_rle, Simulation};
+    use crate::simulation_builder::SimulationBuilder;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// The glider as Golly copies it to the clipboard.
+    const GOLLY_GLIDER: &str = "x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!\n";
+
+    /// The glider as it appears in a pattern file, with a name, comments, and a header without
+    /// a rule.
+    const COMMENTED_GLIDER: &str = "#N Glider\r\n#O Richard K. Guy\r\n#C The smallest spaceship.\r\nx = 3, y = 3\r\nbo$2bo$\r\n3o!\r\n";
+
+    /// Returns a simulation of the given seed.
+    fn build(seed: &str) -> Simulation {
+        SimulationBuilder::new().seed(seed).build().unwrap()
+    }
+
+    /// Returns the cells of the glider.
+    fn glider() -> Pattern {
+        Pattern::from_cells([(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)])
+    }
+
+    #[test]
+    fn golly_clipboard_matches_what_golly_copies() {
+        let simulation: Simulation = build("-*-\n--*\n***");
+        assert_eq!(
+            to_golly_clipboard(&simulation),
+            format!("#C Generated by simple_game_of_life\n{}", GOLLY_GLIDER)
+        );
+    }
+
+    #[test]
+    fn golly_clipboard_header_names_the_rule() {
+        let simulation: Simulation = SimulationBuilder::new()
+            .seed("-----\n--*--\n-----\n-----\n*----")
+            .rule_str("B36/S23")
+            .build()
+            .unwrap();
+        assert_eq!(
+            to_golly_clipboard(&simulation),
+            "#C Generated by simple_game_of_life\nx = 5, y = 5, rule = B36/S23\n$2bo3$o!\n"
+        );
+    }
+
+    #[test]
+    fn golly_clipboard_lines_are_at_most_70_characters() {
+        let simulation: Simulation = SimulationBuilder::new()
+            .height(4)
+            .width(100)
+            .seed(&"*-".repeat(200))
+            .build()
+            .unwrap();
+        let clipboard: String = to_golly_clipboard(&simulation);
+        let data: Vec<&str> = clipboard.lines().skip(2).collect();
+        assert!(data.len() > 1);
+        for line in &data {
+            assert!(line.len() <= 70, "{}", line);
+            // Runs are never split, so every line ends with a complete run
+            assert!(line.ends_with(['o', 'b', '$', '!']), "{}", line);
+        }
+        let pattern: Pattern = from_golly_clipboard(&clipboard).unwrap();
+        assert_eq!(pattern.cells.len(), 200);
+    }
+
+    #[test]
+    fn golly_clipboard_region_is_relative_to_its_corner() {
+        let simulation: Simulation = build("-----\n--*--\n---*-\n-***-\n-----");
+        assert_eq!(
+            region_to_golly_clipboard(&simulation, 1, 1, 3, 3).unwrap(),
+            format!("#C Generated by simple_game_of_life\n{}", GOLLY_GLIDER)
+        );
+        assert!(region_to_golly_clipboard(&simulation, 3, 3, 3, 3).is_err());
+    }
+
+    #[test]
+    fn golly_clipboard_is_read_back_as_the_same_pattern() {
+        let simulation: Simulation = build("-*-\n--*\n***");
+        assert_eq!(
+            from_golly_clipboard(&to_golly_clipboard(&simulation)).unwrap(),
+            glider()
+        );
+    }
+
+    #[test]
+    fn golly_clipboard_import_is_liberal() {
+        assert_eq!(from_golly_clipboard(GOLLY_GLIDER).unwrap(), glider());
+        assert_eq!(from_golly_clipboard(COMMENTED_GLIDER).unwrap(), glider());
+        assert_eq!(from_golly_clipboard("bo$2bo$3o!").unwrap(), glider());
+        assert_eq!(from_golly_clipboard("bo$2b\no$3o").unwrap(), glider());
+        assert!(from_golly_clipboard("bo$2bo$3o?").is_err());
+    }
+
+    #[test]
+    fn generation_rle_omits_trailing_dead_cells_and_joins_empty_rows() {
+        let simulation: Simulation = build("-*---\n-----\n-----\n-----\n**-**\n-----");
+        assert_eq!(
+            simulation.generation_rle(),
+            "x = 5, y = 6, rule = B3/S23\nbo4$2ob2o!\n"
+        );
+    }
+
+    #[test]
+    fn empty_generation_rle_is_only_a_terminator() {
+        let simulation: Simulation = build("---\n---");
+        assert_eq!(
+            simulation.generation_rle(),
+            "x = 3, y = 2, rule = B3/S23\n!\n"
+        );
+    }
+
+    #[test]
+    fn generation_rle_is_read_back_as_the_same_generation() {
+        for rng_seed in 0..8 {
+            let simulation: Simulation = SimulationBuilder::new()
+                .height(30)
+                .width(90)
+                .alive_probability(0.2)
+                .rng_seed(rng_seed)
+                .build()
+                .unwrap();
+            let rle: String = simulation.generation_rle();
+            assert!(rle.lines().all(|line| line.len() <= 70));
+            let generation: HashSet<Cell> = generation_from_rle(&rle, 90).unwrap();
+            assert!(generation == simulation.generation, "soup {}", rng_seed);
+        }
+    }
+
+    #[test]
+    fn pattern_rotations_compose_and_keep_every_cell() {
+        let lightweight_spaceship: Pattern = Pattern::from_rle("bo2bo$o4b$o3bo$4o!").unwrap();
+        let r_pentomino: Pattern = Pattern::from_rle("b2o$2o$bo!").unwrap();
+        for pattern in [glider(), lightweight_spaceship, r_pentomino] {
+            let clockwise: Pattern = pattern.rotate_cw();
+            assert_eq!(
+                (clockwise.height(), clockwise.width()),
+                (pattern.width(), pattern.height())
+            );
+            assert_eq!(clockwise.rotate_cw().rotate_cw().rotate_cw(), pattern);
+            assert_eq!(clockwise.rotate_cw(), pattern.rotate_180());
+            assert_eq!(pattern.rotate_ccw(), pattern.rotate_180().rotate_cw());
+            assert_eq!(pattern.rotate_ccw().rotate_cw(), pattern);
+            assert_eq!(pattern.flip_horizontal().flip_horizontal(), pattern);
+            assert_eq!(pattern.flip_vertical().flip_vertical(), pattern);
+            assert_eq!(
+                pattern.flip_horizontal().flip_vertical(),
+                pattern.rotate_180()
+            );
+            for transformed in [
+                clockwise,
+                pattern.rotate_ccw(),
+                pattern.rotate_180(),
+                pattern.flip_horizontal(),
+                pattern.flip_vertical(),
+            ] {
+                assert_eq!(transformed.alive_count(), pattern.alive_count());
+            }
+        }
+    }
+
+    #[test]
+    fn rotated_gliders_travel_in_all_four_directions() {
+        let mut directions: HashSet<(i64, i64)> = HashSet::new();
+        let mut orientation: Pattern = glider();
+        for _ in 0..4 {
+            let mut simulation: Simulation = SimulationBuilder::new()
+                .height(12)
+                .seed(&"-".repeat(144))
+                .build()
+                .unwrap();
+            simulation
+                .insert_pattern(&orientation, 5, 5, CollisionMode::Merge)
+                .unwrap();
+            let sum = |simulation: &Simulation| -> (i64, i64) {
+                simulation
+                    .alive_cells_as_vec()
+                    .iter()
+                    .fold((0, 0), |(rows, columns), (row, column)| {
+                        (rows + *row as i64, columns + *column as i64)
+                    })
+            };
+            let before: (i64, i64) = sum(&simulation);
+            simulation.simulate_generations(4);
+            let after: (i64, i64) = sum(&simulation);
+            // Every glider cell moves one row and one column every four generations
+            let direction: (i64, i64) = ((after.0 - before.0) / 5, (after.1 - before.1) / 5);
+            assert_eq!((direction.0.abs(), direction.1.abs()), (1, 1));
+            directions.insert(direction);
+            orientation = orientation.rotate_cw();
+        }
+        assert_eq!(directions.len(), 4);
+    }
+
+    #[test]
+    fn align_finds_every_phase_of_an_oscillator_in_every_orientation() {
+        for (name, period) in [("pulsar", 3), ("toad", 2)] {
+            let reference: PatternDef = lookup(name).unwrap();
+            assert_eq!(reference.period(), period);
+            for (phase_index, phase) in reference.phases.iter().enumerate() {
+                for transform in Transform::ALL {
+                    let candidate: Pattern = phase.transformed(transform);
+                    let alignment: Alignment = align(&candidate, &reference).unwrap();
+                    assert_eq!(alignment.phase, phase_index, "{} {:?}", name, transform);
+                    assert_eq!(candidate.transformed(alignment.transform), *phase);
+                    // The found transform undoes the applied one, up to a symmetry of the phase
+                    assert_eq!(
+                        phase.transformed(transform.then(alignment.transform)),
+                        *phase
+                    );
+                    if transform == Transform::Identity {
+                        assert_eq!(alignment.transform, Transform::Identity);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn align_finds_the_phase_of_a_simulated_oscillator() {
+        let reference: PatternDef = lookup("pulsar").unwrap();
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(17)
+            .width(17)
+            .seed_named("pulsar")
+            .build()
+            .unwrap();
+        for generation in 0..6 {
+            let candidate: Pattern = Pattern::from_simulation(&simulation);
+            let alignment: Alignment = align(&candidate, &reference).unwrap();
+            assert_eq!(alignment.phase, generation % 3);
+            simulation.simulate_generation();
+        }
+    }
+
+    #[test]
+    fn align_rejects_a_random_blob() {
+        let mut rng: StdRng = StdRng::seed_from_u64(1975);
+        for (name, size) in [("pulsar", 13), ("toad", 4)] {
+            let reference: PatternDef = lookup(name).unwrap();
+            // The blob has as many cells as the first phase, so it is not rejected by its count
+            let mut cells: HashSet<(u16, u16)> = HashSet::new();
+            while cells.len() < reference.phases[0].alive_count() as usize {
+                cells.insert((rng.gen_range(0..size), rng.gen_range(0..size)));
+            }
+            let blob: Pattern = Pattern::from_cells(cells);
+            assert_eq!(align(&blob, &reference), None, "{}", name);
+        }
+    }
+
+    /// Returns an empty 4x5 simulation on the surface set by the given function.
+    fn empty_grid(surface: fn(SimulationBuilder) -> SimulationBuilder) -> Simulation {
+        surface(SimulationBuilder::new().height(4).width(5))
+            .seed(&"-".repeat(20))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn clipped_insertion_leaves_out_cells_past_bounded_edges() {
+        let block: Pattern = Pattern::from_rle("3o$3o!").unwrap();
+        let mut simulation: Simulation = empty_grid(SimulationBuilder::surface_rectangle);
+        assert!(simulation
+            .insert_pattern(&block, 3, 3, CollisionMode::Merge)
+            .is_err());
+        assert_eq!(simulation.alive_count(), 0);
+        simulation
+            .insert_pattern_clipped(&block, 3, 3, CollisionMode::Merge)
+            .unwrap();
+        assert_eq!(simulation.alive_cells_as_vec(), vec![(3, 3), (3, 4)]);
+
+        // The top-left corner must still be within the grid
+        assert!(simulation
+            .insert_pattern_clipped(&block, 4, 0, CollisionMode::Merge)
+            .is_err());
+    }
+
+    #[test]
+    fn clipped_insertion_still_wraps_on_wrapping_edges() {
+        let block: Pattern = Pattern::from_rle("3o$3o!").unwrap();
+        let mut simulation: Simulation = empty_grid(SimulationBuilder::surface_horizontal_loop);
+        simulation
+            .insert_pattern_clipped(&block, 3, 3, CollisionMode::Merge)
+            .unwrap();
+        // The columns wrap to the left edge, and the row past the bottom edge is clipped
+        assert_eq!(
+            simulation.alive_cells_as_vec(),
+            vec![(3, 0), (3, 3), (3, 4)]
+        );
+
+        let mut unclipped: Simulation = empty_grid(SimulationBuilder::surface_horizontal_loop);
+        unclipped
+            .insert_pattern(&block, 2, 3, CollisionMode::Merge)
+            .unwrap();
+        assert_eq!(
+            unclipped.alive_cells_as_vec(),
+            vec![(2, 0), (2, 3), (2, 4), (3, 0), (3, 3), (3, 4)]
+        );
+    }
+
+    #[test]
+    fn clipped_insertion_leaves_out_cells_a_custom_surface_maps_to_none() {
+        // A surface that wraps columns but has no cells in its last row
+        let simulation = |clip: bool| -> Result<Simulation, String> {
+            let mut simulation: Simulation = SimulationBuilder::new()
+                .height(4)
+                .width(5)
+                .seed(&"-".repeat(20))
+                .surface_custom(|row, column, _, columns| {
+                    (0..3)
+                        .contains(&row)
+                        .then(|| (row as u16, column.rem_euclid(columns as i32) as u16))
+                })
+                .build()
+                .unwrap();
+            let block: Pattern = Pattern::from_rle("2o$2o!").unwrap();
+            let result: Result<(), String> = if clip {
+                simulation.insert_pattern_clipped(&block, 2, 4, CollisionMode::Merge)
+            } else {
+                simulation.insert_pattern(&block, 2, 4, CollisionMode::Merge)
+            };
+            result.map(|_| simulation)
+        };
+        assert!(simulation(false).is_err());
+        assert_eq!(
+            simulation(true).unwrap().alive_cells_as_vec(),
+            vec![(2, 0), (2, 4)]
+        );
+    }
+
+    #[test]
+    fn diagonal_neighbors_are_connected() {
+        // *---
+        // -*--
+        // ---*
+        let simulation: Simulation = SimulationBuilder::new()
+            .height(3)
+            .seed("*----*-----*")
+            .build()
+            .unwrap();
+        assert_eq!(simulation.connected_components(), 2);
+        assert_eq!(simulation.component_sizes(), vec![2, 1]);
+    }
+
+    #[test]
+    fn components_join_across_wrapping_edges_only() {
+        // A domino split across the left and right edges, and one across the top and bottom
+        let seed: &str = "--*--\n-----\n*---*\n-----\n--*--";
+        let build = |surface: fn(SimulationBuilder) -> SimulationBuilder| -> Simulation {
+            surface(SimulationBuilder::new())
+                .seed(seed)
+                .build()
+                .unwrap()
+        };
+        let rectangle: Simulation = build(SimulationBuilder::surface_rectangle);
+        assert_eq!(rectangle.connected_components(), 4);
+        let horizontal_loop: Simulation = build(SimulationBuilder::surface_horizontal_loop);
+        assert_eq!(horizontal_loop.connected_components(), 3);
+        assert_eq!(horizontal_loop.component_sizes(), vec![2, 1, 1]);
+        let ball: Simulation = build(SimulationBuilder::surface_ball);
+        assert_eq!(ball.connected_components(), 2);
+        assert_eq!(ball.component_sizes(), vec![2, 2]);
+    }
+
+    #[test]
+    fn component_sizes_are_in_descending_order() {
+        // A block, a blinker, a lone cell, and a glider, far enough apart to be separate
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(12)
+            .seed(&"-".repeat(144))
+            .build()
+            .unwrap();
+        for (pattern, row, column) in [
+            ("o!", 0, 0),
+            ("3o!", 0, 4),
+            ("2o$2o!", 6, 0),
+            ("bo$2bo$3o!", 6, 6),
+        ] {
+            simulation
+                .insert_pattern(
+                    &Pattern::from_rle(pattern).unwrap(),
+                    row,
+                    column,
+                    CollisionMode::Merge,
+                )
+                .unwrap();
+        }
+        assert_eq!(simulation.connected_components(), 4);
+        assert_eq!(simulation.component_sizes(), vec![5, 4, 3, 1]);
+    }
+
+    #[test]
+    fn empty_grid_has_no_components() {
+        let simulation: Simulation = build("---------");
+        assert_eq!(simulation.connected_components(), 0);
+        assert!(simulation.component_sizes().is_empty());
+    }
+}
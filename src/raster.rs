@@ -0,0 +1,513 @@
+//! A minimal headless rasterizer.
+//!
+//! This renders images without a display window, so simulations can be exported as images
+//! anywhere. With the `image` feature, images are saved as compressed PNG files with the
+//! `image` crate, and with the `png` feature, `export_animation_frames` saves its frames as
+//! PNG files.
+
+#[cfg(feature = "image")]
+use std::error::Error;
+#[cfg(feature = "image")]
+use std::fs::File;
+#[cfg(feature = "image")]
+use std::io::BufWriter;
+#[cfg(feature = "image")]
+use std::path::Path;
+
+#[cfg(feature = "image")]
+use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+#[cfg(feature = "image")]
+use image::{ExtendedColorType, ImageEncoder, ImageError};
+
+use std::collections::HashSet;
+
+use crate::cell::Cell;
+use crate::simulation::{RenderConfig, Simulation};
+use crate::simulation_window::{cell_rect, FrameRect};
+
+/// An RGB color.
+pub(crate) type Rgb = (u8, u8, u8);
+/// An RGBA color.
+pub(crate) type Rgba = (u8, u8, u8, u8);
+
+/// An image held in memory, with RGB pixels unless another pixel type is given.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Raster<P = Rgb> {
+    /// The width of the image in pixels.
+    pub(crate) width: u32,
+    /// The height of the image in pixels.
+    pub(crate) height: u32,
+    /// The color of every pixel in row-major order.
+    pub(crate) pixels: Vec<P>,
+}
+
+impl<P: Copy> Raster<P> {
+    /// Creates a new `Raster` of the given size filled with the given color.
+    pub(crate) fn new(width: u32, height: u32, color: P) -> Raster<P> {
+        Raster {
+            width,
+            height,
+            pixels: vec![color; width as usize * height as usize],
+        }
+    }
+
+    /// Fills a rectangle with the given color. Parts of the rectangle outside of the image are
+    /// ignored.
+    pub(crate) fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: P) {
+        for row in y..(y + height).min(self.height) {
+            for column in x..(x + width).min(self.width) {
+                self.pixels[row as usize * self.width as usize + column as usize] = color;
+            }
+        }
+    }
+
+    /// Fills a rectangle of a drawn generation with the given color, clipping it to the image.
+    pub(crate) fn fill_frame_rect(&mut self, rect: FrameRect, color: P) {
+        let left: i64 = (rect.x as i64).max(0);
+        let top: i64 = (rect.y as i64).max(0);
+        let right: i64 = (rect.x as i64 + rect.width as i64).min(self.width as i64);
+        let bottom: i64 = (rect.y as i64 + rect.height as i64).min(self.height as i64);
+        if right > left && bottom > top {
+            self.fill_rect(
+                left as u32,
+                top as u32,
+                (right - left) as u32,
+                (bottom - top) as u32,
+                color,
+            );
+        }
+    }
+
+    /// Fills the cells of a grid with the given colors and optionally draws one pixel wide
+    /// grid lines between them.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of rows in the grid.
+    /// * `columns` - The number of columns in the grid.
+    /// * `cell_size` - The width and height of each cell in pixels.
+    /// * `cell_color` - Returns the color of the cell at the given `(row, column)`, or `None`
+    ///   to leave it as the background.
+    /// * `grid_color` - The color of the grid lines, or `None` for no grid lines.
+    #[cfg(feature = "image")]
+    pub(crate) fn draw_grid<F: Fn(u16, u16) -> Option<P>>(
+        &mut self,
+        rows: u16,
+        columns: u16,
+        cell_size: u32,
+        cell_color: F,
+        grid_color: Option<P>,
+    ) {
+        for row in 0..rows {
+            for column in 0..columns {
+                if let Some(color) = cell_color(row, column) {
+                    self.fill_rect(
+                        column as u32 * cell_size,
+                        row as u32 * cell_size,
+                        cell_size,
+                        cell_size,
+                        color,
+                    );
+                }
+            }
+        }
+        if let Some(grid_color) = grid_color {
+            for column in 1..columns as u32 {
+                self.fill_rect(column * cell_size, 0, 1, self.height, grid_color);
+            }
+            for row in 1..rows as u32 {
+                self.fill_rect(0, row * cell_size, self.width, 1, grid_color);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl Raster<Rgba> {
+    /// Returns a copy of the image without the alpha component of each pixel.
+    pub(crate) fn opaque(&self) -> Raster {
+        Raster {
+            width: self.width,
+            height: self.height,
+            pixels: self
+                .pixels
+                .iter()
+                .map(|&(red, green, blue, _)| (red, green, blue))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl Raster {
+    /// Saves the image as an 8-bit RGB PNG file with the best compression.
+    ///
+    /// # Note
+    /// Images of generations are mostly large areas of a single color, which compress to a
+    /// small fraction of their uncompressed size.
+    pub(crate) fn save_png(&self, path: &Path) -> Result<(), ImageError> {
+        let bytes: Vec<u8> = self
+            .pixels
+            .iter()
+            .flat_map(|&(red, green, blue)| [red, green, blue])
+            .collect();
+        let encoder: PngEncoder<BufWriter<File>> = PngEncoder::new_with_quality(
+            BufWriter::new(File::create(path)?),
+            CompressionType::Best,
+            FilterType::Adaptive,
+        );
+        encoder.write_image(&bytes, self.width, self.height, ExtendedColorType::Rgb8)
+    }
+}
+
+impl Simulation {
+    /// Draws the current generation on a new `Raster` with the given configuration.
+    ///
+    /// # Description
+    /// The generation is drawn exactly as the display window draws it, using the same
+    /// rectangles (see `cell_rect` and `grid_line_rects`): the background is filled,
+    /// then the alive cells, and then the grid lines. The image is `columns * cell_width` pixels
+    /// wide and `rows * cell_height` pixels high. Colors are not blended, so each pixel has the
+    /// color (including the alpha component) of whatever was drawn there last.
+    pub(crate) fn frame_raster(&self, config: &RenderConfig) -> Raster<Rgba> {
+        self.generation_raster(&self.generation, config)
+    }
+
+    /// Draws the given generation of this simulation's grid on a new `Raster`, the same way
+    /// `frame_raster` draws the current generation.
+    pub(crate) fn generation_raster(
+        &self,
+        generation: &HashSet<Cell>,
+        config: &RenderConfig,
+    ) -> Raster<Rgba> {
+        let width: u32 = self.columns as u32 * config.cell_width as u32;
+        let height: u32 = self.rows as u32 * config.cell_height as u32;
+        let mut raster: Raster<Rgba> = Raster::new(width, height, config.background_color);
+        for cell in generation {
+            raster.fill_frame_rect(
+                cell_rect(cell.row, cell.column, config.cell_width, config.cell_height),
+                config.cell_color,
+            );
+        }
+        for line in self.grid_line_rects(config, width, height) {
+            raster.fill_frame_rect(line, config.line_color);
+        }
+        raster
+    }
+
+    /// Renders the current generation as raw RGBA pixels with the given configuration.
+    ///
+    /// # Description
+    /// The generation is drawn the same way the display window draws it (see `RenderConfig`),
+    /// but into memory instead of a window, so this works for simulations built without a
+    /// display. This is meant for drawing simulations with other libraries, such as game
+    /// engines, or checking what a simulation looks like in tests.
+    ///
+    /// The colors are not blended: every pixel has the color of whatever was drawn there last,
+    /// including its alpha component.
+    ///
+    /// # Arguments
+    /// * `config` - The colors and sizes to draw the generation with. Use `render_config` for
+    ///   the simulation's own colors and sizes.
+    ///
+    /// # Returns
+    /// A `Vec<u8>` of `columns * cell_width` by `rows * cell_height` pixels in row-major order,
+    /// with four bytes (red, green, blue, and alpha) per pixel.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use simple_game_of_life::simulation::{RenderConfig, Simulation};
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .seed_named("glider")
+    ///     .display(false)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let config: RenderConfig = RenderConfig {
+    ///     line_thickness: 1,
+    ///     ..RenderConfig::default()
+    /// };
+    /// let pixels: Vec<u8> = simulation.render_to_pixel_buffer(&config);
+    /// assert_eq!(pixels.len(), 200 * 200 * 4);
+    /// ```
+    pub fn render_to_pixel_buffer(&self, config: &RenderConfig) -> Vec<u8> {
+        self.frame_raster(config)
+            .pixels
+            .iter()
+            .flat_map(|&(red, green, blue, alpha)| [red, green, blue, alpha])
+            .collect()
+    }
+
+    /// Saves an image of the current generation as a PNG file.
+    ///
+    /// # Description
+    /// The generation is drawn the same way the display window draws it, with the cell,
+    /// background, and grid line colors and the line thickness set on the `SimulationBuilder`,
+    /// but with the given cell size. No display window is needed, so this also works for
+    /// simulations built without a display. The image is `columns * cell_size` pixels wide and
+    /// `rows * cell_size` pixels high.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the PNG file to write.
+    /// * `cell_size` - The width and height of each cell in pixels.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the image was written.
+    /// * `Err(String)` - An error message if the cell size is 0 or the file could not be
+    ///   written.
+    ///
+    /// # Note
+    /// This requires the `image` feature.
+    #[cfg(feature = "image")]
+    pub fn export_png(&self, path: &Path, cell_size: u16) -> Result<(), String> {
+        if cell_size == 0 {
+            return Err(String::from("The cell size of an image must be at least 1"));
+        }
+        let config: RenderConfig = RenderConfig {
+            cell_width: cell_size,
+            cell_height: cell_size,
+            ..self.render_config
+        };
+        self.frame_raster(&config)
+            .opaque()
+            .save_png(path)
+            .map_err(|error| format!("Could not write {}: {}", path.display(), error))
+    }
+
+    /// Saves an image of the current generation as a PNG file, at the same size the display
+    /// window draws it.
+    ///
+    /// # Description
+    /// This is like `export_png`, but uses the simulation's own cell width and height instead
+    /// of a given cell size, so the image matches the display window pixel for pixel. Cell
+    /// sizes come from the `SimulationBuilder`'s cell size or window size, the same as the
+    /// display window's. A simulation built without a display window, cell size, or window size
+    /// uses 20x20 pixel cells.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the PNG file to write.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the image was written.
+    /// * `Err(Box<dyn Error>)` - An error if the cell width or height is 0 or the file could not
+    ///   be written.
+    ///
+    /// # Note
+    /// This requires the `image` feature.
+    #[cfg(feature = "image")]
+    pub fn save_generation_as_png(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if self.render_config.cell_width == 0 || self.render_config.cell_height == 0 {
+            return Err("The cell width and height of an image must be at least 1".into());
+        }
+        self.frame_raster(&self.render_config)
+            .opaque()
+            .save_png(path)?;
+        Ok(())
+    }
+
+    /// Saves an image of a frame of `export_animation_frames` as a PNG file, drawn the same
+    /// way `save_generation_as_png` draws the current generation.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the image was written.
+    /// * `Err(std::io::Error)` - An error if the cell width or height is 0 or the file could
+    ///   not be written.
+    #[cfg(feature = "png")]
+    pub(crate) fn save_frame_as_png(
+        &self,
+        frame: &HashSet<Cell>,
+        path: &Path,
+    ) -> Result<(), std::io::Error> {
+        if self.render_config.cell_width == 0 || self.render_config.cell_height == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "The cell width and height of an image must be at least 1",
+            ));
+        }
+        self.generation_raster(frame, &self.render_config)
+            .opaque()
+            .save_png(path)
+            .map_err(std::io::Error::other)
+    }
+}
+
+#[cfg(all(test, feature = "image"))]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{metadata, remove_file};
+    use std::path::PathBuf;
+
+    use image::RgbImage;
+
+    use crate::simulation::{RenderConfig, Simulation};
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// Returns a path in the temporary directory that is unique to this test process.
+    fn temporary_path(name: &str) -> PathBuf {
+        temp_dir().join(format!("game_of_life_{}_{}.png", std::process::id(), name))
+    }
+
+    fn build(rows: u16, columns: u16) -> Simulation {
+        SimulationBuilder::new()
+            .height(rows)
+            .width(columns)
+            .seed_named("glider")
+            .display(false)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn exported_png_decodes_to_the_rendered_pixels() {
+        let simulation: Simulation = build(10, 12);
+        let path: PathBuf = temporary_path("round_trip");
+        simulation.export_png(&path, 3).unwrap();
+
+        let decoded: RgbImage = image::open(&path).unwrap().to_rgb8();
+        remove_file(&path).unwrap();
+
+        let config: RenderConfig = RenderConfig {
+            cell_width: 3,
+            cell_height: 3,
+            ..simulation.render_config
+        };
+        let expected: Vec<u8> = simulation
+            .frame_raster(&config)
+            .opaque()
+            .pixels
+            .iter()
+            .flat_map(|&(red, green, blue)| [red, green, blue])
+            .collect();
+        assert_eq!(decoded.dimensions(), (36, 30));
+        assert_eq!(decoded.into_raw(), expected);
+    }
+
+    #[test]
+    fn exported_png_is_compressed() {
+        let simulation: Simulation = build(200, 200);
+        let path: PathBuf = temporary_path("compressed");
+        simulation.export_png(&path, 10).unwrap();
+
+        let size: u64 = metadata(&path).unwrap().len();
+        remove_file(&path).unwrap();
+
+        // The 2000x2000 pixel image is 12 MB uncompressed
+        assert!(size < 100_000, "{} bytes", size);
+    }
+
+    #[test]
+    fn saved_generation_uses_twenty_pixel_cells_without_a_window() {
+        let simulation: Simulation = build(10, 12);
+        let path: PathBuf = temporary_path("default_size");
+        simulation.save_generation_as_png(&path).unwrap();
+
+        let decoded: RgbImage = image::open(&path).unwrap().to_rgb8();
+        remove_file(&path).unwrap();
+        assert_eq!(decoded.dimensions(), (240, 200));
+    }
+
+    #[test]
+    fn saved_generation_uses_the_configured_cell_size() {
+        let simulation: Simulation = SimulationBuilder::new()
+            .height(4)
+            .width(5)
+            .seed_named("blinker")
+            .cell_width(7)
+            .cell_height(3)
+            .display(false)
+            .build()
+            .unwrap();
+        let path: PathBuf = temporary_path("configured_size");
+        simulation.save_generation_as_png(&path).unwrap();
+
+        let decoded: RgbImage = image::open(&path).unwrap().to_rgb8();
+        remove_file(&path).unwrap();
+        let expected: Vec<u8> = simulation
+            .frame_raster(&simulation.render_config)
+            .opaque()
+            .pixels
+            .iter()
+            .flat_map(|&(red, green, blue)| [red, green, blue])
+            .collect();
+        assert_eq!(decoded.dimensions(), (35, 12));
+        assert_eq!(decoded.into_raw(), expected);
+    }
+
+    #[test]
+    fn export_png_rejects_a_cell_size_of_zero() {
+        let simulation: Simulation = build(10, 10);
+        assert!(simulation.export_png(&temporary_path("zero"), 0).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "png")]
+    fn animation_frames_are_exported_as_numbered_png_files() {
+        // Thin grid lines, so that they do not cover the 3x3 pixel cells
+        let builder: SimulationBuilder = SimulationBuilder::new()
+            .height(10)
+            .width(12)
+            .seed_named("glider")
+            .cell_size(3)
+            .line_thickness(1)
+            .display(false);
+        let seed: Simulation = builder.clone().build().unwrap();
+        let mut simulation: Simulation = builder.build().unwrap();
+        simulation.simulate_generations(4);
+        let dir: PathBuf = temp_dir().join(format!(
+            "game_of_life_{}_animation_png_frames",
+            std::process::id()
+        ));
+
+        let written: usize = simulation.export_animation_frames(&dir, 0..5).unwrap();
+        let frames: Vec<RgbImage> = (0..5)
+            .map(|frame| {
+                image::open(dir.join(format!("frame_{:04}.png", frame)))
+                    .unwrap()
+                    .to_rgb8()
+            })
+            .collect();
+        let text_frame_exists: bool = dir.join("frame_0000.txt").exists();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let pixels = |simulation: &Simulation| -> Vec<u8> {
+            simulation
+                .frame_raster(&simulation.render_config)
+                .opaque()
+                .pixels
+                .iter()
+                .flat_map(|&(red, green, blue)| [red, green, blue])
+                .collect()
+        };
+        assert_eq!(written, 5);
+        assert!(!text_frame_exists);
+        for frame in &frames {
+            assert_eq!(frame.dimensions(), (36, 30));
+        }
+        assert_eq!(frames[0].clone().into_raw(), pixels(&seed));
+        assert_eq!(frames[4].clone().into_raw(), pixels(&simulation));
+        assert_ne!(frames[0], frames[1]);
+    }
+
+    #[test]
+    #[cfg(feature = "png")]
+    fn animation_png_frames_reject_a_cell_size_of_zero() {
+        let simulation: Simulation = SimulationBuilder::new()
+            .height(10)
+            .width(10)
+            .seed_named("glider")
+            .cell_size(0)
+            .display(false)
+            .build()
+            .unwrap();
+        let dir: PathBuf = temp_dir().join(format!(
+            "game_of_life_{}_animation_png_zero",
+            std::process::id()
+        ));
+        let result: Result<usize, std::io::Error> = simulation.export_animation_frames(&dir, 0..1);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+    }
+}
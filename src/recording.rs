@@ -0,0 +1,223 @@
+//! Recordings of simulation runs as animated GIFs, enabled with the `gif` feature.
+//!
+//! # Example
+//! ```rust,no_run
+//! use std::path::Path;
+//! use std::time::Duration;
+//! use simple_game_of_life::simulation::Simulation;
+//! use simple_game_of_life::simulation_builder::SimulationBuilder;
+//!
+//! let mut simulation: Simulation = SimulationBuilder::new()
+//!     .height(20)
+//!     .width(20)
+//!     .surface_ball()
+//!     .seed_named("glider")
+//!     .cell_size(8)
+//!     .build()
+//!     .unwrap();
+//!
+//! simulation
+//!     .record_gif(80, Duration::from_millis(100), Path::new("glider.gif"))
+//!     .unwrap();
+//! ```
+
+use std::fs::File;
+use std::path::Path;
+use std::time::Duration;
+
+use gif::{Encoder, Frame, Repeat};
+
+use crate::raster::{Raster, Rgba};
+use crate::simulation::{RenderConfig, Simulation};
+
+impl Simulation {
+    /// Simulates the given number of generations and records them as an animated GIF.
+    ///
+    /// # Description
+    /// The current generation and each simulated generation become one frame of the GIF, which
+    /// loops forever. Frames are drawn the same way as the display window draws generations:
+    /// alive cells are filled with the cell color on the background color, and grid lines are
+    /// drawn between the cells.
+    ///
+    /// The colors, line thickness, and cell size set on the `SimulationBuilder` are used, even
+    /// if the simulation has no display window. If no cell or window size was set, each cell is
+    /// 20x20 pixels.
+    ///
+    /// If the simulation finishes (see `is_finished`) before the given number of generations
+    /// have been simulated, recording stops early and the GIF is still completed.
+    ///
+    /// # Arguments
+    /// * `iterations` - The maximum number of generations to simulate.
+    /// * `frame_delay` - How long each frame is shown for. GIFs store delays in hundredths of a
+    ///   second, so the delay is rounded down to the nearest 10 milliseconds.
+    /// * `path` - The path of the GIF file to write.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the GIF was written.
+    /// * `Err(String)` - An error message if the frames are larger than 65535x65535 pixels or
+    ///   the file could not be written.
+    pub fn record_gif(
+        &mut self,
+        iterations: u128,
+        frame_delay: Duration,
+        path: &Path,
+    ) -> Result<(), String> {
+        let style: RenderConfig = self.render_config;
+        let width: u16 = u16::try_from(self.columns as u32 * style.cell_width as u32)
+            .map_err(|_| String::from("The frames of the GIF are wider than 65535 pixels"))?;
+        let height: u16 = u16::try_from(self.rows as u32 * style.cell_height as u32)
+            .map_err(|_| String::from("The frames of the GIF are taller than 65535 pixels"))?;
+        // Every frame only uses these three colors, so they make up the whole palette
+        let colors: [Rgba; 3] = [style.background_color, style.cell_color, style.line_color];
+        let palette: Vec<u8> = colors
+            .iter()
+            .flat_map(|(red, green, blue, _)| [*red, *green, *blue])
+            .collect();
+        let delay: u16 = (frame_delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+
+        let file: File = File::create(path)
+            .map_err(|error| format!("Could not create {}: {}", path.display(), error))?;
+        let write_error = |error: gif::EncodingError| {
+            format!("Could not write the GIF to {}: {}", path.display(), error)
+        };
+        let mut encoder: Encoder<File> =
+            Encoder::new(file, width, height, &palette).map_err(write_error)?;
+        encoder.set_repeat(Repeat::Infinite).map_err(write_error)?;
+        for step in 0..=iterations {
+            if step > 0 {
+                if self.is_finished() {
+                    break;
+                }
+                self.simulate_generation();
+            }
+            let frame: Frame = Frame {
+                width,
+                height,
+                delay,
+                buffer: self.frame_indices(&colors).into(),
+                ..Frame::default()
+            };
+            encoder.write_frame(&frame).map_err(write_error)?;
+        }
+        encoder
+            .into_inner()
+            .map(|_| ())
+            .map_err(|error| format!("Could not write the GIF to {}: {}", path.display(), error))
+    }
+
+    /// Draws the current generation (see `frame_raster`) as indices into the given colors, in
+    /// row-major order.
+    fn frame_indices(&self, colors: &[Rgba]) -> Vec<u8> {
+        let raster: Raster<Rgba> = self.frame_raster(&self.render_config);
+        raster
+            .pixels
+            .iter()
+            .map(|pixel| colors.iter().position(|color| color == pixel).unwrap_or(0) as u8)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{remove_file, File};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use gif::{ColorOutput, DecodeOptions, Decoder};
+
+    use crate::simulation::Simulation;
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// Returns a path in the temporary directory that is unique to this test process.
+    fn temporary_path(name: &str) -> PathBuf {
+        temp_dir().join(format!("game_of_life_{}_{}.gif", std::process::id(), name))
+    }
+
+    /// Builds a simulation of a glider on a ball with 5x5 pixel cells and thin grid lines.
+    fn build(rows: u16, columns: u16, seed: &str) -> Simulation {
+        SimulationBuilder::new()
+            .height(rows)
+            .width(columns)
+            .surface_ball()
+            .seed_named(seed)
+            .cell_size(5)
+            .line_thickness(1)
+            .display(false)
+            .build()
+            .unwrap()
+    }
+
+    /// Decodes a GIF into its width, height, and frames of RGBA pixels.
+    fn decode(path: &PathBuf) -> (u16, u16, Vec<Vec<u8>>) {
+        let mut options: DecodeOptions = DecodeOptions::new();
+        options.set_color_output(ColorOutput::RGBA);
+        let mut decoder: Decoder<File> = options.read_info(File::open(path).unwrap()).unwrap();
+        let (width, height): (u16, u16) = (decoder.width(), decoder.height());
+        let mut frames: Vec<Vec<u8>> = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((frame.width, frame.height), (width, height));
+            assert_eq!(frame.delay, 10);
+            frames.push(frame.buffer.to_vec());
+        }
+        (width, height, frames)
+    }
+
+    #[test]
+    fn recorded_frames_show_each_generation() {
+        let mut simulation: Simulation = build(8, 10, "glider");
+        let mut expected: Simulation = simulation.clone();
+        let path: PathBuf = temporary_path("glider");
+        simulation
+            .record_gif(4, Duration::from_millis(100), &path)
+            .unwrap();
+        let (width, height, frames): (u16, u16, Vec<Vec<u8>>) = decode(&path);
+        remove_file(&path).unwrap();
+
+        assert_eq!((width, height), (50, 40));
+        assert_eq!(frames.len(), 5);
+        assert_eq!(simulation.iteration(), 4);
+
+        let (cell_color, background_color) = (
+            simulation.render_config.cell_color,
+            simulation.render_config.background_color,
+        );
+        for (index, frame) in frames.iter().enumerate() {
+            if index > 0 {
+                expected.simulate_generation();
+            }
+            for (row, column, alive) in expected.cells() {
+                // The center pixel of each cell is never covered by a grid line
+                let pixel: usize =
+                    (row as usize * 5 + 2) * width as usize + column as usize * 5 + 2;
+                let (red, green, blue, _) = match alive {
+                    true => cell_color,
+                    false => background_color,
+                };
+                assert_eq!(
+                    frame[pixel * 4..pixel * 4 + 3],
+                    [red, green, blue],
+                    "frame {} cell ({}, {})",
+                    index,
+                    row,
+                    column
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn recording_stops_early_when_the_simulation_finishes() {
+        let mut simulation: Simulation = build(6, 6, "block");
+        let path: PathBuf = temporary_path("block");
+        simulation
+            .record_gif(10, Duration::from_millis(100), &path)
+            .unwrap();
+        let (width, height, frames): (u16, u16, Vec<Vec<u8>>) = decode(&path);
+        remove_file(&path).unwrap();
+
+        assert_eq!((width, height), (30, 30));
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0], frames[1]);
+    }
+}
@@ -0,0 +1,669 @@
+//! Birth and survival rules for Game of Life simulations.
+//!
+//! # Example
+//! ```rust,no_run
+//! use simple_game_of_life::rule::Rule;
+//! use simple_game_of_life::simulation::Simulation;
+//! use simple_game_of_life::simulation_builder::SimulationBuilder;
+//!
+//! let simulation: Simulation = SimulationBuilder::new()
+//!     .height(15) // 15 rows high
+//!     .width(15) // 15 columns wide
+//!     .surface_ball() // Ball (all-wrapping) surface
+//!     .rule("B3678/S34678".parse::<Rule>().unwrap()) // Day & Night
+//!     .build() // Build into a simulation
+//!     .unwrap();
+//!
+//! // Compare the simulation's rule (Day & Night) against HighLife (B36/S23)
+//! let report = simulation.co_simulate(Rule::HIGHLIFE, 100);
+//! println!("{:?}", report.first_divergence);
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::simulation::{hamming_distance, Simulation};
+
+/// The largest number of alive neighbors a cell can have with a radius of 1.
+const MAXIMUM_NEIGHBORS: u8 = 8;
+/// The largest neighborhood radius a rule can have. A radius of 5 gives each cell up to 120
+/// neighbors, which is as many as the birth and survival bitmasks can hold.
+pub const MAXIMUM_RADIUS: u8 = 5;
+
+/// Represents the birth and survival conditions used to compute each new generation.
+///
+/// Besides the usual rules where only the adjacent cells are neighbors, a rule can have a
+/// larger neighborhood radius, as in Larger than Life rules (see `Rule::larger_than_life`).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rule {
+    /// A bitmask where bit `n` is set if a dead cell with `n` alive neighbors is born.
+    pub(crate) birth: u128,
+    /// A bitmask where bit `n` is set if an alive cell with `n` alive neighbors survives.
+    pub(crate) survival: u128,
+    /// How far away (in rows or columns) the neighbors of a cell can be.
+    pub(crate) radius: u8,
+}
+
+impl Default for Rule {
+    /// Defines the default rule as Conway's Game of Life (B3/S23).
+    fn default() -> Self {
+        Rule::CONWAY
+    }
+}
+
+impl Rule {
+    /// Conway's Game of Life (B3/S23).
+    pub const CONWAY: Rule = Rule {
+        birth: 1 << 3,
+        survival: 1 << 2 | 1 << 3,
+        radius: 1,
+    };
+    /// HighLife (B36/S23), which is similar to Conway's Game of Life but has a small
+    /// replicator.
+    pub const HIGHLIFE: Rule = Rule {
+        birth: 1 << 3 | 1 << 6,
+        survival: 1 << 2 | 1 << 3,
+        radius: 1,
+    };
+    /// Day & Night (B3678/S34678), where alive and dead cells behave symmetrically.
+    pub const DAY_AND_NIGHT: Rule = Rule {
+        birth: 1 << 3 | 1 << 6 | 1 << 7 | 1 << 8,
+        survival: 1 << 3 | 1 << 4 | 1 << 6 | 1 << 7 | 1 << 8,
+        radius: 1,
+    };
+    /// Seeds (B2/S), where every alive cell dies in each generation.
+    pub const SEEDS: Rule = Rule {
+        birth: 1 << 2,
+        survival: 0,
+        radius: 1,
+    };
+    /// Life without Death (B3/S012345678), where alive cells never die.
+    pub const LIFE_WITHOUT_DEATH: Rule = Rule {
+        birth: 1 << 3,
+        survival: 0b1_1111_1111,
+        radius: 1,
+    };
+    /// Maze (B3/S12345), which grows maze-like corridors.
+    pub const MAZE: Rule = Rule {
+        birth: 1 << 3,
+        survival: 1 << 1 | 1 << 2 | 1 << 3 | 1 << 4 | 1 << 5,
+        radius: 1,
+    };
+
+    /// Creates a new `Rule` from the neighbor counts that cause a birth and the neighbor
+    /// counts that allow survival.
+    ///
+    /// # Arguments
+    /// * `birth` - The numbers of alive neighbors that cause a dead cell to be born.
+    /// * `survival` - The numbers of alive neighbors that allow an alive cell to survive.
+    ///
+    /// # Returns
+    /// * `Ok(Rule)` - The rule with the given birth and survival counts.
+    /// * `Err(String)` - An error message if any of the counts is greater than 8.
+    pub fn new(birth: &[u8], survival: &[u8]) -> Result<Rule, String> {
+        Ok(Rule {
+            birth: neighbor_mask(birth)?,
+            survival: neighbor_mask(survival)?,
+            radius: 1,
+        })
+    }
+
+    /// Creates a new Larger than Life `Rule`, where the neighbors of a cell are every cell
+    /// within the given radius and births and survivals happen for ranges of neighbor counts.
+    ///
+    /// # Description
+    /// In the `Moore` neighborhood, the neighbors of a cell are the `(2r+1)² - 1` other cells
+    /// in the square centered on it. In the `VonNeumann` neighborhood, they are the cells within
+    /// a Manhattan distance of `r`. A radius of 1 gives the usual neighborhoods, so
+    /// `Rule::larger_than_life(1, 3..=3, 2..=3)` is Conway's Game of Life.
+    ///
+    /// The neighbor counts do not include the cell itself.
+    ///
+    /// # Arguments
+    /// * `radius` - How far away (in rows or columns) the neighbors of a cell can be, from 1 to
+    ///   `MAXIMUM_RADIUS`.
+    /// * `birth` - The range of alive neighbor counts that cause a dead cell to be born.
+    /// * `survival` - The range of alive neighbor counts that allow an alive cell to survive.
+    ///
+    /// # Returns
+    /// * `Ok(Rule)` - The rule with the given radius and ranges.
+    /// * `Err(String)` - An error message if the radius is out of range, or if either range is
+    ///   empty or includes more neighbors than a cell can have with the given radius.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use simple_game_of_life::rule::Rule;
+    ///
+    /// // Bosco's Rule (R5,C0,M1,S34..58,B34..45,NM)
+    /// let bosco: Rule = Rule::larger_than_life(5, 34..=45, 33..=57).unwrap();
+    /// ```
+    pub fn larger_than_life(
+        radius: u8,
+        birth: RangeInclusive<u8>,
+        survival: RangeInclusive<u8>,
+    ) -> Result<Rule, String> {
+        if !(1..=MAXIMUM_RADIUS).contains(&radius) {
+            return Err(format!(
+                "Unexpected radius of {}, the radius must be between 1 and {}",
+                radius, MAXIMUM_RADIUS
+            ));
+        }
+        let maximum_neighbors: u8 = maximum_neighbors(radius);
+        let range_mask = |name: &str, range: RangeInclusive<u8>| -> Result<u128, String> {
+            if range.is_empty() {
+                return Err(format!("The {} range {:?} is empty", name, range));
+            }
+            if *range.end() > maximum_neighbors {
+                return Err(format!(
+                    "Unexpected {} range {:?}, counts must be between 0 and {} with a radius of {}",
+                    name, range, maximum_neighbors, radius
+                ));
+            }
+            Ok(range.fold(0, |mask: u128, count: u8| mask | 1 << count))
+        };
+        Ok(Rule {
+            birth: range_mask("birth", birth)?,
+            survival: range_mask("survival", survival)?,
+            radius,
+        })
+    }
+
+    /// Returns how far away (in rows or columns) the neighbors of a cell can be.
+    pub fn radius(&self) -> u8 {
+        self.radius
+    }
+
+    /// Returns true if a dead cell with the given number of alive neighbors is born.
+    pub fn births(&self, alive_neighbors: u8) -> bool {
+        (alive_neighbors as u32) < u128::BITS && self.birth & (1 << alive_neighbors) != 0
+    }
+
+    /// Returns true if an alive cell with the given number of alive neighbors survives.
+    pub fn survives(&self, alive_neighbors: u8) -> bool {
+        (alive_neighbors as u32) < u128::BITS && self.survival & (1 << alive_neighbors) != 0
+    }
+}
+
+impl Display for Rule {
+    /// Renders the rule in B/S notation, such as `B3/S23` for Conway's Game of Life.
+    ///
+    /// Rules with a radius greater than 1 are rendered in Larger than Life notation instead,
+    /// such as `R5,C0,M0,S33..57,B34..45` for Bosco's Rule. The neighborhood is not part of the
+    /// rule, so it is left out.
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.radius > 1 {
+            let range = |mask: u128| (mask.trailing_zeros(), 127 - mask.leading_zeros());
+            let (survival_start, survival_end) = range(self.survival);
+            let (birth_start, birth_end) = range(self.birth);
+            return write!(
+                f,
+                "R{},C0,M0,S{}..{},B{}..{}",
+                self.radius, survival_start, survival_end, birth_start, birth_end
+            );
+        }
+        write!(f, "B")?;
+        for count in 0..=MAXIMUM_NEIGHBORS {
+            if self.births(count) {
+                write!(f, "{}", count)?;
+            }
+        }
+        write!(f, "/S")?;
+        for count in 0..=MAXIMUM_NEIGHBORS {
+            if self.survives(count) {
+                write!(f, "{}", count)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    /// Parses a rule from B/S notation, such as `"B36/S23"` for HighLife.
+    ///
+    /// # Description
+    /// The birth and survival parts may be given in either order and in either letter case
+    /// (`"s23/b3"` is the same as `"B3/S23"`), and either part may have no counts (`"B2/S"`).
+    /// The older survival/birth notation without letters (`"23/3"`) is also accepted.
+    ///
+    /// Larger than Life rules are parsed from their `Rr,Cc,Mm,Smin..max,Bmin..max,Nn`
+    /// notation, such as `"R5,C0,M1,S34..58,B34..45,NM"` for Bosco's Rule (see
+    /// `larger_than_life`). `C` must be 0 or 2, since cells are either alive or dead, and `N`
+    /// must be `M` (Moore) if given, since the neighborhood is set separately with
+    /// `SimulationBuilder::neighborhood`. With `M1`, the survival counts include the cell
+    /// itself, so they are lowered by one.
+    ///
+    /// # Returns
+    /// * `Ok(Rule)` - The parsed rule.
+    /// * `Err(String)` - An error message if the string is not a valid rule.
+    fn from_str(rule: &str) -> Result<Rule, String> {
+        if rule.trim().starts_with(['R', 'r']) {
+            return larger_than_life_from_str(rule);
+        }
+        let invalid = || {
+            format!(
+                "Invalid rule \"{}\", expected a rule such as \"B3/S23\"",
+                rule
+            )
+        };
+        let (first, second) = rule.trim().split_once('/').ok_or_else(invalid)?;
+        let counts = |part: &str| -> Result<Vec<u8>, String> {
+            part.chars()
+                .map(|count| {
+                    count
+                        .to_digit(10)
+                        .map(|count| count as u8)
+                        .ok_or_else(invalid)
+                })
+                .collect()
+        };
+        let (birth, survival) = match (first.chars().next(), second.chars().next()) {
+            (Some('B' | 'b'), Some('S' | 's')) => (&first[1..], &second[1..]),
+            (Some('S' | 's'), Some('B' | 'b')) => (&second[1..], &first[1..]),
+            _ => (second, first),
+        };
+        Rule::new(&counts(birth)?, &counts(survival)?)
+    }
+}
+
+/// The named rules that can be selected with `SimulationBuilder::rule_preset`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Preset {
+    /// Conway's Game of Life (B3/S23, see `Rule::CONWAY`).
+    Conway,
+    /// HighLife (B36/S23, see `Rule::HIGHLIFE`).
+    HighLife,
+    /// Seeds (B2/S, see `Rule::SEEDS`).
+    Seeds,
+    /// Day & Night (B3678/S34678, see `Rule::DAY_AND_NIGHT`).
+    DayAndNight,
+    /// Life without Death (B3/S012345678, see `Rule::LIFE_WITHOUT_DEATH`).
+    LifeWithoutDeath,
+    /// Maze (B3/S12345, see `Rule::MAZE`).
+    Maze,
+}
+
+impl Preset {
+    /// Returns the rule of the preset.
+    pub fn rule(&self) -> Rule {
+        match self {
+            Preset::Conway => Rule::CONWAY,
+            Preset::HighLife => Rule::HIGHLIFE,
+            Preset::Seeds => Rule::SEEDS,
+            Preset::DayAndNight => Rule::DAY_AND_NIGHT,
+            Preset::LifeWithoutDeath => Rule::LIFE_WITHOUT_DEATH,
+            Preset::Maze => Rule::MAZE,
+        }
+    }
+}
+
+impl From<Preset> for Rule {
+    /// Converts a preset into its rule.
+    fn from(preset: Preset) -> Rule {
+        preset.rule()
+    }
+}
+
+/// Parses a Larger than Life rule, such as `"R5,C0,M1,S34..58,B34..45,NM"`.
+fn larger_than_life_from_str(rule: &str) -> Result<Rule, String> {
+    let invalid = || {
+        format!(
+            "Invalid rule \"{}\", expected a rule such as \"R5,C0,M1,S34..58,B34..45,NM\"",
+            rule
+        )
+    };
+    let number = |text: &str| text.trim().parse::<u8>().map_err(|_| invalid());
+    let range = |text: &str| -> Result<RangeInclusive<u8>, String> {
+        match text.split_once("..") {
+            Some((start, end)) => Ok(number(start)?..=number(end)?),
+            None => number(text).map(|count| count..=count),
+        }
+    };
+    let mut radius: Option<u8> = None;
+    let mut middle_included: bool = false;
+    let mut birth: Option<RangeInclusive<u8>> = None;
+    let mut survival: Option<RangeInclusive<u8>> = None;
+    for part in rule.trim().split(',') {
+        let part: &str = part.trim();
+        let value: &str = part.get(1..).ok_or_else(invalid)?;
+        match part
+            .chars()
+            .next()
+            .map(|letter| letter.to_ascii_uppercase())
+        {
+            Some('R') => radius = Some(number(value)?),
+            Some('C') if matches!(number(value)?, 0 | 2) => {}
+            Some('C') => {
+                return Err(format!(
+                    "Invalid rule \"{}\", only rules with 2 states (C0 or C2) are supported",
+                    rule
+                ));
+            }
+            Some('M') => {
+                middle_included = match number(value)? {
+                    0 => false,
+                    1 => true,
+                    _ => return Err(invalid()),
+                }
+            }
+            Some('S') => survival = Some(range(value)?),
+            Some('B') => birth = Some(range(value)?),
+            Some('N') if value.eq_ignore_ascii_case("M") => {}
+            Some('N') => {
+                return Err(format!(
+                    "Invalid rule \"{}\", only the Moore neighborhood (NM) can be given in a rule, use SimulationBuilder::neighborhood for others",
+                    rule
+                ));
+            }
+            _ => return Err(invalid()),
+        }
+    }
+    let (radius, birth, survival) = match (radius, birth, survival) {
+        (Some(radius), Some(birth), Some(survival)) => (radius, birth, survival),
+        _ => return Err(invalid()),
+    };
+    let survival: RangeInclusive<u8> = match (middle_included, survival.end().checked_sub(1)) {
+        (true, Some(end)) => survival.start().saturating_sub(1)..=end,
+        // An alive cell counts itself with M1, so it never has 0 alive neighbors
+        (true, None) => {
+            return Err(format!(
+                "Invalid rule \"{}\", the survival range is empty since M1 counts the cell itself",
+                rule
+            ))
+        }
+        (false, _) => survival,
+    };
+    Rule::larger_than_life(radius, birth, survival)
+}
+
+/// Returns the largest number of alive neighbors a cell can have with the given radius.
+fn maximum_neighbors(radius: u8) -> u8 {
+    let width: u8 = 2 * radius + 1;
+    width * width - 1
+}
+
+/// Converts a list of neighbor counts into a bitmask, rejecting counts greater than 8.
+fn neighbor_mask(counts: &[u8]) -> Result<u128, String> {
+    let mut mask: u128 = 0;
+    for &count in counts {
+        if count > MAXIMUM_NEIGHBORS {
+            return Err(format!(
+                "Unexpected neighbor count of {}, counts must be between 0 and {}",
+                count, MAXIMUM_NEIGHBORS
+            ));
+        }
+        mask |= 1 << count;
+    }
+    Ok(mask)
+}
+
+/// The results of running the same generation under two different rules in lockstep.
+#[derive(Clone, Debug)]
+pub struct CoSimulationReport {
+    /// The Hamming distance (number of differing cells) between the two generations after
+    /// each simulated generation.
+    pub distances: Vec<u64>,
+    /// The iteration at which the two generations first differed, if they ever did.
+    pub first_divergence: Option<u128>,
+    /// The string representation of the final generation under the simulation's own rule.
+    pub final_generation_a: String,
+    /// The string representation of the final generation under the second rule.
+    pub final_generation_b: String,
+}
+
+impl Simulation {
+    /// Runs the current generation under the simulation's own rule and a second rule in
+    /// lockstep, comparing the two at every generation.
+    ///
+    /// # Description
+    /// This function creates two internal copies of the simulation starting from its current
+    /// generation, one using the simulation's own `Rule` and one using `rule_b`, and steps both
+    /// of them together for the given number of generations.
+    ///
+    /// After each generation, the Hamming distance between the two copies (the number of cells
+    /// that are alive in one and dead in the other) is recorded. The iteration at which the
+    /// distance first becomes non-zero is reported as the first divergence.
+    ///
+    /// The simulation itself is not advanced, and nothing is printed or displayed.
+    ///
+    /// # Arguments
+    /// * `rule_b` - The rule to compare against the simulation's own rule.
+    /// * `generations` - The number of generations to simulate under both rules.
+    ///
+    /// # Returns
+    /// A `CoSimulationReport` containing the distance time series, the first divergence
+    /// iteration, and the string representations of both final generations.
+    pub fn co_simulate(&self, rule_b: Rule, generations: u128) -> CoSimulationReport {
+        let mut simulation_a: Simulation = self.headless_clone();
+        let mut simulation_b: Simulation = self.headless_clone();
+        simulation_b.rule = rule_b;
+        let mut distances: Vec<u64> = Vec::new();
+        let mut first_divergence: Option<u128> = None;
+        for _ in 0..generations {
+            simulation_a.generation = simulation_a.next_generation();
+            simulation_a.iteration += 1;
+            simulation_b.generation = simulation_b.next_generation();
+            simulation_b.iteration += 1;
+            let distance: u64 =
+                hamming_distance(&simulation_a.generation, &simulation_b.generation);
+            if distance > 0 && first_divergence.is_none() {
+                first_divergence = Some(simulation_a.iteration);
+            }
+            distances.push(distance);
+        }
+        CoSimulationReport {
+            distances,
+            first_divergence,
+            final_generation_a: simulation_a.generation_string(),
+            final_generation_b: simulation_b.generation_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::simulation::Simulation;
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// The HighLife replicator, which makes two copies of itself every 12 generations.
+    const REPLICATOR: [(u16, u16); 12] = [
+        (0, 2),
+        (0, 3),
+        (0, 4),
+        (1, 1),
+        (1, 4),
+        (2, 0),
+        (2, 4),
+        (3, 0),
+        (3, 3),
+        (4, 0),
+        (4, 1),
+        (4, 2),
+    ];
+
+    /// Builds a simulation of the given preset with the given cells alive.
+    fn build(preset: Preset, rows: u16, columns: u16, cells: &[(u16, u16)]) -> Simulation {
+        SimulationBuilder::new()
+            .height(rows)
+            .width(columns)
+            .seed_fn({
+                let cells: Vec<(u16, u16)> = cells.to_vec();
+                move |row, column| cells.contains(&(row, column))
+            })
+            .rule_preset(preset)
+            .build()
+            .unwrap()
+    }
+
+    /// Returns the positions of the alive cells of a simulation.
+    fn alive(simulation: &Simulation) -> HashSet<(u16, u16)> {
+        simulation.alive_cells().collect()
+    }
+
+    #[test]
+    fn presets_match_their_rule_strings() {
+        let presets: [(Preset, &str); 6] = [
+            (Preset::Conway, "B3/S23"),
+            (Preset::HighLife, "B36/S23"),
+            (Preset::Seeds, "B2/S"),
+            (Preset::DayAndNight, "B3678/S34678"),
+            (Preset::LifeWithoutDeath, "B3/S012345678"),
+            (Preset::Maze, "B3/S12345"),
+        ];
+        for (preset, rule) in presets {
+            assert_eq!(preset.rule(), rule.parse::<Rule>().unwrap(), "{:?}", preset);
+            assert_eq!(preset.rule().to_string(), rule);
+            assert_eq!(Rule::from(preset), preset.rule());
+        }
+    }
+
+    #[test]
+    fn conway_blinker_oscillates() {
+        let mut simulation: Simulation = build(Preset::Conway, 5, 5, &[(2, 1), (2, 2), (2, 3)]);
+        simulation.simulate_generation();
+        assert_eq!(alive(&simulation), HashSet::from([(1, 2), (2, 2), (3, 2)]));
+        simulation.simulate_generation();
+        assert_eq!(alive(&simulation), HashSet::from([(2, 1), (2, 2), (2, 3)]));
+    }
+
+    #[test]
+    fn highlife_replicator_copies_itself() {
+        let seed: Vec<(u16, u16)> = REPLICATOR
+            .iter()
+            .map(|&(row, column)| (row + 12, column + 12))
+            .collect();
+        let mut simulation: Simulation = build(Preset::HighLife, 30, 30, &seed);
+        simulation.simulate_generations(12);
+        let copies: HashSet<(u16, u16)> = seed
+            .iter()
+            .flat_map(|&(row, column)| [(row - 2, column - 2), (row + 2, column + 2)])
+            .collect();
+        assert_eq!(alive(&simulation), copies);
+
+        // Conway's Game of Life has no birth on 6 neighbors, so the replicator does not copy
+        let mut conway: Simulation = build(Preset::Conway, 30, 30, &seed);
+        conway.simulate_generations(12);
+        assert_ne!(alive(&conway), copies);
+    }
+
+    #[test]
+    fn seeds_cells_always_die() {
+        let mut simulation: Simulation = build(Preset::Seeds, 6, 6, &[(2, 2), (2, 3)]);
+        simulation.simulate_generation();
+        assert_eq!(
+            alive(&simulation),
+            HashSet::from([(1, 2), (1, 3), (3, 2), (3, 3)])
+        );
+        for _ in 0..10 {
+            let previous: HashSet<(u16, u16)> = alive(&simulation);
+            simulation.simulate_generation();
+            assert!(alive(&simulation).is_disjoint(&previous));
+        }
+    }
+
+    #[test]
+    fn day_and_night_treats_alive_and_dead_cells_symmetrically() {
+        let soup: Simulation = SimulationBuilder::new()
+            .height(16)
+            .width(16)
+            .surface_ball()
+            .alive_probability(0.4)
+            .rng_seed(5)
+            .rule_preset(Preset::DayAndNight)
+            .build()
+            .unwrap();
+        let cells: HashSet<(u16, u16)> = alive(&soup);
+        let inverted_cells: Vec<(u16, u16)> = (0..16)
+            .flat_map(|row| (0..16).map(move |column| (row, column)))
+            .filter(|position| !cells.contains(position))
+            .collect();
+        let mut simulation: Simulation = soup.clone();
+        let mut inverted: Simulation = SimulationBuilder::new()
+            .height(16)
+            .width(16)
+            .surface_ball()
+            .seed_fn(move |row, column| inverted_cells.contains(&(row, column)))
+            .rule_preset(Preset::DayAndNight)
+            .build()
+            .unwrap();
+        for _ in 0..5 {
+            simulation.simulate_generation();
+            inverted.simulate_generation();
+            assert_eq!(alive(&simulation).len() + alive(&inverted).len(), 16 * 16);
+            assert!(alive(&simulation).is_disjoint(&alive(&inverted)));
+        }
+    }
+
+    #[test]
+    fn life_without_death_cells_never_die() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(20)
+            .width(20)
+            .alive_probability(0.1)
+            .rng_seed(3)
+            .rule_preset(Preset::LifeWithoutDeath)
+            .build()
+            .unwrap();
+        for _ in 0..10 {
+            let previous: HashSet<(u16, u16)> = alive(&simulation);
+            simulation.simulate_generation();
+            assert!(alive(&simulation).is_superset(&previous));
+        }
+    }
+
+    #[test]
+    fn maze_domino_survives_with_one_neighbor() {
+        let domino: [(u16, u16); 2] = [(2, 2), (2, 3)];
+        let mut maze: Simulation = build(Preset::Maze, 5, 6, &domino);
+        maze.simulate_generations(3);
+        assert_eq!(alive(&maze), HashSet::from(domino));
+
+        let mut conway: Simulation = build(Preset::Conway, 5, 6, &domino);
+        conway.simulate_generation();
+        assert!(alive(&conway).is_empty());
+    }
+
+    #[test]
+    fn co_simulating_life_and_highlife_diverges_when_the_replicator_first_copies() {
+        let seed: Vec<(u16, u16)> = REPLICATOR
+            .iter()
+            .map(|&(row, column)| (row + 12, column + 12))
+            .collect();
+        let simulation: Simulation = build(Preset::Conway, 30, 30, &seed);
+        let report: CoSimulationReport = simulation.co_simulate(Preset::HighLife.rule(), 20);
+
+        // The replicator's first cell with six neighbors is born in the second generation
+        assert_eq!(report.first_divergence, Some(2));
+        assert_eq!(report.distances.len(), 20);
+        assert_eq!(report.distances[0], 0);
+
+        let mut conway: Simulation = build(Preset::Conway, 30, 30, &seed);
+        let mut highlife: Simulation = build(Preset::HighLife, 30, 30, &seed);
+        for &distance in &report.distances {
+            conway.simulate_generation();
+            highlife.simulate_generation();
+            let differing: usize = alive(&conway)
+                .symmetric_difference(&alive(&highlife))
+                .count();
+            assert_eq!(distance, differing as u64);
+        }
+        assert_eq!(report.final_generation_a, conway.generation_string());
+        assert_eq!(report.final_generation_b, highlife.generation_string());
+        assert_eq!(simulation.iteration(), 0);
+    }
+
+    #[test]
+    fn co_simulating_the_same_rule_never_diverges() {
+        let simulation: Simulation = build(Preset::Conway, 30, 30, &REPLICATOR);
+        let report: CoSimulationReport = simulation.co_simulate(Preset::Conway.rule(), 7);
+        assert_eq!(report.distances, vec![0; 7]);
+        assert_eq!(report.first_divergence, None);
+        assert_eq!(report.final_generation_a, report.final_generation_b);
+    }
+}
@@ -0,0 +1,447 @@
+//! Random seed generators with spatial structure.
+//!
+//! Uniform random seeds (such as those from `simulation::random_seed`) are homogeneous. The
+//! generators in this module produce structured initial conditions instead: clusters of alive
+//! cells, a density gradient, or smooth noise. Every generator takes the random number generator
+//! to use, so seeds are reproducible when a seeded generator (such as `rand::rngs::StdRng`) is
+//! used.
+//!
+//! # Example
+//! ```rust,no_run
+//! use rand::rngs::StdRng;
+//! use rand::SeedableRng;
+//! use simple_game_of_life::seeding::{clustered, SeedGenerator};
+//! use simple_game_of_life::simulation_builder::SimulationBuilder;
+//!
+//! // A reproducible seed with 4 clusters of alive cells
+//! let mut rng = StdRng::seed_from_u64(42);
+//! let seed: String = clustered(30, 30, 4, 3.0, 0.6, &mut rng);
+//!
+//! // A simulation seeded with smooth noise
+//! let simulation = SimulationBuilder::new()
+//!     .height(30)
+//!     .width(30)
+//!     .seed_generator(SeedGenerator::Perlin {
+//!         scale: 6.0,
+//!         threshold: 0.6,
+//!     })
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use rand::Rng;
+
+use crate::cell::{ALIVE_CHAR, DEAD_CHAR};
+
+/// The direction a density gradient runs in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Axis {
+    /// The density changes from the left column to the right column.
+    Horizontal,
+    /// The density changes from the top row to the bottom row.
+    Vertical,
+}
+
+/// A structured seed generator, used with `SimulationBuilder::seed_generator`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SeedGenerator {
+    /// Gaussian blobs of alive cells (see `clustered`).
+    Clustered {
+        /// The number of clusters.
+        cluster_count: u16,
+        /// The standard deviation of each cluster in cells.
+        cluster_radius: f64,
+        /// The chance that a cell at the center of a cluster is alive.
+        density: f64,
+    },
+    /// A linear change in density across the grid (see `gradient`).
+    Gradient {
+        /// The chance that a cell on the first row or column is alive.
+        from_density: f64,
+        /// The chance that a cell on the last row or column is alive.
+        to_density: f64,
+        /// The direction the density changes in.
+        axis: Axis,
+    },
+    /// Thresholded value noise (see `perlin`).
+    Perlin {
+        /// The size of the noise features in cells.
+        scale: f64,
+        /// The noise value above which cells are alive, between 0 and 1.
+        threshold: f64,
+    },
+}
+
+impl SeedGenerator {
+    /// Generates a seed string for a grid of the given size with this generator.
+    pub fn generate<R: Rng + ?Sized>(&self, rows: u16, columns: u16, rng: &mut R) -> String {
+        match *self {
+            SeedGenerator::Clustered {
+                cluster_count,
+                cluster_radius,
+                density,
+            } => clustered(rows, columns, cluster_count, cluster_radius, density, rng),
+            SeedGenerator::Gradient {
+                from_density,
+                to_density,
+                axis,
+            } => gradient(rows, columns, from_density, to_density, axis, rng),
+            SeedGenerator::Perlin { scale, threshold } => {
+                perlin(rows, columns, scale, threshold, rng)
+            }
+        }
+    }
+}
+
+/// Generates a seed string with Gaussian blobs of alive cells.
+///
+/// # Description
+/// The centers of the clusters are placed uniformly at random on the grid. Each cell is then
+/// alive with a probability of `density * e^(-d² / 2r²)`, where `d` is the distance to the
+/// nearest cluster center and `r` is the cluster radius, so cells near a center are about as
+/// dense as `density` and the density falls off smoothly away from it.
+///
+/// # Arguments
+/// * `rows` - The number of rows in the seed.
+/// * `columns` - The number of columns in the seed.
+/// * `cluster_count` - The number of clusters.
+/// * `cluster_radius` - The standard deviation of each cluster in cells. If it is not positive,
+///   only the center cell of each cluster can be alive.
+/// * `density` - The chance that a cell at the center of a cluster is alive, between 0 and 1.
+/// * `rng` - The random number generator to use.
+///
+/// # Returns
+/// The seed string, where `'*'` represents an alive cell and `'-'` represents a dead cell.
+pub fn clustered<R: Rng + ?Sized>(
+    rows: u16,
+    columns: u16,
+    cluster_count: u16,
+    cluster_radius: f64,
+    density: f64,
+    rng: &mut R,
+) -> String {
+    let centers: Vec<(f64, f64)> = (0..cluster_count)
+        .map(|_| {
+            (
+                rng.gen_range(0.0..rows.max(1) as f64),
+                rng.gen_range(0.0..columns.max(1) as f64),
+            )
+        })
+        .collect();
+    seed_from_probabilities(rows, columns, rng, |row, column| {
+        let nearest_squared_distance: f64 = centers
+            .iter()
+            .map(|(center_row, center_column)| {
+                (row - center_row).powi(2) + (column - center_column).powi(2)
+            })
+            .fold(f64::INFINITY, f64::min);
+        if cluster_radius > 0.0 {
+            density * (-nearest_squared_distance / (2.0 * cluster_radius.powi(2))).exp()
+        } else if nearest_squared_distance < 0.25 {
+            density
+        } else {
+            0.0
+        }
+    })
+}
+
+/// Generates a seed string whose density changes linearly across the grid.
+///
+/// # Arguments
+/// * `rows` - The number of rows in the seed.
+/// * `columns` - The number of columns in the seed.
+/// * `from_density` - The chance that a cell on the first row or column is alive.
+/// * `to_density` - The chance that a cell on the last row or column is alive.
+/// * `axis` - The direction the density changes in.
+/// * `rng` - The random number generator to use.
+///
+/// # Returns
+/// The seed string, where `'*'` represents an alive cell and `'-'` represents a dead cell.
+pub fn gradient<R: Rng + ?Sized>(
+    rows: u16,
+    columns: u16,
+    from_density: f64,
+    to_density: f64,
+    axis: Axis,
+    rng: &mut R,
+) -> String {
+    let length: f64 = match axis {
+        Axis::Horizontal => columns,
+        Axis::Vertical => rows,
+    }
+    .saturating_sub(1)
+    .max(1) as f64;
+    seed_from_probabilities(rows, columns, rng, |row, column| {
+        let position: f64 = match axis {
+            Axis::Horizontal => column,
+            Axis::Vertical => row,
+        };
+        from_density + (to_density - from_density) * position / length
+    })
+}
+
+/// Generates a seed string from thresholded value noise.
+///
+/// # Description
+/// A random value between 0 and 1 is chosen for every point of a lattice with a spacing of
+/// `scale` cells. The noise value of each cell is interpolated (with smoothstep easing) from the
+/// four lattice points around it, and the cell is alive if its noise value is above the
+/// threshold. This produces smooth, blob-like regions of alive cells whose size is controlled by
+/// the scale.
+///
+/// # Arguments
+/// * `rows` - The number of rows in the seed.
+/// * `columns` - The number of columns in the seed.
+/// * `scale` - The size of the noise features in cells. Scales below 1 are treated as 1.
+/// * `threshold` - The noise value above which cells are alive, between 0 and 1.
+/// * `rng` - The random number generator to use.
+///
+/// # Returns
+/// The seed string, where `'*'` represents an alive cell and `'-'` represents a dead cell.
+pub fn perlin<R: Rng + ?Sized>(
+    rows: u16,
+    columns: u16,
+    scale: f64,
+    threshold: f64,
+    rng: &mut R,
+) -> String {
+    let scale: f64 = scale.max(1.0);
+    let lattice_rows: usize = (rows as f64 / scale).ceil() as usize + 2;
+    let lattice_columns: usize = (columns as f64 / scale).ceil() as usize + 2;
+    let lattice: Vec<f64> = (0..lattice_rows * lattice_columns)
+        .map(|_| rng.gen_range(0.0..1.0))
+        .collect();
+    let value = |row: usize, column: usize| lattice[row * lattice_columns + column];
+    let smoothstep = |t: f64| t * t * (3.0 - 2.0 * t);
+    (0..rows)
+        .flat_map(|row| (0..columns).map(move |column| (row, column)))
+        .map(|(row, column)| {
+            let y: f64 = row as f64 / scale;
+            let x: f64 = column as f64 / scale;
+            let (top, left) = (y.floor() as usize, x.floor() as usize);
+            let (ty, tx) = (smoothstep(y.fract()), smoothstep(x.fract()));
+            let upper: f64 = value(top, left) + (value(top, left + 1) - value(top, left)) * tx;
+            let lower: f64 =
+                value(top + 1, left) + (value(top + 1, left + 1) - value(top + 1, left)) * tx;
+            if upper + (lower - upper) * ty > threshold {
+                ALIVE_CHAR
+            } else {
+                DEAD_CHAR
+            }
+        })
+        .collect()
+}
+
+/// Generates a seed string where each cell is alive with the probability returned by
+/// `probability` for its `(row, column)`.
+fn seed_from_probabilities<R: Rng + ?Sized, F: Fn(f64, f64) -> f64>(
+    rows: u16,
+    columns: u16,
+    rng: &mut R,
+    probability: F,
+) -> String {
+    (0..rows)
+        .flat_map(|row| (0..columns).map(move |column| (row, column)))
+        .map(|(row, column)| {
+            if rng.gen_range(0.0..1.0) < probability(row as f64, column as f64) {
+                ALIVE_CHAR
+            } else {
+                DEAD_CHAR
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::simulation::Simulation;
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// Returns the fraction of the cells of a seed string that are alive.
+    fn density(seed: &str) -> f64 {
+        seed.chars().filter(|&char| char == ALIVE_CHAR).count() as f64 / seed.len() as f64
+    }
+
+    /// Returns the fraction of the cells within the given rows and columns of a seed string
+    /// that are alive.
+    fn region_density(
+        seed: &str,
+        columns: u16,
+        rows: std::ops::Range<u16>,
+        region_columns: std::ops::Range<u16>,
+    ) -> f64 {
+        let cells: Vec<char> = seed.chars().collect();
+        let region: Vec<char> = rows
+            .flat_map(|row| {
+                region_columns
+                    .clone()
+                    .map(move |column| row as usize * columns as usize + column as usize)
+            })
+            .map(|index| cells[index])
+            .collect();
+        region.iter().filter(|&&char| char == ALIVE_CHAR).count() as f64 / region.len() as f64
+    }
+
+    /// Returns the number of horizontally adjacent pairs of cells in a seed string where one
+    /// cell is alive and the other is dead.
+    fn boundaries(seed: &str, columns: u16) -> usize {
+        let cells: Vec<char> = seed.chars().collect();
+        cells
+            .chunks(columns as usize)
+            .map(|row| row.windows(2).filter(|pair| pair[0] != pair[1]).count())
+            .sum()
+    }
+
+    #[test]
+    fn generators_are_deterministic_under_a_fixed_rng() {
+        let generators: [SeedGenerator; 3] = [
+            SeedGenerator::Clustered {
+                cluster_count: 3,
+                cluster_radius: 2.5,
+                density: 0.7,
+            },
+            SeedGenerator::Gradient {
+                from_density: 0.0,
+                to_density: 1.0,
+                axis: Axis::Vertical,
+            },
+            SeedGenerator::Perlin {
+                scale: 5.0,
+                threshold: 0.5,
+            },
+        ];
+        for generator in generators {
+            let first: String = generator.generate(40, 30, &mut StdRng::seed_from_u64(3));
+            let second: String = generator.generate(40, 30, &mut StdRng::seed_from_u64(3));
+            let other: String = generator.generate(40, 30, &mut StdRng::seed_from_u64(4));
+            assert_eq!(first.len(), 40 * 30);
+            assert_eq!(first, second, "{:?}", generator);
+            assert_ne!(first, other, "{:?}", generator);
+        }
+    }
+
+    #[test]
+    fn builder_seed_generator_is_deterministic_under_a_fixed_rng_seed() {
+        let build = || -> Simulation {
+            SimulationBuilder::new()
+                .height(20)
+                .width(20)
+                .rng_seed(11)
+                .seed_generator(SeedGenerator::Perlin {
+                    scale: 4.0,
+                    threshold: 0.5,
+                })
+                .build()
+                .unwrap()
+        };
+        assert_eq!(build().seed(), build().seed());
+    }
+
+    #[test]
+    fn gradient_density_follows_the_axis() {
+        let mut rng: StdRng = StdRng::seed_from_u64(7);
+        let horizontal: String = gradient(100, 100, 0.1, 0.9, Axis::Horizontal, &mut rng);
+        assert!((0.45..0.55).contains(&density(&horizontal)));
+        assert!((0.05..0.2).contains(&region_density(&horizontal, 100, 0..100, 0..10)));
+        assert!((0.8..0.95).contains(&region_density(&horizontal, 100, 0..100, 90..100)));
+
+        let vertical: String = gradient(100, 100, 0.8, 0.2, Axis::Vertical, &mut rng);
+        assert!((0.45..0.55).contains(&density(&vertical)));
+        assert!((0.7..0.85).contains(&region_density(&vertical, 100, 0..10, 0..100)));
+        assert!((0.15..0.3).contains(&region_density(&vertical, 100, 90..100, 0..100)));
+    }
+
+    #[test]
+    fn gradient_with_equal_densities_is_uniform() {
+        let mut rng: StdRng = StdRng::seed_from_u64(7);
+        assert_eq!(
+            density(&gradient(30, 30, 0.0, 0.0, Axis::Vertical, &mut rng)),
+            0.0
+        );
+        assert_eq!(
+            density(&gradient(30, 30, 1.0, 1.0, Axis::Vertical, &mut rng)),
+            1.0
+        );
+    }
+
+    #[test]
+    fn clustered_cells_are_dense_near_the_center_of_a_cluster() {
+        let mut rng: StdRng = StdRng::seed_from_u64(7);
+        let seed: String = clustered(100, 100, 5, 4.0, 0.8, &mut rng);
+        // Each cluster holds about 0.8 * 2π * 4² ≈ 80 alive cells
+        assert!((0.02..0.06).contains(&density(&seed)));
+
+        let seed: String = clustered(100, 100, 1, 4.0, 0.8, &mut rng);
+        let alive: Vec<(f64, f64)> = seed
+            .chars()
+            .enumerate()
+            .filter(|&(_, char)| char == ALIVE_CHAR)
+            .map(|(index, _)| ((index / 100) as f64, (index % 100) as f64))
+            .collect();
+        let count: f64 = alive.len() as f64;
+        let center: (f64, f64) = (
+            alive.iter().map(|cell| cell.0).sum::<f64>() / count,
+            alive.iter().map(|cell| cell.1).sum::<f64>() / count,
+        );
+        let distance = |cell: &(f64, f64)| -> f64 {
+            ((cell.0 - center.0).powi(2) + (cell.1 - center.1).powi(2)).sqrt()
+        };
+        // Almost every alive cell is within 3 standard deviations of the center
+        let near: usize = alive.iter().filter(|cell| distance(cell) < 12.0).count();
+        assert!(near as f64 / count > 0.95, "{} of {}", near, count);
+    }
+
+    #[test]
+    fn clustered_without_clusters_or_radius_is_almost_empty() {
+        let mut rng: StdRng = StdRng::seed_from_u64(7);
+        assert_eq!(density(&clustered(50, 50, 0, 4.0, 0.8, &mut rng)), 0.0);
+        let centers_only: String = clustered(50, 50, 3, 0.0, 1.0, &mut rng);
+        assert!(
+            centers_only
+                .chars()
+                .filter(|&char| char == ALIVE_CHAR)
+                .count()
+                <= 3
+        );
+    }
+
+    #[test]
+    fn perlin_density_falls_as_the_threshold_rises() {
+        let densities: Vec<f64> = [0.0, 0.3, 0.5, 0.7, 1.0]
+            .iter()
+            .map(|&threshold| {
+                density(&perlin(
+                    100,
+                    100,
+                    8.0,
+                    threshold,
+                    &mut StdRng::seed_from_u64(7),
+                ))
+            })
+            .collect();
+        assert_eq!(densities[0], 1.0);
+        assert!((0.6..0.95).contains(&densities[1]), "{:?}", densities);
+        assert!((0.3..0.7).contains(&densities[2]), "{:?}", densities);
+        assert!((0.05..0.4).contains(&densities[3]), "{:?}", densities);
+        assert_eq!(densities[4], 0.0);
+    }
+
+    #[test]
+    fn perlin_noise_is_smoother_than_a_uniform_soup() {
+        let mut rng: StdRng = StdRng::seed_from_u64(7);
+        let noise: String = perlin(100, 100, 8.0, 0.5, &mut rng);
+        let soup: String = gradient(
+            100,
+            100,
+            density(&noise),
+            density(&noise),
+            Axis::Vertical,
+            &mut rng,
+        );
+        assert!(boundaries(&noise, 100) * 4 < boundaries(&soup, 100));
+    }
+}
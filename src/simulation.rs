@@ -27,24 +27,68 @@
 //! simulation.reset_to_rand()
 //! ```
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::{Debug, Display, Formatter};
-use std::iter::repeat;
-use std::thread::sleep;
-use std::time::Duration;
+use std::fs::create_dir_all;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Arc;
+use std::thread::{sleep, spawn};
+use std::time::{Duration, Instant};
 
 use crate::rand::distributions::Distribution;
 use rand::distributions::Uniform;
 use rand::prelude::ThreadRng;
-use rand::thread_rng;
+use rand::rngs::StdRng;
+use rand::{thread_rng, Rng, SeedableRng};
 
 use crate::cell::CellState::{ALIVE, DEAD};
 use crate::cell::{Cell, ALIVE_CHAR, DEAD_CHAR};
+use crate::delta::GenerationDelta;
+use crate::history::SaveHistory;
+#[cfg(not(feature = "parallel"))]
+use crate::neighbors::NeighborGrid;
+use crate::neighbors::{custom_neighbors, is_sparse, neighbor_offsets, sparse_neighbor_counts};
+use crate::observer::Observers;
+use crate::patterns::{decode_rle, CollisionMode, PatternDef};
+use crate::rule::Rule;
 use crate::simulation::SurfaceType::*;
 use crate::simulation_window::SimulationWindowData;
+use crate::statistics::Statistics;
+
+/// The character that represents a cell that was born in string representations of the
+/// difference between two generations.
+pub const BORN_CHAR: char = '+';
+/// The character that represents a cell that died in string representations of the difference
+/// between two generations.
+pub const DIED_CHAR: char = '-';
+/// The character that represents a cell that did not change in string representations of the
+/// difference between two generations.
+pub const UNCHANGED_CHAR: char = '·';
+/// The character that represents an alive cell in the plaintext (`.cells`) format.
+pub const PLAINTEXT_ALIVE_CHAR: char = 'O';
+/// The character that represents a dead cell in the plaintext (`.cells`) format.
+pub const PLAINTEXT_DEAD_CHAR: char = '.';
+/// The character that represents an alive cell in `render_generation_as_block_chars` by default.
+pub const BLOCK_ALIVE_CHAR: char = '█';
+/// The character that represents a dead cell in `render_generation_as_block_chars` by default.
+pub const BLOCK_DEAD_CHAR: char = '·';
+
+/// A function that wraps a neighbor position onto the grid for a `Custom` surface type.
+///
+/// It is called with the row and column of a neighbor (which can be negative or past the last
+/// row or column) and the number of rows and columns in the grid, and returns the position of
+/// the cell on the grid, or `None` if the neighbor is outside of the grid.
+pub(crate) type SurfaceWrap = Arc<dyn Fn(i32, i32, u16, u16) -> Option<(u16, u16)> + Send + Sync>;
 
 /// Represents the surface type of a simulation (how wrapping will behave).
-#[derive(Clone, Debug)]
+///
+/// On a wrapping grid too small for the neighborhood (such as a 1-row `Ball`), a cell that
+/// can be reached in more than one way around the grid is only counted once as a neighbor, and
+/// a cell is never its own neighbor.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum SurfaceType {
     /// A spherical surface where cells wrap around on every edge.
     Ball,
@@ -54,14 +98,227 @@ pub(crate) enum SurfaceType {
     VerticalLoop,
     /// A rectangular surface with no wrapping.
     Rectangle,
+    /// A Möbius strip where cells wrap around horizontally (left/right) with a vertical flip, so
+    /// a cell leaving the right edge at row `r` comes back on the left edge at row
+    /// `rows - 1 - r`, and the top and bottom do not wrap.
+    Mobius,
+    /// A Klein bottle where cells wrap around horizontally (left/right) with a vertical flip,
+    /// the same as a `Mobius` surface, and also wrap around vertically (top/bottom) without
+    /// a flip.
+    KleinBottle,
+    /// A surface where every neighbor position is wrapped onto the grid by a user-supplied
+    /// function (see `SimulationBuilder::surface_custom`). It can not be serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(SurfaceWrap),
+}
+
+impl Debug for SurfaceType {
+    /// Writes the name of the surface type, without the function of a `Custom` surface.
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Ball => "Ball",
+            HorizontalLoop => "HorizontalLoop",
+            VerticalLoop => "VerticalLoop",
+            Rectangle => "Rectangle",
+            Mobius => "Mobius",
+            KleinBottle => "KleinBottle",
+            Custom(_) => "Custom",
+        })
+    }
+}
+
+impl SurfaceType {
+    /// Returns true if cells wrap around between the left and right edges.
+    ///
+    /// # Note
+    /// This is false for a `Custom` surface, since how it wraps is only known by calling its
+    /// function for each position. The same goes for `wraps_vertically`.
+    pub(crate) fn wraps_horizontally(&self) -> bool {
+        matches!(self, Ball | HorizontalLoop | Mobius | KleinBottle)
+    }
+
+    /// Returns true if cells wrap around between the top and bottom edges.
+    pub(crate) fn wraps_vertically(&self) -> bool {
+        matches!(self, Ball | VerticalLoop | KleinBottle)
+    }
+
+    /// Returns true if cells are flipped vertically when they wrap around between the left and
+    /// right edges.
+    pub(crate) fn flips_on_horizontal_wrap(&self) -> bool {
+        matches!(self, Mobius | KleinBottle)
+    }
+}
+
+/// Represents which cells count as the neighbors of a cell.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Neighborhood {
+    /// The eight surrounding cells, including diagonals (the standard Game of Life
+    /// neighborhood).
+    #[default]
+    Moore,
+    /// The four orthogonally adjacent cells (top, bottom, left, and right), ignoring diagonals.
+    VonNeumann,
+}
+
+/// The pairs of characters that seed strings can represent alive and dead cells with.
+///
+/// Seeds are read with whichever alphabet their first character belongs to (see
+/// `generation_from_string`), and written with `string_from_generation_with_alphabet`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SeedAlphabet {
+    /// `'*'` for alive cells and `'-'` for dead cells, the alphabet of `generation_string`.
+    #[default]
+    Symbols,
+    /// `'1'` for alive cells and `'0'` for dead cells.
+    Binary,
+    /// `'O'` for alive cells and `'.'` for dead cells, the same as the plaintext (`.cells`)
+    /// format.
+    Plaintext,
+}
+
+impl SeedAlphabet {
+    /// Every seed alphabet.
+    pub const ALL: [SeedAlphabet; 3] = [
+        SeedAlphabet::Symbols,
+        SeedAlphabet::Binary,
+        SeedAlphabet::Plaintext,
+    ];
+
+    /// Returns the character that represents an alive cell.
+    pub fn alive_char(self) -> char {
+        match self {
+            SeedAlphabet::Symbols => ALIVE_CHAR,
+            SeedAlphabet::Binary => '1',
+            SeedAlphabet::Plaintext => PLAINTEXT_ALIVE_CHAR,
+        }
+    }
+
+    /// Returns the character that represents a dead cell.
+    pub fn dead_char(self) -> char {
+        match self {
+            SeedAlphabet::Symbols => DEAD_CHAR,
+            SeedAlphabet::Binary => '0',
+            SeedAlphabet::Plaintext => PLAINTEXT_DEAD_CHAR,
+        }
+    }
+
+    /// Returns the alphabet the given character belongs to, if any.
+    pub(crate) fn of(character: char) -> Option<SeedAlphabet> {
+        SeedAlphabet::ALL.into_iter().find(|alphabet| {
+            character == alphabet.alive_char() || character == alphabet.dead_char()
+        })
+    }
+}
+
+/// Describes a cycle that a simulation has entered.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CycleInfo {
+    /// The number of generations between repeats.
+    pub period: u128,
+    /// The `(rows, columns)` the generation moved by over one period, which is `(0, 0)` for
+    /// anything other than spaceships.
+    pub displacement: (i32, i32),
+}
+
+/// Describes why a simulation stopped simulating (see `run_to_completion` and
+/// `simulate_until`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StopReason {
+    /// The simulation reached a finished (periodic) state.
+    Finished,
+    /// The maximum number of iterations was simulated without reaching another stop criterion.
+    MaximumIterations,
+    /// The simulation was stagnant according to the given `StagnationLimit`.
+    Stagnant,
+    /// The predicate given to `simulate_until` returned true.
+    PredicateSatisfied,
+}
+
+/// The parameters of the stagnation stop criterion for `run_to_completion` (see
+/// `Simulation::is_stagnant`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StagnationLimit {
+    /// The number of most recent generations to consider.
+    pub window: u128,
+    /// The allowed fractional deviation of the population over the window.
+    pub tolerance: f64,
+}
+
+/// Describes how a run of `simulate_until` or `simulate_continuous_generations` ended.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SimulationOutcome {
+    /// The number of generations simulated during the run.
+    pub iterations_run: u128,
+    /// The reason the run stopped.
+    pub stop_reason: StopReason,
+    /// The time the run took, including the cooldowns between generations.
+    pub elapsed: Duration,
+}
+
+/// The colors and sizes used to draw generations, both in the display window and in images
+/// (see `Simulation::render_to_pixel_buffer`).
+///
+/// The default configuration matches the `SimulationBuilder`'s defaults: 20x20 pixel yellow
+/// cells on a white background, with black grid lines 5 pixels thick.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RenderConfig {
+    /// The width of each cell in pixels.
+    pub cell_width: u16,
+    /// The height of each cell in pixels.
+    pub cell_height: u16,
+    /// The color of the cells, represented as an RGBA tuple.
+    pub cell_color: (u8, u8, u8, u8),
+    /// The background color, represented as an RGBA tuple.
+    pub background_color: (u8, u8, u8, u8),
+    /// The color of the grid lines, represented as an RGBA tuple.
+    pub line_color: (u8, u8, u8, u8),
+    /// The thickness of the grid lines in pixels.
+    pub line_thickness: u16,
+}
+
+impl Default for RenderConfig {
+    /// Defines the default configuration as the `SimulationBuilder`'s default colors and sizes.
+    fn default() -> Self {
+        RenderConfig {
+            cell_width: 20,
+            cell_height: 20,
+            cell_color: (255, 255, 0, 255),
+            background_color: (255, 255, 255, 255),
+            line_color: (0, 0, 0, 255),
+            line_thickness: 5,
+        }
+    }
 }
 
+/// A generation moved so its bounding box starts at `(0, 0)`, along with the original
+/// top-left corner of its bounding box.
+type NormalizedGeneration = ((u16, u16), HashSet<(u16, u16)>);
+
 /// Represents a simulation of the Game of Life.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Simulation {
     /// The initial seed string used to generate the simulation.
     pub(crate) seed: String,
+    /// The number that the random number generator for random seeds was seeded with, if the
+    /// simulation's random seeds are reproducible.
+    pub(crate) rng_seed: Option<u64>,
+    /// The random number generator that random seeds are drawn from, if the simulation's random
+    /// seeds are reproducible. This is not serialized, so random seeds of deserialized
+    /// simulations are drawn from `rand::thread_rng`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) rng: Option<StdRng>,
+    /// The chance that each cell of a random seed is alive, or `None` if a new chance is drawn
+    /// for every random seed.
+    pub(crate) random_alive_probability: Option<f64>,
     /// The surface type (affects wrapping) of the simulation.
     pub(crate) surface_type: SurfaceType,
+    /// The birth and survival rule used to compute each new generation.
+    pub(crate) rule: Rule,
+    /// The cells that count as the neighbors of each cell.
+    pub(crate) neighborhood: Neighborhood,
     /// The number of rows in the simulation grid.
     pub(crate) rows: u16,
     /// The number of columns in the simulation grid.
@@ -70,16 +327,42 @@ pub struct Simulation {
     pub(crate) generation: HashSet<Cell>,
     /// The current iteration or generation number of the simulation.
     pub(crate) iteration: u128,
+    /// The statistics collected since the simulation was built or last reset.
+    pub(crate) statistics: Statistics,
+    /// The cells born and the cells that died in the most recently simulated generation.
+    pub(crate) generation_delta: GenerationDelta,
     /// A history of previous generations, used for rolling back the simulation.
-    pub(crate) save_history: Vec<HashSet<Cell>>,
+    pub(crate) save_history: SaveHistory,
     /// The maximum number of generations to retain in the save history.
     pub(crate) maximum_saves: u128,
-    /// A flag indicating whether the simulation should be displayed in a window.
+    /// A flag indicating whether the simulation should be displayed in a window. This is not
+    /// serialized, since the window is not, so deserialized simulations are never displayed.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) display: bool,
     /// A flag indicating whether the simulation should be printed to the console.
     pub(crate) print: bool,
+    /// A flag indicating whether generations that only differ by a translation should be
+    /// considered repeats when detecting cycles.
+    pub(crate) detect_translated_cycles: bool,
+    /// A flag indicating whether births and deaths caused by bounded edges should be counted.
+    pub(crate) track_boundary_effects: bool,
+    /// The patterns selected with the number keys 1 to 9 when editing the simulation in its
+    /// display window. If empty, a default palette is used.
+    pub(crate) stamp_palette: Vec<PatternDef>,
+    /// How patterns stamped in the display window treat the cells already there.
+    pub(crate) stamp_collision_mode: CollisionMode,
+    /// The colors and sizes used to draw generations in the display window and in images.
+    pub(crate) render_config: RenderConfig,
+    /// The characters that alive and dead cells are written as when the simulation is printed
+    /// or formatted with `Display`.
+    pub(crate) render_chars: (char, char),
     /// Data related to the display window for the simulation, if applicable.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) window_data: Option<SimulationWindowData>,
+    /// The functions called after each generation is simulated (see `on_generation`). These
+    /// are not cloned or serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) observers: Observers,
 }
 
 impl Clone for Simulation {
@@ -87,16 +370,123 @@ impl Clone for Simulation {
     fn clone(&self) -> Self {
         Simulation {
             seed: self.seed.clone(),
+            rng_seed: self.rng_seed,
+            rng: self.rng.clone(),
+            random_alive_probability: self.random_alive_probability,
             surface_type: self.surface_type.clone(),
+            rule: self.rule.clone(),
+            neighborhood: self.neighborhood,
             rows: self.rows,
             columns: self.columns,
             generation: self.generation.clone(),
             iteration: self.iteration,
+            statistics: self.statistics.clone(),
+            generation_delta: self.generation_delta.clone(),
             save_history: self.save_history.clone(),
             maximum_saves: self.maximum_saves,
             display: self.display,
             print: self.print,
+            detect_translated_cycles: self.detect_translated_cycles,
+            track_boundary_effects: self.track_boundary_effects,
+            stamp_palette: self.stamp_palette.clone(),
+            stamp_collision_mode: self.stamp_collision_mode,
+            render_config: self.render_config,
+            render_chars: self.render_chars,
             window_data: self.window_data.clone(),
+            observers: Observers::default(),
+        }
+    }
+}
+
+/// Every part of a simulation except its display window, which is all that is moved to the
+/// thread spawned by `Simulation::simulate_generations_async_with_channel`.
+///
+/// Display windows can not be moved between threads, so a `Simulation` is never `Send`. Each
+/// field here is `Send`, so this struct is too.
+struct HeadlessSimulation {
+    /// The initial seed string used to generate the simulation.
+    seed: String,
+    /// The number that the random number generator for random seeds was seeded with, if the
+    /// simulation's random seeds are reproducible.
+    rng_seed: Option<u64>,
+    /// The random number generator that random seeds are drawn from, if the simulation's random
+    /// seeds are reproducible.
+    rng: Option<StdRng>,
+    /// The chance that each cell of a random seed is alive, or `None` if a new chance is drawn
+    /// for every random seed.
+    random_alive_probability: Option<f64>,
+    /// The surface type (affects wrapping) of the simulation.
+    surface_type: SurfaceType,
+    /// The birth and survival rule used to compute each new generation.
+    rule: Rule,
+    /// The cells that count as the neighbors of each cell.
+    neighborhood: Neighborhood,
+    /// The number of rows in the simulation grid.
+    rows: u16,
+    /// The number of columns in the simulation grid.
+    columns: u16,
+    /// The current generation of cells in the simulation.
+    generation: HashSet<Cell>,
+    /// The current iteration or generation number of the simulation.
+    iteration: u128,
+    /// The statistics collected since the simulation was built or last reset.
+    statistics: Statistics,
+    /// The cells born and the cells that died in the most recently simulated generation.
+    generation_delta: GenerationDelta,
+    /// A history of previous generations, used for rolling back the simulation.
+    save_history: SaveHistory,
+    /// The maximum number of generations to retain in the save history.
+    maximum_saves: u128,
+    /// A flag indicating whether the simulation should be printed to the console.
+    print: bool,
+    /// A flag indicating whether generations that only differ by a translation should be
+    /// considered repeats when detecting cycles.
+    detect_translated_cycles: bool,
+    /// A flag indicating whether births and deaths caused by bounded edges should be counted.
+    track_boundary_effects: bool,
+    /// The patterns selected with the number keys 1 to 9 when editing the simulation in its
+    /// display window. If empty, a default palette is used.
+    stamp_palette: Vec<PatternDef>,
+    /// How patterns stamped in the display window treat the cells already there.
+    stamp_collision_mode: CollisionMode,
+    /// The colors and sizes used to draw generations in the display window and in images.
+    render_config: RenderConfig,
+    /// The characters that alive and dead cells are written as when the simulation is printed
+    /// or formatted with `Display`.
+    render_chars: (char, char),
+    /// The functions called after each generation is simulated (see `on_generation`).
+    observers: Observers,
+}
+
+impl HeadlessSimulation {
+    /// Returns a simulation of the moved parts, without a display window.
+    fn into_simulation(self) -> Simulation {
+        Simulation {
+            seed: self.seed,
+            rng_seed: self.rng_seed,
+            rng: self.rng,
+            random_alive_probability: self.random_alive_probability,
+            surface_type: self.surface_type,
+            rule: self.rule,
+            neighborhood: self.neighborhood,
+            rows: self.rows,
+            columns: self.columns,
+            generation: self.generation,
+            iteration: self.iteration,
+            statistics: self.statistics,
+            generation_delta: self.generation_delta,
+            save_history: self.save_history,
+            maximum_saves: self.maximum_saves,
+            print: self.print,
+            detect_translated_cycles: self.detect_translated_cycles,
+            track_boundary_effects: self.track_boundary_effects,
+            stamp_palette: self.stamp_palette,
+            stamp_collision_mode: self.stamp_collision_mode,
+            render_config: self.render_config,
+            render_chars: self.render_chars,
+            display: false,
+            window_data: None,
+            observers: self.observers,
         }
     }
 }
@@ -114,648 +504,3625 @@ impl Display for Simulation {
     /// 1. If the current iteration is 0, it writes the string "SEED".
     /// 2. Otherwise, it writes the current iteration number.
     /// 3. For each row in the simulation grid, it iterates through the columns and writes the
-    /// corresponding character representation (either `'*'` for alive cells or `'-'` for
-    /// dead cells) obtained by calling the `as_char` method of the `Cell` struct.
+    ///    corresponding character representation (`'*'` for alive cells and `'-'` for dead
+    ///    cells by default, or the characters set with `SimulationBuilder::render_chars`).
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         if self.iteration == 0 {
             write!(f, "SEED\n")?;
         } else {
             write!(f, "{}\n", self.iteration)?;
         }
-        for row in 0..self.rows {
-            for column in 0..self.columns {
-                write!(f, "{}", self.get_cell(row, column).as_char())?;
-            }
-            write!(f, "\n")?;
-        }
-        Ok(())
+        write!(
+            f,
+            "{}",
+            self.render_generation_as_block_chars(Some(self.render_chars))
+        )
     }
 }
 
 impl Simulation {
     /// Returns the simulation's current generation iteration.
-    pub fn iteration(&mut self) -> u128 {
+    pub fn iteration(&self) -> u128 {
         self.iteration
     }
 
+    /// Returns the simulation's birth and survival rule.
+    pub fn rule(&self) -> Rule {
+        self.rule.clone()
+    }
+
+    /// Returns the cells that count as the neighbors of each cell in the simulation.
+    pub fn neighborhood(&self) -> Neighborhood {
+        self.neighborhood
+    }
+
+    /// Returns the colors and sizes used to draw the simulation in its display window and in
+    /// images.
+    pub fn render_config(&self) -> RenderConfig {
+        self.render_config
+    }
+
     /// Returns the simulation's seed.
-    pub fn seed(&mut self) -> String {
+    pub fn seed(&self) -> String {
         self.seed.clone()
     }
 
+    /// Returns the number that the simulation's random seeds are drawn with, if it was set with
+    /// `SimulationBuilder::rng_seed` or `reset_to_rand_seeded`.
+    pub fn rng_seed(&self) -> Option<u64> {
+        self.rng_seed
+    }
+
     /// Returns the simulation's width in columns.
-    pub fn width(&mut self) -> u16 {
+    pub fn width(&self) -> u16 {
         self.columns
     }
 
     /// Returns the simulation's height in rows.
-    pub fn height(&mut self) -> u16 {
+    pub fn height(&self) -> u16 {
         self.rows
     }
 
     /// Returns the simulation's current generation.
-    pub fn generation(&mut self) -> HashSet<Cell> {
+    pub fn generation(&self) -> HashSet<Cell> {
         self.generation.clone()
     }
 
     /// Returns the simulation's save history.
-    pub fn save_history(&mut self) -> Vec<HashSet<Cell>> {
-        self.save_history.clone()
+    pub fn save_history(&self) -> Vec<HashSet<Cell>> {
+        self.save_history.iter().collect()
     }
 
     /// Returns the simulation's current save history length.
-    pub fn save_history_size(&mut self) -> u128 {
+    pub fn save_history_size(&self) -> u128 {
         self.save_history.len() as u128
     }
 
     /// Returns the generation from the specified index of the simulation's save history.
-    pub fn get_save(&mut self, index: u128) -> HashSet<Cell> {
-        self.save_history[index as usize].clone()
+    pub fn get_save(&self, index: u128) -> HashSet<Cell> {
+        self.save_history.get(index as usize)
     }
 
-    /// Returns the cell at the given row and column.
+    /// Sets whether each generation is printed to the console as it is simulated.
+    pub fn set_print(&mut self, print: bool) {
+        self.print = print;
+    }
+
+    /// Sets the maximum number of generations to retain in the save history, dropping the
+    /// oldest saves if more than that are already saved.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(5)
+    ///     .width(5)
+    ///     .seed_named("blinker")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// simulation.simulate_generations(10);
+    /// simulation.set_maximum_saves(3);
+    /// assert_eq!(simulation.save_history_size(), 3);
+    ///
+    /// // The most recent saves are kept, so the simulation can still be rolled back
+    /// assert_eq!(simulation.rollback_generations(5), 3);
+    /// assert_eq!(simulation.iteration(), 7);
+    /// ```
+    pub fn set_maximum_saves(&mut self, maximum_saves: u128) {
+        self.maximum_saves = maximum_saves;
+        self.save_history
+            .truncate(maximum_saves.try_into().unwrap_or(usize::MAX));
+    }
+
+    /// Returns an estimate of the number of bytes of memory used by the simulation's save
+    /// history.
     ///
     /// # Description
-    /// This function retrieves the `Cell` instance representing the cell at the specified
-    /// row and column coordinates in the simulation grid.
+    /// Most saves only store the cells that were born and died since the save before them,
+    /// with the full generation stored every so often, so the estimate grows with how many
+    /// cells change each generation rather than with the size of the grid. It includes the
+    /// hashes used to find saves matching a generation, but not the allocator's overhead.
+    pub fn history_memory_estimate(&self) -> usize {
+        self.save_history.memory_estimate()
+    }
+
+    /// Creates a copy of the simulation without a display window, printing, or save history.
     ///
-    /// It first creates a new `Cell` instance with the `ALIVE` state and the provided
-    /// row and column indices.
+    /// # Note
+    /// This is used for internal simulations that should not open a second window, since you
+    /// can not have multiple windows at once.
+    pub(crate) fn headless_clone(&self) -> Simulation {
+        Simulation {
+            seed: self.seed.clone(),
+            rng_seed: self.rng_seed,
+            rng: self.rng.clone(),
+            random_alive_probability: self.random_alive_probability,
+            surface_type: self.surface_type.clone(),
+            rule: self.rule.clone(),
+            neighborhood: self.neighborhood,
+            rows: self.rows,
+            columns: self.columns,
+            generation: self.generation.clone(),
+            iteration: self.iteration,
+            statistics: self.statistics.clone(),
+            generation_delta: self.generation_delta.clone(),
+            save_history: SaveHistory::default(),
+            maximum_saves: self.maximum_saves,
+            display: false,
+            print: false,
+            detect_translated_cycles: self.detect_translated_cycles,
+            track_boundary_effects: self.track_boundary_effects,
+            stamp_palette: self.stamp_palette.clone(),
+            stamp_collision_mode: self.stamp_collision_mode,
+            render_config: self.render_config,
+            render_chars: self.render_chars,
+            window_data: None,
+            observers: Observers::default(),
+        }
+    }
+
+    /// Creates a new simulation from a rectangular region of the current generation.
     ///
-    /// Then, it checks if this `Cell` exists in the current generation (`self.generation`).
-    /// If the `Cell` is not found in the generation, its state is set to `DEAD`.
+    /// # Description
+    /// The new simulation has `r2 - r1` rows and `c2 - c1` columns, and its seed is the alive
+    /// cells in rows `r1..r2` and columns `c1..c2` of the current generation. It keeps the
+    /// simulation's surface type, rule, neighborhood, `maximum_saves`, and rendering settings,
+    /// and starts at iteration 0 with an empty save history. It is never displayed in a window
+    /// or printed, since you can not have multiple windows at once.
     ///
     /// # Arguments
-    /// * `row` - The row index of the cell to retrieve.
-    /// * `column` - The column index of the cell to retrieve.
+    /// * `r1` - The first row of the region.
+    /// * `c1` - The first column of the region.
+    /// * `r2` - The row after the last row of the region.
+    /// * `c2` - The column after the last column of the region.
     ///
     /// # Returns
-    /// A `Cell` instance representing the cell at the specified row and column coordinates
-    /// in the simulation grid, with its state set to `ALIVE` if it exists in the current
-    /// generation, or `DEAD` otherwise.
-    fn get_cell(&self, row: u16, column: u16) -> Cell {
-        let mut cell: Cell = Cell::new(ALIVE, row, column);
-        if !self.generation.contains(&cell) {
-            cell.state = DEAD;
+    /// * `Ok(Simulation)` - The simulation of the cropped region.
+    /// * `Err(String)` - An error message if the region is empty or exceeds the grid.
+    ///
+    /// # Note
+    /// The cropped simulation behaves like the region of the original on a `Rectangle`
+    /// surface, as long as nothing outside of the region reaches it. On wrapping surfaces, the
+    /// region wraps onto itself rather than onto the rest of the original grid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .surface_rectangle()
+    ///     .seed_named("glider")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // The glider is placed in the middle of the grid
+    /// let mut cropped: Simulation = simulation.crop(2, 2, 8, 8).unwrap();
+    /// assert_eq!(cropped.height(), 6);
+    /// assert_eq!(cropped.alive_cells_iter().count(), 5);
+    /// assert!(simulation.crop(0, 0, 11, 5).is_err());
+    /// ```
+    pub fn crop(&self, r1: u16, c1: u16, r2: u16, c2: u16) -> Result<Simulation, String> {
+        if r1 >= r2 || c1 >= c2 {
+            return Err(format!(
+                "The region from ({}, {}) to ({}, {}) is empty",
+                r1, c1, r2, c2
+            ));
         }
-        return cell;
+        if r2 > self.rows || c2 > self.columns {
+            return Err(format!(
+                "The region from ({}, {}) to ({}, {}) exceeds the {}x{} grid",
+                r1, c1, r2, c2, self.rows, self.columns
+            ));
+        }
+        let rows: u16 = r2 - r1;
+        let columns: u16 = c2 - c1;
+        let generation: HashSet<Cell> = self
+            .generation
+            .iter()
+            .filter(|cell| (r1..r2).contains(&cell.row) && (c1..c2).contains(&cell.column))
+            .map(|cell| Cell::new(ALIVE, cell.row - r1, cell.column - c1))
+            .collect();
+        Ok(self.with_seed_generation(rows, columns, generation))
     }
 
-    /// Counts the number of alive neighbor cells for the given cell.
+    /// Pastes the current generation of another simulation into this one at an offset.
     ///
     /// # Description
-    /// This function determines the number of alive neighbor cells surrounding the specified
-    /// `Cell` instance in the current generation of the simulation.
-    ///
-    /// It considers all eight neighboring cells (top, bottom, left, right, and four diagonals)
-    /// and counts how many of them are alive.
+    /// Every cell of the other simulation's grid is moved down by `offset_row` rows and right by
+    /// `offset_column` columns. Alive cells make the cell they land on alive, and dead cells make
+    /// it dead, so the whole grid of the other simulation replaces the cells it covers. This
+    /// composes with `crop` to copy a region from one simulation and paste it into another.
     ///
-    /// This function takes into account the surface type of the simulation to handle wrapping
-    /// behavior correctly.
+    /// Cells that land past an edge are handled according to the surface type. On a wrapping
+    /// edge they wrap around the grid (flipped on a `Mobius` or `KleinBottle` surface, the same
+    /// as cells that move past it while simulating), and on a bounded edge they are dropped.
+    /// On a `Custom` surface, they are wrapped with the surface's function. If the other grid
+    /// is large enough to wrap onto itself, the cells placed last overwrite the ones before.
     ///
-    /// To maintain the use of unsigned integers, this function is built to never
-    /// hold or calculate a negative number.
-    ///
-    /// If the simulation has a wrapping surface type (e.g., `Ball`, `HorizontalLoop`,
-    /// `VerticalLoop`), this function adjusts the neighbor cell coordinates accordingly
-    /// to wrap around the edges of the grid.
+    /// If the simulation is set to display in a window, the current generation is redrawn.
     ///
     /// # Arguments
-    /// * `cell` - The `Cell` instance for which to count the alive neighbors.
+    /// * `other` - The simulation whose current generation is pasted.
+    /// * `offset_row` - The row the other grid's top edge is placed at.
+    /// * `offset_column` - The column the other grid's left edge is placed at.
     ///
     /// # Returns
-    /// An `u8` value representing the number of alive neighbor cells surrounding the specified
-    /// `Cell` instance.
+    /// * `Ok(())` - If the other generation was pasted.
+    /// * `Err(String)` - An error message if the offset is outside of the grid.
     ///
     /// # Note
-    /// I don't remember how I came up with this function, but it works, and it haunts me.
-    fn get_alive_neighbors(&self, cell: Cell) -> u8 {
-        let origin_row: u16 = cell.row;
-        let origin_column: u16 = cell.column;
-        let mut wrapping_vertically: bool = false;
-        let mut wrapping_horizontally: bool = false;
-        let mut bounded_vertically: bool = false;
-        let mut bounded_horizontally: bool = false;
-        match self.surface_type.clone() {
-            Ball => {
-                wrapping_vertically = true;
-                wrapping_horizontally = true;
-            }
-            HorizontalLoop => {
-                wrapping_horizontally = true;
-                bounded_vertically = true;
-            }
-            VerticalLoop => {
-                wrapping_vertically = true;
-                bounded_horizontally = true;
-            }
-            Rectangle => {
-                bounded_vertically = true;
-                bounded_horizontally = true;
-            }
-        }
-
-        let on_top_edge: bool = origin_row == 0;
-        let on_bottom_edge: bool = origin_row == self.rows.clone() - 1;
-        let on_left_edge: bool = origin_column == 0;
-        let on_right_edge: bool = origin_column == self.columns.clone() - 1;
-
-        let top_left_is_alive: bool = {
-            let result: bool = (|| {
-                if on_top_edge && bounded_vertically {
-                    return false;
-                }
-                if on_left_edge && bounded_horizontally {
-                    return false;
-                }
-                let neighbor_row: u16;
-                let neighbor_column: u16;
-                if on_top_edge && wrapping_vertically {
-                    neighbor_row = self.rows.clone() - 1
-                } else {
-                    neighbor_row = origin_row.clone() - 1
-                }
-                if on_left_edge && wrapping_horizontally {
-                    neighbor_column = self.columns.clone() - 1
-                } else {
-                    neighbor_column = origin_column.clone() - 1
-                }
-                self.get_cell(neighbor_row, neighbor_column).is_alive()
-            })();
-            result
-        };
-        let top_center_is_alive: bool = {
-            let result: bool = (|| {
-                if on_top_edge && bounded_vertically {
-                    return false;
-                }
-                let neighbor_row: u16;
-                if on_top_edge && wrapping_vertically {
-                    neighbor_row = self.rows.clone() - 1
-                } else {
-                    neighbor_row = origin_row.clone() - 1
-                }
-                self.get_cell(neighbor_row, origin_column.clone())
-                    .is_alive()
-            })();
-            result
-        };
-        let top_right_is_alive: bool = {
-            let result: bool = (|| {
-                if on_top_edge && bounded_vertically {
-                    return false;
-                }
-                if on_right_edge && bounded_horizontally {
-                    return false;
-                }
-                let neighbor_row: u16;
-                let neighbor_column: u16;
-                if on_top_edge && wrapping_vertically {
-                    neighbor_row = self.rows.clone() - 1
-                } else {
-                    neighbor_row = origin_row.clone() - 1
-                }
-                if on_right_edge && wrapping_horizontally {
-                    neighbor_column = 0;
-                } else {
-                    neighbor_column = origin_column.clone() + 1
-                }
-                self.get_cell(neighbor_row, neighbor_column).is_alive()
-            })();
-            result
-        };
-        let middle_left_is_alive: bool = {
-            let result: bool = (|| {
-                if on_left_edge && bounded_horizontally {
-                    return false;
-                }
-                let neighbor_column: u16;
-                if on_left_edge && wrapping_horizontally {
-                    neighbor_column = self.columns.clone() - 1
-                } else {
-                    neighbor_column = origin_column.clone() - 1
-                }
-                self.get_cell(origin_row.clone(), neighbor_column)
-                    .is_alive()
-            })();
-            result
-        };
-        let middle_right_is_alive: bool = {
-            let result: bool = (|| {
-                if on_right_edge && bounded_horizontally {
-                    return false;
-                }
-                let neighbor_column: u16;
-                if on_right_edge && wrapping_horizontally {
-                    neighbor_column = 0;
-                } else {
-                    neighbor_column = origin_column.clone() + 1
-                }
-                self.get_cell(origin_row.clone(), neighbor_column)
-                    .is_alive()
-            })();
-            result
-        };
-        let bottom_left_is_alive: bool = {
-            let result: bool = (|| {
-                if on_left_edge && bounded_horizontally {
-                    return false;
-                }
-                if on_bottom_edge && bounded_vertically {
-                    return false;
-                }
-                let neighbor_row: u16;
-                let neighbor_column: u16;
-                if on_bottom_edge && wrapping_vertically {
-                    neighbor_row = 0;
-                } else {
-                    neighbor_row = origin_row.clone() + 1
-                }
-                if on_left_edge && wrapping_horizontally {
-                    neighbor_column = self.columns.clone() - 1
-                } else {
-                    neighbor_column = origin_column.clone() - 1
-                }
-                self.get_cell(neighbor_row, neighbor_column).is_alive()
-            })();
-            result
-        };
-        let bottom_center_is_alive: bool = {
-            let result: bool = (|| {
-                if on_bottom_edge && bounded_vertically {
-                    return false;
-                }
-                let neighbor_row: u16;
-                if on_bottom_edge && wrapping_vertically {
-                    neighbor_row = 0;
-                } else {
-                    neighbor_row = origin_row.clone() + 1
-                }
-                self.get_cell(neighbor_row, origin_column.clone())
-                    .is_alive()
-            })();
-            result
-        };
-        let bottom_right_is_alive: bool = {
-            let result: bool = (|| {
-                if on_bottom_edge && bounded_vertically {
-                    return false;
-                }
-                if on_right_edge && bounded_horizontally {
-                    return false;
-                }
-                let neighbor_row: u16;
-                let neighbor_column: u16;
-                if on_bottom_edge && wrapping_vertically {
-                    neighbor_row = 0;
-                } else {
-                    neighbor_row = origin_row.clone() + 1
-                }
-                if on_right_edge && wrapping_horizontally {
-                    neighbor_column = 0;
+    /// The iteration, save history, and statistics are not changed, the same as setting cells
+    /// with `set_cells`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let source: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .seed_named("glider")
+    ///     .build()
+    ///     .unwrap();
+    /// let mut target: Simulation = SimulationBuilder::new()
+    ///     .height(20)
+    ///     .width(20)
+    ///     .seed_named("block")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Copy the glider out of the source and paste it into the top left of the target
+    /// target
+    ///     .embed(&source.crop(3, 3, 6, 6).unwrap(), 0, 0)
+    ///     .unwrap();
+    /// assert_eq!(target.alive_cells_iter().count(), 4 + 5);
+    /// ```
+    pub fn embed(
+        &mut self,
+        other: &Simulation,
+        offset_row: u16,
+        offset_column: u16,
+    ) -> Result<(), String> {
+        self.check_bounds(offset_row, offset_column)?;
+        for row in 0..other.rows {
+            for column in 0..other.columns {
+                let position: Option<(u16, u16)> = self.wrap_position(
+                    offset_row as i32 + row as i32,
+                    offset_column as i32 + column as i32,
+                );
+                let cell: Cell = match position {
+                    Some((row_in_grid, column_in_grid)) => {
+                        Cell::new(ALIVE, row_in_grid, column_in_grid)
+                    }
+                    None => continue,
+                };
+                if other.generation.contains(&Cell::new(ALIVE, row, column)) {
+                    self.generation.insert(cell);
                 } else {
-                    neighbor_column = origin_column.clone() + 1
+                    self.generation.remove(&cell);
                 }
-                self.get_cell(neighbor_row, neighbor_column).is_alive()
-            })();
-            result
-        };
-
-        let mut count: u8 = 0;
-        if top_left_is_alive {
-            count += 1
-        }
-        if top_center_is_alive {
-            count += 1
-        }
-        if top_right_is_alive {
-            count += 1
-        }
-        if middle_left_is_alive {
-            count += 1
-        }
-        if middle_right_is_alive {
-            count += 1
-        }
-        if bottom_left_is_alive {
-            count += 1
-        }
-        if bottom_center_is_alive {
-            count += 1
+            }
         }
-        if bottom_right_is_alive {
-            count += 1
+        if self.display {
+            self.draw_generation();
         }
-        count
+        Ok(())
     }
 
-    /// Saves the current generation to the save history.
+    /// Creates a new simulation that repeats the current generation in a grid of tiles.
     ///
     /// # Description
-    /// This function adds a copy of the current generation to the simulation's save history.
-    /// The save history is a vector that stores previous generations, allowing the simulation
-    /// to be rolled back to a previous state if needed.
+    /// The new simulation has `rows * tile_rows` rows and `columns * tile_columns` columns. The
+    /// tile in row `i` and column `j` of the tiles is a copy of the current generation moved by
+    /// `(i * rows, j * columns)`, so each row of the seed is the matching row of the current
+    /// generation repeated `tile_columns` times. Like `crop`, the new simulation keeps the
+    /// simulation's surface type, rule, neighborhood, `maximum_saves`, and rendering settings,
+    /// and starts at iteration 0 without a window.
     ///
-    /// This function maintains a maximum number of saved generations specified by the
-    /// `maximum_saves` field.
+    /// Tiling a generation on a `Ball` surface gives a simulation that behaves like the
+    /// original repeated in every direction, which is useful for studying how patterns interact
+    /// in a larger periodic lattice.
     ///
-    /// When the save history reaches the maximum size, the oldest generation is removed before
-    /// adding the new generation to the end of the vector.
+    /// # Arguments
+    /// * `tile_rows` - The number of times the generation is repeated vertically.
+    /// * `tile_columns` - The number of times the generation is repeated horizontally.
     ///
-    /// Saving generations is essential for enabling features like rolling back the simulation
-    /// or detecting periodic or still states, where the current generation matches a previous
-    /// generation in the save history.
-    fn save_generation(&mut self) {
-        if self.save_history.len() == self.maximum_saves as usize {
-            self.save_history.remove(0);
+    /// # Returns
+    /// * `Ok(Simulation)` - The tiled simulation.
+    /// * `Err(String)` - An error message if either number of tiles is 0, or the tiled grid
+    ///   would have more than `u16::MAX` rows or columns.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .height(2)
+    ///     .width(3)
+    ///     .seed("*----*")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Each row is repeated twice, and then the rows are repeated twice
+    /// let tiled: Simulation = simulation.tile(2, 2).unwrap();
+    /// assert_eq!(
+    ///     tiled.generation_string(),
+    ///     ["*--*--", "--*--*", "*--*--", "--*--*"].concat()
+    /// );
+    /// ```
+    pub fn tile(&self, tile_rows: u16, tile_columns: u16) -> Result<Simulation, String> {
+        if tile_rows == 0 || tile_columns == 0 {
+            return Err(format!(
+                "A simulation can not be tiled {}x{} times, there must be at least one tile",
+                tile_rows, tile_columns
+            ));
+        }
+        let (rows, columns): (u16, u16) = match (
+            self.rows.checked_mul(tile_rows),
+            self.columns.checked_mul(tile_columns),
+        ) {
+            (Some(rows), Some(columns)) => (rows, columns),
+            _ => {
+                return Err(format!(
+                    "Tiling the {}x{} grid {}x{} times would be larger than the maximum of {} rows and columns",
+                    self.rows, self.columns, tile_rows, tile_columns, u16::MAX
+                ));
+            }
+        };
+        let mut generation: HashSet<Cell> = HashSet::new();
+        for tile_row in 0..tile_rows {
+            for tile_column in 0..tile_columns {
+                generation.extend(self.generation.iter().map(|cell| {
+                    Cell::new(
+                        ALIVE,
+                        tile_row * self.rows + cell.row,
+                        tile_column * self.columns + cell.column,
+                    )
+                }));
+            }
         }
-        self.save_history.push(self.generation.clone());
+        Ok(self.with_seed_generation(rows, columns, generation))
     }
 
-    /// Rolls back the simulation by the specified number of generations.
+    /// Creates a new simulation whose seed is the union of the alive cells of this simulation's
+    /// current generation and another's.
     ///
     /// # Description
-    /// This function allows you to undo a certain number of iterations in the simulation by
-    /// restoring the state of the simulation to a previous generation stored in the save history.
+    /// The new simulation keeps this simulation's surface type, rule, neighborhood,
+    /// `maximum_saves`, and rendering settings, and starts at iteration 0 with an empty save
+    /// history. It is never displayed in a window. Neither simulation is changed.
     ///
-    /// If the requested number of rollback iterations exceeds the available save history,
-    /// the simulation will be rolled back to the earliest saved generation.
+    /// # Arguments
+    /// * `other` - The simulation to combine this one with.
     ///
-    /// After rolling back the specified number of generations, if the simulation is set to
-    /// display in a window, the current generation is drawn on the display window.
+    /// # Returns
+    /// * `Ok(Simulation)` - The combined simulation.
+    /// * `Err(String)` - An error message if the simulations do not have the same number of rows
+    ///   and columns.
     ///
-    /// # Arguments
-    /// * `iterations` - The number of generations to roll back.
-    pub fn rollback_generations(&mut self, iterations: u128) {
-        if iterations == 0 {
-            return;
-        }
-        for _ in 0..iterations {
-            if let Some(previous_generation) = self.save_history.pop() {
-                self.generation = previous_generation;
-                self.iteration -= 1;
-            } else {
-                break;
-            }
-        }
-        if self.display {
-            self.draw_generation()
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let a: Simulation = SimulationBuilder::new().height(2).width(2).seed("**--").build().unwrap();
+    /// let b: Simulation = SimulationBuilder::new().height(2).width(2).seed("*-*-").build().unwrap();
+    ///
+    /// assert_eq!(a.or_with(&b).unwrap().generation_string(), "***-");
+    /// assert_eq!(a.and_with(&b).unwrap().generation_string(), "*---");
+    /// assert_eq!(a.xor_with(&b).unwrap().generation_string(), "-**-");
+    ///
+    /// let c: Simulation = SimulationBuilder::new().height(1).width(4).seed("****").build().unwrap();
+    /// assert!(a.or_with(&c).is_err());
+    /// ```
+    pub fn or_with(&self, other: &Simulation) -> Result<Simulation, String> {
+        self.combined_with(other, |a, b| a | b)
+    }
+
+    /// Creates a new simulation whose seed is the intersection of the alive cells of this
+    /// simulation's current generation and another's (see `or_with`).
+    pub fn and_with(&self, other: &Simulation) -> Result<Simulation, String> {
+        self.combined_with(other, |a, b| a & b)
+    }
+
+    /// Creates a new simulation whose seed is the symmetric difference of the alive cells of
+    /// this simulation's current generation and another's, so the cells alive in exactly one of
+    /// them (see `or_with`).
+    pub fn xor_with(&self, other: &Simulation) -> Result<Simulation, String> {
+        self.combined_with(other, |a, b| a ^ b)
+    }
+
+    /// Creates a new simulation whose seed is the given combination of this simulation's current
+    /// generation and another's (see `or_with`).
+    fn combined_with<F>(&self, other: &Simulation, combine: F) -> Result<Simulation, String>
+    where
+        F: Fn(&HashSet<Cell>, &HashSet<Cell>) -> HashSet<Cell>,
+    {
+        self.check_same_size(other)?;
+        let generation: HashSet<Cell> = combine(&self.generation, &other.generation);
+        Ok(self.with_seed_generation(self.rows, self.columns, generation))
+    }
+
+    /// Returns an error if another simulation does not have the same number of rows and columns.
+    fn check_same_size(&self, other: &Simulation) -> Result<(), String> {
+        if self.rows != other.rows || self.columns != other.columns {
+            return Err(format!(
+                "The simulations must be the same size, not {}x{} and {}x{}",
+                self.rows, self.columns, other.rows, other.columns
+            ));
         }
+        Ok(())
     }
 
-    /// Rolls back one generation.
-    pub fn rollback_generation(&mut self) {
-        self.rollback_generations(1)
+    /// Creates a headless copy of the simulation (see `headless_clone`) with the given size,
+    /// seeded with the given generation at iteration 0.
+    fn with_seed_generation(
+        &self,
+        rows: u16,
+        columns: u16,
+        generation: HashSet<Cell>,
+    ) -> Simulation {
+        let mut simulation: Simulation = self.headless_clone();
+        simulation.seed = string_from_generation(generation.clone(), rows, columns);
+        simulation.rows = rows;
+        simulation.columns = columns;
+        simulation.statistics = Statistics::new(rows as usize * columns as usize, &generation);
+        simulation.generation = generation;
+        simulation.iteration = 0;
+        simulation.generation_delta = GenerationDelta::default();
+        simulation
     }
 
-    /// Simulates the specified number of generations in the simulation.
+    /// Mirrors the current generation left to right, so the cell at `(row, column)` moves to
+    /// `(row, columns - 1 - column)`.
     ///
-    /// # Description
-    /// This function advances the simulation by the given number of iterations, updating the
-    /// current generation based on the rules of the Game of Life.
+    /// # Note
+    /// The flip is an edit of the current generation: the generation before it is saved to the
+    /// save history, so the flip is undone by rolling back one generation, but the iteration
+    /// counter, statistics, and `generation_delta` are not changed. The seed is not changed
+    /// either, and nothing is saved if the flip does not change any cell.
+    pub fn flip_horizontal(&mut self) {
+        let columns: u16 = self.columns;
+        self.flip_generation(|row, column| (row, columns - 1 - column));
+    }
+
+    /// Mirrors the current generation top to bottom, so the cell at `(row, column)` moves to
+    /// `(rows - 1 - row, column)`.
     ///
-    /// For each iteration, the following steps are performed:
+    /// # Note
+    /// The flip is applied the same way as with `flip_horizontal`.
+    pub fn flip_vertical(&mut self) {
+        let rows: u16 = self.rows;
+        self.flip_generation(|row, column| (rows - 1 - row, column));
+    }
+
+    /// Mirrors the current generation across its main diagonal, so the cell at `(row, column)`
+    /// moves to `(column, row)`.
     ///
-    /// 1. Save the current generation to the save history.
-    /// 2. Create a new `HashSet` to store the next generation.
-    /// 3. Iterate through each cell in the current generation.
+    /// # Returns
+    /// * `Ok(())` - If the generation was flipped.
+    /// * `Err(String)` - An error message if the grid is not square.
     ///
-    ///    a. Count the number of alive neighbors for the current cell.
+    /// # Note
+    /// The flip is applied the same way as with `flip_horizontal`.
     ///
-    ///    b. If the cell is alive and has fewer than 2 or more than 3 alive neighbors, mark it
-    /// as dead in the next generation.
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
     ///
-    ///    c. If the cell is dead and has exactly 3 alive neighbors, mark it as alive in the
-    /// next generation.
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(3)
+    ///     .width(3)
+    ///     .seed("**-------")
+    ///     .build()
+    ///     .unwrap();
     ///
-    /// 4. Update the current generation to the new generation.
+    /// simulation.flip_generation_diagonal().unwrap();
+    /// assert_eq!(simulation.generation_string(), "*--*-----");
+    /// simulation.flip_horizontal();
+    /// assert_eq!(simulation.generation_string(), "--*--*---");
     ///
-    /// 5. Increment the generation iteration counter.
+    /// // Each flip is undone by rolling back one generation
+    /// simulation.rollback_generations(2);
+    /// assert_eq!(simulation.generation_string(), "**-------");
+    /// ```
+    pub fn flip_generation_diagonal(&mut self) -> Result<(), String> {
+        if self.rows != self.columns {
+            return Err(format!(
+                "Only square grids can be flipped diagonally, the grid is {}x{}",
+                self.rows, self.columns
+            ));
+        }
+        self.flip_generation(|row, column| (column, row));
+        Ok(())
+    }
+
+    /// Rotates the current generation 90 degrees clockwise, so the cell at `(row, column)`
+    /// moves to `(column, rows - 1 - row)`.
     ///
-    /// After simulating the specified number of iterations, if the simulation is set to display
-    /// in a window, the current generation is drawn on the display window.
+    /// # Description
+    /// On a square grid, the rotation is applied the same way as with `flip_horizontal`: it is
+    /// an edit that is undone by rolling back one generation, and the iteration counter is not
+    /// changed.
     ///
-    /// If the simulation is set to print to the console, the current generation is printed to
-    /// the console.
+    /// On a grid that is not square, the number of rows and columns are swapped. The seed is
+    /// rotated as well, so `reset` returns to the rotated seed, and the save history is cleared,
+    /// since its generations no longer fit the grid. The iteration is not changed.
     ///
-    /// # Arguments
-    /// * `iterations` - The number of generations to simulate.
-    pub fn simulate_generations(&mut self, iterations: u128) {
-        if iterations == 0 {
-            return;
-        }
-        self.save_generation();
-        for _ in 0..iterations {
-            let mut new_generation: HashSet<Cell> = self.generation.clone();
-            let mut row: u16 = 0;
-            while row < self.rows {
-                let mut column: u16 = 0;
-                while column < self.columns {
-                    let mut cell: Cell = self.get_cell(row.clone(), column.clone());
-                    let alive_neighbors: u8 = self.get_alive_neighbors(cell.clone());
-                    let cell_alive: bool = cell.is_alive();
-                    if cell_alive {
-                        if alive_neighbors < 2 || alive_neighbors > 3 {
-                            new_generation.remove(&cell);
-                        }
-                    } else {
-                        if alive_neighbors == 3 {
-                            cell.state = ALIVE;
-                            new_generation.insert(cell);
-                        }
-                    }
-                    column = column + 1;
-                }
-                row = row + 1;
-            }
-            self.generation = new_generation;
-            self.iteration += 1;
-        }
-        if self.display {
-            self.draw_generation()
+    /// # Returns
+    /// * `Ok(())` - If the generation was rotated. If the grid is not square, the seed was
+    ///   rotated and the save history was cleared too, so the rotation (and every generation
+    ///   before it) can not be rolled back.
+    /// * `Err(String)` - An error message if the grid is not square and the simulation has a
+    ///   display window, which can not be resized. Nothing is changed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(2)
+    ///     .width(3)
+    ///     .seed("***---")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// simulation.rotate_90().unwrap();
+    /// assert_eq!((simulation.height(), simulation.width()), (3, 2));
+    /// assert_eq!(simulation.generation_string(), "-*-*-*");
+    ///
+    /// simulation.rotate_270().unwrap();
+    /// assert_eq!(simulation.generation_string(), "***---");
+    /// ```
+    pub fn rotate_90(&mut self) -> Result<(), String> {
+        self.rotate_quarter(1)
+    }
+
+    /// Rotates the current generation 180 degrees, so the cell at `(row, column)` moves to
+    /// `(rows - 1 - row, columns - 1 - column)`.
+    ///
+    /// # Note
+    /// This is two rotations of `rotate_90`, applied as one. The grid keeps its size, so the
+    /// rotation is always undone by rolling back one generation.
+    pub fn rotate_180(&mut self) {
+        let (rows, columns): (u16, u16) = (self.rows, self.columns);
+        self.flip_generation(|row, column| rotate_position((row, column), (rows, columns), 2));
+    }
+
+    /// Rotates the current generation 90 degrees counterclockwise, so the cell at
+    /// `(row, column)` moves to `(columns - 1 - column, row)`.
+    ///
+    /// # Returns
+    /// The same as `rotate_90`, including clearing the save history of a grid that is not
+    /// square.
+    ///
+    /// # Note
+    /// This is three rotations of `rotate_90`, applied as one (see `rotate_90` for how grids
+    /// that are not square are rotated).
+    pub fn rotate_270(&mut self) -> Result<(), String> {
+        self.rotate_quarter(3)
+    }
+
+    /// Rotates the current generation clockwise by an odd number of quarter turns, swapping the
+    /// number of rows and columns (see `rotate_90`).
+    fn rotate_quarter(&mut self, quarter_turns: u8) -> Result<(), String> {
+        let (rows, columns): (u16, u16) = (self.rows, self.columns);
+        if rows == columns {
+            self.flip_generation(|row, column| {
+                rotate_position((row, column), (rows, columns), quarter_turns)
+            });
+            return Ok(());
         }
-        if self.print {
-            println!("{}", self)
+        if self.window_data.is_some() {
+            return Err(format!(
+                "The {}x{} grid can not be rotated to {}x{} while it is displayed, since the window can not be resized",
+                rows, columns, columns, rows
+            ));
         }
+        let rotate = |generation: &HashSet<Cell>| -> HashSet<Cell> {
+            generation
+                .iter()
+                .map(|cell| {
+                    let (row, column): (u16, u16) =
+                        rotate_position((cell.row, cell.column), (rows, columns), quarter_turns);
+                    Cell::new(ALIVE, row, column)
+                })
+                .collect()
+        };
+        let seed_generation: HashSet<Cell> =
+            rotate(&generation_from_string(self.seed.clone(), rows, columns)?);
+        self.generation = rotate(&self.generation);
+        self.rows = columns;
+        self.columns = rows;
+        self.seed = string_from_generation(seed_generation, self.rows, self.columns);
+        self.save_history = SaveHistory::default();
+        self.generation_delta = GenerationDelta::default();
+        Ok(())
     }
 
-    /// Simulates one generation.
-    pub fn simulate_generation(&mut self) {
-        self.simulate_generations(1)
+    /// Shifts every alive cell of the current generation by the given number of rows and
+    /// columns.
+    ///
+    /// # Description
+    /// Positive offsets shift cells down and right, and negative offsets shift them up and left.
+    /// Cells shifted past a wrapping edge wrap around the grid (flipped on a `Mobius` or
+    /// `KleinBottle` surface, the same as cells that move past it while simulating), and cells
+    /// shifted past a bounded edge are discarded. On a `Custom` surface, the shifted positions
+    /// are wrapped with the surface's function.
+    ///
+    /// The shift is applied the same way as with `flip_horizontal`: it is an edit that is undone
+    /// by rolling back one generation, and the iteration counter and statistics are not
+    /// changed. If the simulation is set to display in a window, the shifted generation is
+    /// drawn.
+    ///
+    /// # Arguments
+    /// * `delta_row` - The number of rows to shift the cells down by.
+    /// * `delta_column` - The number of columns to shift the cells right by.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(3)
+    ///     .width(3)
+    ///     .surface_horizontal_loop()
+    ///     .seed("-**------")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // The cell shifted past the right edge wraps around to the left edge
+    /// simulation.translate(1, 1);
+    /// assert_eq!(simulation.generation_string(), "---*-*---");
+    ///
+    /// // The cells shifted past the bottom edge are discarded
+    /// simulation.translate(2, 0);
+    /// assert_eq!(simulation.alive_count(), 0);
+    /// ```
+    pub fn translate(&mut self, delta_row: i32, delta_column: i32) {
+        self.apply_edited_generation(self.translated_generation(delta_row, delta_column));
     }
 
-    /// Simulates generations continuously with a specified cooldown period.
-    pub fn simulate_continuous_generations(
-        &mut self,
-        cooldown: Duration,
-        stop_when_finished: bool,
-    ) {
-        loop {
-            self.simulate_generation();
-            if stop_when_finished && self.is_finished() {
-                break;
-            }
-            sleep(cooldown)
+    /// Shifts every alive cell of the current generation by the given number of rows and
+    /// columns, without counting it as a generation.
+    ///
+    /// # Description
+    /// Cells are moved the same way as with `translate`: they wrap around wrapping edges
+    /// (flipped on a `Mobius` or `KleinBottle` surface) and are discarded past bounded edges.
+    /// Unlike `translate`, the current generation is edited directly, the same as with
+    /// `set_cells`, so the shift is not saved to the save history and can not be rolled back.
+    /// This is useful for recentering a drifting pattern, or aligning two patterns before
+    /// comparing them. If the simulation is set to display in a window, the shifted generation
+    /// is drawn.
+    ///
+    /// # Arguments
+    /// * `row_delta` - The number of rows to shift the cells down by.
+    /// * `column_delta` - The number of columns to shift the cells right by.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(3)
+    ///     .width(3)
+    ///     .surface_vertical_loop()
+    ///     .seed("*-------*")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // The cell shifted past the bottom edge wraps to the top, and the cell shifted past the
+    /// // left edge is discarded
+    /// simulation.shift(1, -1);
+    /// assert_eq!(simulation.generation_string(), "-*-------");
+    /// assert_eq!(simulation.iteration(), 0);
+    /// ```
+    pub fn shift(&mut self, row_delta: i32, column_delta: i32) {
+        self.generation = self.translated_generation(row_delta, column_delta);
+        if self.display {
+            self.draw_generation();
         }
     }
 
-    /// Returns the count of alive cells in the current generation.
-    pub fn alive_count(&self) -> u64 {
-        self.generation.len() as u64
+    /// Returns the current generation with every alive cell shifted by the given number of rows
+    /// and columns (see `translate`).
+    fn translated_generation(&self, delta_row: i32, delta_column: i32) -> HashSet<Cell> {
+        // Reduce the shift around wrapping edges (an even number of times around a flipping
+        // edge), and limit it past bounded edges, so adding it to a position can not overflow
+        let reduce = |delta: i32, length: i32, wraps: bool| match (&self.surface_type, wraps) {
+            (Custom(_), _) => delta,
+            (_, true) => delta % length,
+            (_, false) => delta.clamp(-length, length),
+        };
+        let delta_row: i32 = reduce(
+            delta_row,
+            self.rows as i32,
+            self.surface_type.wraps_vertically(),
+        );
+        let column_period: i32 = match self.surface_type.flips_on_horizontal_wrap() {
+            true => 2 * self.columns as i32,
+            false => self.columns as i32,
+        };
+        let delta_column: i32 = reduce(
+            delta_column,
+            column_period,
+            self.surface_type.wraps_horizontally(),
+        );
+        self.generation
+            .iter()
+            .filter_map(|cell| {
+                self.wrap_position(
+                    (cell.row as i32).saturating_add(delta_row),
+                    (cell.column as i32).saturating_add(delta_column),
+                )
+            })
+            .map(|(row, column)| Cell::new(ALIVE, row, column))
+            .collect()
     }
 
-    /// Returns the proportion of alive cells in the current generation.
-    pub fn alive_proportion(&self) -> f64 {
-        self.alive_count() as f64 / self.area() as f64
+    /// Moves every alive cell of the current generation to the position given by `flip` (see
+    /// `apply_edited_generation`).
+    fn flip_generation<F: Fn(u16, u16) -> (u16, u16)>(&mut self, flip: F) {
+        let flipped_generation: HashSet<Cell> = self
+            .generation
+            .iter()
+            .map(|cell| {
+                let (row, column): (u16, u16) = flip(cell.row, cell.column);
+                Cell::new(ALIVE, row, column)
+            })
+            .collect();
+        self.apply_edited_generation(flipped_generation);
     }
 
-    /// Returns the total area (number of cells) in the simulation.
-    pub fn area(&self) -> u16 {
-        self.rows * self.columns
+    /// Makes every dead cell of the current generation alive and every alive cell dead.
+    ///
+    /// # Note
+    /// The inversion is applied the same way as with `flip_horizontal`: it is an edit that is
+    /// undone by rolling back one generation, and the iteration counter and statistics are not
+    /// changed. The seed is not changed.
+    pub fn invert_generation(&mut self) {
+        self.apply_edited_generation(self.inverted_generation(
+            0,
+            0,
+            self.rows - 1,
+            self.columns - 1,
+        ));
     }
 
-    /// Resets the simulation to the initial seed.
+    /// Makes every dead cell in the given region of the current generation alive and every alive
+    /// cell dead, leaving the cells outside of the region unchanged.
+    ///
+    /// # Arguments
+    /// * `r1` - The first row of the region.
+    /// * `c1` - The first column of the region.
+    /// * `r2` - The last row of the region (inclusive).
+    /// * `c2` - The last column of the region (inclusive).
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the region was inverted.
+    /// * `Err(String)` - An error message if the region is empty or exceeds the grid.
+    ///
     /// # Note
-    /// Resetting is preferred over creating a new simulation since it will continue in the same
-    /// window. You can not have multiple windows at once.
-    pub fn reset(&mut self) {
-        let seed: String = self.seed.clone();
-        self.generation = generation_from_string(String::from(seed), self.columns).unwrap();
-        self.iteration = 0;
+    /// The inversion is applied the same way as with `invert_generation`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(3)
+    ///     .width(3)
+    ///     .seed("*-------*")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// simulation.invert_region(0, 0, 1, 1).unwrap();
+    /// assert_eq!(simulation.generation_string(), "-*-**---*");
+    /// simulation.invert_generation();
+    /// assert_eq!(simulation.generation_string(), "*-*--***-");
+    /// assert!(simulation.invert_region(1, 1, 3, 2).is_err());
+    ///
+    /// // Each inversion is undone by rolling back one generation
+    /// simulation.rollback_generations(2);
+    /// assert_eq!(simulation.generation_string(), "*-------*");
+    /// ```
+    pub fn invert_region(&mut self, r1: u16, c1: u16, r2: u16, c2: u16) -> Result<(), String> {
+        self.check_region(r1, c1, r2, c2)?;
+        self.apply_edited_generation(self.inverted_generation(r1, c1, r2, c2));
+        Ok(())
     }
 
-    /// Resets the simulation to the specified seed.
+    /// Makes every cell in the given region of the current generation dead, leaving the cells
+    /// outside of the region unchanged.
+    ///
+    /// # Arguments
+    /// * `r1` - The first row of the region.
+    /// * `c1` - The first column of the region.
+    /// * `r2` - The last row of the region (inclusive).
+    /// * `c2` - The last column of the region (inclusive).
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the region was cleared.
+    /// * `Err(String)` - An error message if the region is empty or exceeds the grid.
+    ///
     /// # Note
-    /// Resetting is preferred over creating a new simulation since it will continue in the same
-    /// window. You can not have multiple windows at once.
-    pub fn reset_to(&mut self, seed: &str) {
-        self.generation = generation_from_string(String::from(seed), self.columns).unwrap();
-        self.seed = String::from(seed);
-        self.iteration = 0;
+    /// The change is applied the same way as with `invert_generation`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(3)
+    ///     .width(3)
+    ///     .seed("*-*-*-*-*")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// simulation.clear_region(0, 1, 2, 2).unwrap();
+    /// assert_eq!(simulation.generation_string(), "*-----*--");
+    /// simulation.fill_region(1, 1, 1, 2).unwrap();
+    /// assert_eq!(simulation.generation_string(), "*---***--");
+    /// assert!(simulation.fill_region(2, 0, 1, 0).is_err());
+    /// ```
+    pub fn clear_region(&mut self, r1: u16, c1: u16, r2: u16, c2: u16) -> Result<(), String> {
+        self.set_region(r1, c1, r2, c2, false)
     }
 
-    /// Resets the simulation to a random seed.
+    /// Makes every cell in the given region of the current generation alive, leaving the cells
+    /// outside of the region unchanged.
+    ///
+    /// # Arguments
+    /// * `r1` - The first row of the region.
+    /// * `c1` - The first column of the region.
+    /// * `r2` - The last row of the region (inclusive).
+    /// * `c2` - The last column of the region (inclusive).
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the region was filled.
+    /// * `Err(String)` - An error message if the region is empty or exceeds the grid.
     ///
     /// # Note
-    /// Resetting is preferred over creating a new simulation since it will continue in the same
-    /// window. You can not have multiple windows at once.
-    pub fn reset_to_rand(&mut self) {
-        let seed: String = random_seed(self.rows, self.columns);
-        self.generation = generation_from_string(String::from(seed.clone()), self.columns).unwrap();
-        self.seed = seed;
-        self.iteration = 0;
+    /// The change is applied the same way as with `invert_generation`.
+    pub fn fill_region(&mut self, r1: u16, c1: u16, r2: u16, c2: u16) -> Result<(), String> {
+        self.set_region(r1, c1, r2, c2, true)
     }
 
-    /// Returns true if the simulation is in a still state (a period of 1).
-    pub fn is_still(&self) -> bool {
-        self.is_periodic(1)
+    /// Makes every cell in the given region (inclusive) alive or dead (see `clear_region` and
+    /// `fill_region`).
+    fn set_region(
+        &mut self,
+        r1: u16,
+        c1: u16,
+        r2: u16,
+        c2: u16,
+        alive: bool,
+    ) -> Result<(), String> {
+        self.check_region(r1, c1, r2, c2)?;
+        let mut new_generation: HashSet<Cell> = self.generation.clone();
+        for row in r1..=r2 {
+            for column in c1..=c2 {
+                let cell: Cell = Cell::new(ALIVE, row, column);
+                if alive {
+                    new_generation.insert(cell);
+                } else {
+                    new_generation.remove(&cell);
+                }
+            }
+        }
+        self.apply_edited_generation(new_generation);
+        Ok(())
     }
 
-    /// Returns true if the simulation is in a periodic state with the specified period.
-    pub fn is_periodic(&self, period: usize) -> bool {
-        self.save_history.len() >= period
-            && self.generation == self.save_history[self.save_history.len() - (period)]
+    /// Returns the current generation with every cell in the given region (inclusive) flipped
+    /// between alive and dead.
+    fn inverted_generation(&self, r1: u16, c1: u16, r2: u16, c2: u16) -> HashSet<Cell> {
+        let mut inverted_generation: HashSet<Cell> = self.generation.clone();
+        for row in r1..=r2 {
+            for column in c1..=c2 {
+                let cell: Cell = Cell::new(ALIVE, row, column);
+                if !inverted_generation.remove(&cell) {
+                    inverted_generation.insert(cell);
+                }
+            }
+        }
+        inverted_generation
     }
 
-    /// Returns true if the simulation has reached a finished state (has any periodic state).
-    pub fn is_finished(&self) -> bool {
-        self.save_history.contains(&self.generation)
+    /// Replaces the current generation with one made by editing its cells, saving the current
+    /// generation first so the edit can be undone with `rollback_generations`.
+    ///
+    /// # Note
+    /// Unlike a simulated generation, the edit does not change the iteration counter,
+    /// statistics, or `generation_delta`, and observers are not notified. Since the edited
+    /// generation does not follow from the saved one by the simulation's rule, saves up to the
+    /// edit are not used to detect cycles. Nothing is saved if no cell changed.
+    fn apply_edited_generation(&mut self, edited_generation: HashSet<Cell>) {
+        if edited_generation == self.generation {
+            return;
+        }
+        let maximum_saves: usize = self.maximum_saves.try_into().unwrap_or(usize::MAX);
+        self.save_history
+            .push_edit(self.generation.clone(), maximum_saves);
+        self.generation = edited_generation;
+        if self.display {
+            self.draw_generation()
+        }
     }
 
-    /// Returns the string representation of the current generation.
-    pub fn generation_string(&self) -> String {
-        string_from_generation(self.generation.clone(), self.rows, self.columns)
+    /// Moves a position that may be past an edge of the grid onto the grid according to the
+    /// surface type, or returns `None` if it is past a bounded edge.
+    fn wrap_position(&self, row: i32, column: i32) -> Option<(u16, u16)> {
+        let rows: i32 = self.rows as i32;
+        let columns: i32 = self.columns as i32;
+        if let Custom(wrap) = &self.surface_type {
+            return wrap(row, column, self.rows, self.columns)
+                .filter(|&(row, column)| row < self.rows && column < self.columns);
+        }
+        if (!(0..rows).contains(&row) && !self.surface_type.wraps_vertically())
+            || (!(0..columns).contains(&column) && !self.surface_type.wraps_horizontally())
+        {
+            return None;
+        }
+        let wrapped_row: u16 = row.rem_euclid(rows) as u16;
+        let wrapped_column: u16 = column.rem_euclid(columns) as u16;
+        let laps: i32 = column.div_euclid(columns);
+        if self.surface_type.flips_on_horizontal_wrap() && laps % 2 != 0 {
+            Some((self.rows - 1 - wrapped_row, wrapped_column))
+        } else {
+            Some((wrapped_row, wrapped_column))
+        }
     }
-}
 
-/// Converts a string seed into a `HashSet` of `Cell` instances.
-///
-/// # Description
-/// This function takes a string seed representation of a generation and converts it into a
-/// `HashSet` of `Cell` instances. The string seed should consist of the characters `'*'`
-/// (alive) and `'-'` (dead), representing the state of each cell in the generation.
-///
-/// This function iterates through each character in the seed string and creates a `Cell`
-/// instance for each alive cell (`'*'`), with the appropriate row and column indices based on
-/// the position of the character in the string and the provided number of columns.
-///
-/// If the seed string contains any characters other than `'*'` or `'-'`, an error is returned.
-///
-/// The resulting `HashSet` of `Cell` instances represents the generation specified by the seed
-/// string.
-///
-/// # Arguments
-/// * `seed` - A string representation of the generation, where `'*'` represents an alive cell
-/// and `'-'` represents a dead cell.
-/// * `columns` - The number of columns in the generation grid, used to determine the row and
-/// column indices of each cell from its position in the seed string.
-///
-/// # Returns
-/// * `Ok(HashSet<Cell>)` - A `HashSet` containing `Cell` instances representing the alive cells
-/// in the generation specified by the seed string.
-/// * `Err(String)` - An error message if the seed string contains invalid characters.
-pub fn generation_from_string(seed: String, columns: u16) -> Result<HashSet<Cell>, String> {
-    let mut generation: HashSet<Cell> = HashSet::new();
-    let values: Vec<char> = seed.chars().collect();
-    for i in 0..values.len() {
-        let index: u16 = i as u16;
-        let row_index: u16 = index.clone() / columns.clone();
-        let column_index: u16 = index % columns.clone();
-        let value: char = values.get(i).unwrap().clone();
-        match value {
-            ALIVE_CHAR => {
-                generation.insert(Cell::new(ALIVE, row_index, column_index));
-            }
-            DEAD_CHAR => {}
-            _ => {
-                return Err(format!(
-                    "Unexpected seed character of \'{}\', seeds must only contain \'{}\' or \'{}\'",
-                    value, DEAD_CHAR, ALIVE_CHAR
-                ));
-            }
-        };
+    /// Returns the cell at the given row and column.
+    ///
+    /// # Description
+    /// This function retrieves the `Cell` instance representing the cell at the specified
+    /// row and column coordinates in the simulation grid.
+    ///
+    /// It first creates a new `Cell` instance with the `ALIVE` state and the provided
+    /// row and column indices.
+    ///
+    /// Then, it checks if this `Cell` exists in the current generation (`self.generation`).
+    /// If the `Cell` is not found in the generation, its state is set to `DEAD`.
+    ///
+    /// # Arguments
+    /// * `row` - The row index of the cell to retrieve.
+    /// * `column` - The column index of the cell to retrieve.
+    ///
+    /// # Returns
+    /// A `Cell` instance representing the cell at the specified row and column coordinates
+    /// in the simulation grid, with its state set to `ALIVE` if it exists in the current
+    /// generation, or `DEAD` otherwise.
+    fn get_cell(&self, row: u16, column: u16) -> Cell {
+        let mut cell: Cell = Cell::new(ALIVE, row, column);
+        if !self.generation.contains(&cell) {
+            cell.state = DEAD;
+        }
+        return cell;
     }
-    Ok(generation)
-}
 
-/// Converts a `HashSet` of `Cell` instances into a `String` representation.
-///
-/// # Description
-/// This function takes a `HashSet` of `Cell` instances representing a generation and converts
-/// it into a string representation. The resulting string consists of the characters `'*'`
-/// (alive) and `'-'` (dead), representing the state of each cell in the generation.
-///
-/// This function iterates through each row and column of the generation grid and appends the
-/// corresponding character (`'*'` or `'-'`) to the output string based on whether a `Cell`
-/// instance exists in the provided `HashSet` for that row and column.
-///
-/// The resulting string is a compact representation of the generation, and can be used for
-/// storage or display purposes.
-///
-/// # Arguments
-/// * `generation` - A `HashSet` of `Cell` instances representing the alive cells in the
-/// generation.
-/// * `rows` - The number of rows in the generation grid.
-/// * `columns` - The number of columns in the generation grid.
-///
-/// # Returns
-/// A `String` representation of the generation, where `'*'` represents an alive cell and `'-'`
-/// represents a dead cell.
-pub fn string_from_generation(generation: HashSet<Cell>, rows: u16, columns: u16) -> String {
-    let mut generation_characters: Vec<char> =
-        repeat(DEAD_CHAR).take((rows * columns) as usize).collect();
-    for cell in generation {
-        generation_characters[(cell.row * columns + cell.column) as usize] = ALIVE_CHAR;
+    /// Saves the current generation to the save history.
+    ///
+    /// # Description
+    /// This function adds a copy of the current generation to the simulation's save history.
+    /// The save history is a vector that stores previous generations, allowing the simulation
+    /// to be rolled back to a previous state if needed.
+    ///
+    /// This function maintains a maximum number of saved generations specified by the
+    /// `maximum_saves` field.
+    ///
+    /// When the save history reaches the maximum size, the oldest generation is removed before
+    /// adding the new generation to the end of the history. If `maximum_saves` is 0, nothing
+    /// is saved.
+    ///
+    /// Saving generations is essential for enabling features like rolling back the simulation
+    /// or detecting periodic or still states, where the current generation matches a previous
+    /// generation in the save history.
+    pub(crate) fn save_generation(&mut self) {
+        let maximum_saves: usize = self.maximum_saves.try_into().unwrap_or(usize::MAX);
+        self.save_history
+            .push(self.generation.clone(), maximum_saves);
     }
-    generation_characters.iter().collect()
-}
 
-/// Generates a random seed `String` for the specified number of rows and columns with a random alive probability.
-///
-/// # Description
-/// This function creates a random seed string representing a generation with the given number
-/// of rows and columns and a randomly determined probability for a cell to be alive.
-///
+    /// Rolls back the simulation by the specified number of generations.
+    ///
+    /// # Description
+    /// This function allows you to undo a certain number of iterations in the simulation by
+    /// restoring the state of the simulation to a previous generation stored in the save history.
+    ///
+    /// Every simulated generation is saved (up to `maximum_saves`), so rolling back `n`
+    /// generations restores the generation from exactly `n` iterations ago. If the requested
+    /// number of rollback iterations exceeds the available save history, the simulation will
+    /// be rolled back to the earliest saved generation. The seed is the earliest generation
+    /// that can be rolled back to, and nothing is rolled back if `maximum_saves` is 0.
+    ///
+    /// Edits of the current generation (such as `flip_horizontal` or `translate`) are saved as
+    /// well, and each one counts as one generation to roll back. Rolling back an edit restores
+    /// the generation before it without changing the iteration counter or statistics.
+    ///
+    /// After rolling back the specified number of generations, if the simulation is set to
+    /// display in a window, the current generation is drawn on the display window.
+    ///
+    /// # Arguments
+    /// * `iterations` - The number of generations to roll back.
+    ///
+    /// # Returns
+    /// The number of generations (and edits) actually rolled back, which is less than
+    /// `iterations` if the save history ran out.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(6)
+    ///     .width(6)
+    ///     .seed_named("glider")
+    ///     .build()
+    ///     .unwrap();
+    /// let seed: String = simulation.generation_string();
+    ///
+    /// // Rolling back past the seed stops at the seed
+    /// simulation.simulate_generations(3);
+    /// assert_eq!(simulation.rollback_generations(10), 3);
+    /// assert_eq!(simulation.iteration(), 0);
+    /// assert_eq!(simulation.generation_string(), seed);
+    /// assert_eq!(simulation.rollback_generations(1), 0);
+    /// ```
+    pub fn rollback_generations(&mut self, iterations: u128) -> u128 {
+        if iterations == 0 {
+            return 0;
+        }
+        let mut rolled_back: usize = 0;
+        let mut generations_rolled_back: usize = 0;
+        while (rolled_back as u128) < iterations {
+            let edit: bool = self.save_history.last_is_edit();
+            if !edit && self.iteration == 0 {
+                break;
+            }
+            match self.save_history.pop() {
+                Some(previous_generation) => {
+                    self.generation = previous_generation;
+                    if !edit {
+                        self.iteration -= 1;
+                        generations_rolled_back += 1;
+                    }
+                    rolled_back += 1;
+                }
+                None => break,
+            }
+        }
+        self.statistics.rollback(generations_rolled_back);
+        self.generation_delta = GenerationDelta::default();
+        if self.display {
+            self.draw_generation()
+        }
+        rolled_back as u128
+    }
+
+    /// Rolls back one generation, returning true if there was a saved generation to roll back
+    /// to.
+    pub fn rollback_generation(&mut self) -> bool {
+        self.rollback_generations(1) == 1
+    }
+
+    /// Computes the next generation of the simulation without modifying the simulation.
+    ///
+    /// # Description
+    /// This function applies the simulation's `Rule` to every cell in the grid and returns the
+    /// resulting generation. It is the single place where the rules of the Game of Life are
+    /// evaluated, so every way of advancing a simulation produces the same result.
+    ///
+    /// For each cell in the grid, the following steps are performed:
+    ///
+    /// 1. Count the number of alive neighbors for the cell within the rule's radius.
+    ///
+    /// 2. If the cell is alive and the rule does not allow it to survive with that many alive
+    ///    neighbors, mark it as dead in the next generation.
+    ///
+    /// 3. If the cell is dead and the rule allows it to be born with that many alive
+    ///    neighbors, mark it as alive in the next generation.
+    ///
+    /// When only a few cells are alive (see `neighbors::is_sparse`), only the alive cells and
+    /// the cells next to them are evaluated, since every other cell has no alive neighbors and
+    /// stays dead. Otherwise, the whole grid is evaluated. Rules where cells are born with no
+    /// alive neighbors (`B0`) always evaluate the whole grid.
+    ///
+    /// # Returns
+    /// A `HashSet` of `Cell` instances representing the alive cells in the next generation.
+    pub(crate) fn next_generation(&self) -> HashSet<Cell> {
+        if !self.rule.births(0)
+            && is_sparse(
+                self.generation.len(),
+                self.rows as usize * self.columns as usize,
+                self.neighborhood,
+                self.rule.radius,
+            )
+        {
+            self.sparse_next_generation()
+        } else {
+            self.dense_next_generation()
+        }
+    }
+
+    /// Computes the next generation by evaluating only the alive cells and the cells that
+    /// have an alive neighbor, which gives the same result as `dense_next_generation` as long
+    /// as the rule does not allow cells to be born with no alive neighbors.
+    fn sparse_next_generation(&self) -> HashSet<Cell> {
+        let counts: HashMap<(u16, u16), u8> = sparse_neighbor_counts(
+            &self.generation,
+            self.rows,
+            self.columns,
+            &self.surface_type,
+            self.neighborhood,
+            self.rule.radius,
+        );
+        let mut new_generation: HashSet<Cell> = HashSet::new();
+        for cell in &self.generation {
+            let alive_neighbors: u8 = counts.get(&(cell.row, cell.column)).copied().unwrap_or(0);
+            if self.rule.survives(alive_neighbors) {
+                new_generation.insert(cell.clone());
+            }
+        }
+        for (&(row, column), &alive_neighbors) in &counts {
+            let cell: Cell = Cell::new(ALIVE, row, column);
+            if self.rule.births(alive_neighbors) && !self.generation.contains(&cell) {
+                new_generation.insert(cell);
+            }
+        }
+        new_generation
+    }
+
+    /// Computes the next generation by evaluating every cell in the grid.
+    ///
+    /// # Note
+    /// With the `parallel` feature enabled, the rows of the grid are evaluated in parallel by
+    /// `parallel::next_generation` instead, which produces the same result.
+    #[cfg(feature = "parallel")]
+    fn dense_next_generation(&self) -> HashSet<Cell> {
+        crate::parallel::next_generation(
+            &self.generation,
+            self.rows,
+            self.columns,
+            &self.surface_type,
+            &self.rule,
+            self.neighborhood,
+        )
+    }
+
+    /// Computes the next generation by evaluating every cell in the grid.
+    #[cfg(not(feature = "parallel"))]
+    fn dense_next_generation(&self) -> HashSet<Cell> {
+        let grid: NeighborGrid = NeighborGrid::new(
+            &self.generation,
+            self.rows,
+            self.columns,
+            &self.surface_type,
+            self.neighborhood,
+            self.rule.radius,
+        );
+        let mut new_generation: HashSet<Cell> = self.generation.clone();
+        let mut row: u16 = 0;
+        while row < self.rows {
+            let mut column: u16 = 0;
+            while column < self.columns {
+                let alive_neighbors: u8 = grid.alive_neighbors(row, column);
+                if grid.is_alive(row, column) {
+                    if !self.rule.survives(alive_neighbors) {
+                        new_generation.remove(&Cell::new(ALIVE, row, column));
+                    }
+                } else if self.rule.births(alive_neighbors) {
+                    new_generation.insert(Cell::new(ALIVE, row, column));
+                }
+                column += 1;
+            }
+            row += 1;
+        }
+        new_generation
+    }
+
+    /// Simulates the specified number of generations in the simulation.
+    ///
+    /// # Description
+    /// This function advances the simulation by the given number of iterations, updating the
+    /// current generation based on the simulation's `Rule` (Conway's rules of the Game of Life
+    /// by default).
+    ///
+    /// For each iteration, the following steps are performed:
+    ///
+    /// 1. Save the current generation to the save history.
+    /// 2. Compute the next generation with `next_generation`.
+    /// 3. Update the current generation to the new generation.
+    /// 4. Increment the generation iteration counter.
+    ///
+    /// After simulating the specified number of iterations, if the simulation is set to display
+    /// in a window, the current generation is drawn on the display window.
+    ///
+    /// If the simulation is set to print to the console, the current generation is printed to
+    /// the console.
+    ///
+    /// # Arguments
+    /// * `iterations` - The number of generations to simulate.
+    pub fn simulate_generations(&mut self, iterations: u128) {
+        if iterations == 0 {
+            return;
+        }
+        for _ in 0..iterations {
+            self.save_generation();
+            let new_generation: HashSet<Cell> = self.next_generation();
+            if self.track_boundary_effects {
+                self.record_boundary_effects(&new_generation);
+            }
+            self.statistics
+                .record(&self.generation, &new_generation, self.columns);
+            self.generation_delta = GenerationDelta::between(&self.generation, &new_generation);
+            self.generation = new_generation;
+            self.iteration += 1;
+            self.notify_observers();
+        }
+        if self.display {
+            self.draw_generation()
+        }
+        if self.print {
+            println!("{}", self)
+        }
+    }
+
+    /// Simulates one generation.
+    pub fn simulate_generation(&mut self) {
+        self.simulate_generations(1)
+    }
+
+    /// Returns the alive cells of the next generation without advancing the simulation.
+    ///
+    /// # Description
+    /// The next generation is computed exactly as `simulate_generation` would compute it, but
+    /// nothing is changed: the iteration counter, save history, and statistics stay the same,
+    /// and nothing is drawn or printed. Comparing the result with `generation` shows which cells
+    /// are about to be born or die.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(5)
+    ///     .width(5)
+    ///     .seed_named("blinker")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let next_generation = simulation.preview_next_generation();
+    /// assert_eq!(simulation.iteration(), 0);
+    /// assert!(next_generation != simulation.generation());
+    ///
+    /// simulation.simulate_generation();
+    /// assert!(next_generation == simulation.generation());
+    /// ```
+    pub fn preview_next_generation(&self) -> HashSet<Cell> {
+        self.next_generation()
+    }
+
+    /// Returns the `(row, column)` positions of the cells that will be born in the next
+    /// generation (alive in `preview_next_generation` but not now), in ascending (row-major)
+    /// order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .seed("-----\n--*--\n--*--\n--*--\n-----")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(simulation.will_be_born_next_generation(), vec![(2, 1), (2, 3)]);
+    /// assert_eq!(simulation.will_die_next_generation(), vec![(1, 2), (3, 2)]);
+    /// ```
+    pub fn will_be_born_next_generation(&self) -> Vec<(u16, u16)> {
+        GenerationDelta::between(&self.generation, &self.next_generation()).born
+    }
+
+    /// Returns the `(row, column)` positions of the cells that will die in the next generation
+    /// (alive now but not in `preview_next_generation`), in ascending (row-major) order.
+    pub fn will_die_next_generation(&self) -> Vec<(u16, u16)> {
+        GenerationDelta::between(&self.generation, &self.next_generation()).died
+    }
+
+    /// Lazily simulates up to the specified number of generations, yielding the string
+    /// representation of each generation as it is simulated.
+    ///
+    /// # Description
+    /// Each call to `next` on the returned iterator simulates exactly one generation (exactly
+    /// like `simulate_generation`) and returns its `generation_string`. Nothing is simulated
+    /// ahead of time, so consuming only part of the iterator (for example with `take`) leaves
+    /// the simulation at the last generation that was yielded.
+    ///
+    /// # Arguments
+    /// * `n` - The maximum number of generations to simulate.
+    pub fn simulate_generations_lazy(&mut self, n: u128) -> impl Iterator<Item = String> + '_ {
+        (0..n).map(move |_| {
+            self.simulate_generation();
+            self.generation_string()
+        })
+    }
+
+    /// Simulates the specified number of generations, calling the given function with the
+    /// simulation after each one.
+    ///
+    /// # Description
+    /// Each generation is simulated exactly like `simulate_generation`, so this can be used to
+    /// log statistics, export frames, or update a user interface every generation without a
+    /// cooldown. If `n` is 0, nothing is simulated and the callback is never called.
+    ///
+    /// # Arguments
+    /// * `n` - The number of generations to simulate.
+    /// * `callback` - The function to call with the simulation after each generation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .seed_named("glider")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut alive_counts: Vec<u64> = Vec::new();
+    /// simulation.simulate_with_callback(4, |simulation| alive_counts.push(simulation.alive_count()));
+    /// assert_eq!(alive_counts, vec![5, 5, 5, 5]);
+    ///
+    /// let mut iterations: Vec<u128> = Vec::new();
+    /// simulation.simulate_with_callback(0, |simulation| iterations.push(simulation.iteration()));
+    /// assert!(iterations.is_empty());
+    /// ```
+    pub fn simulate_with_callback<F: FnMut(&Simulation)>(&mut self, n: u128, mut callback: F) {
+        for _ in 0..n {
+            self.simulate_generation();
+            callback(self);
+        }
+    }
+
+    /// Simulates the specified number of generations, recording the number of cells born and
+    /// the number of cells that died in each generation.
+    ///
+    /// # Description
+    /// This function simulates one generation at a time, exactly like `simulate_generation`,
+    /// and records how many cells were born and how many died in each step. Only the counts are
+    /// kept, so the memory used is proportional to the number of generations rather than the
+    /// size of the grid.
+    ///
+    /// # Arguments
+    /// * `n` - The number of generations to simulate.
+    ///
+    /// # Returns
+    /// A `Vec` with one `(births, deaths)` entry per simulated generation, in order.
+    pub fn simulate_generations_recording_births_deaths(&mut self, n: u128) -> Vec<(u64, u64)> {
+        let mut events: Vec<(u64, u64)> = Vec::new();
+        for _ in 0..n {
+            let births_before: u64 = self.statistics.total_births;
+            let deaths_before: u64 = self.statistics.total_deaths;
+            self.simulate_generation();
+            events.push((
+                self.statistics.total_births - births_before,
+                self.statistics.total_deaths - deaths_before,
+            ));
+        }
+        events
+    }
+
+    /// Returns the total number of cells born since the simulation was built or last reset.
+    ///
+    /// # Note
+    /// Rolling back generations does not undo births that were already counted.
+    pub fn total_births(&self) -> u64 {
+        self.statistics.total_births
+    }
+
+    /// Returns the total number of cells that died since the simulation was built or last
+    /// reset.
+    ///
+    /// # Note
+    /// Rolling back generations does not undo deaths that were already counted.
+    pub fn total_deaths(&self) -> u64 {
+        self.statistics.total_deaths
+    }
+
+    /// Simulates generations continuously with a specified cooldown period, until the
+    /// simulation is finished (if `stop_when_finished` is true) or the maximum number of
+    /// iterations is reached.
+    ///
+    /// # Arguments
+    /// * `cooldown` - The duration to wait between generations.
+    /// * `stop_when_finished` - Whether to stop once the simulation is finished (see
+    ///   `is_finished`).
+    /// * `max_iterations` - The maximum number of generations to simulate, or `None` for no
+    ///   limit.
+    ///
+    /// # Returns
+    /// A `SimulationOutcome` with the number of generations simulated, the reason the
+    /// simulation stopped (`StopReason::Finished` or `StopReason::MaximumIterations`), and the
+    /// time it took.
+    ///
+    /// # Note
+    /// If `stop_when_finished` is false and `max_iterations` is `None`, this function never
+    /// returns.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::{Simulation, SimulationOutcome, StopReason};
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(5)
+    ///     .width(5)
+    ///     .seed_named("blinker")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // The blinker is finished once it returns to its seed
+    /// let outcome: SimulationOutcome =
+    ///     simulation.simulate_continuous_generations(Duration::ZERO, true, Some(10));
+    /// assert_eq!(outcome.stop_reason, StopReason::Finished);
+    /// assert_eq!(outcome.iterations_run, 2);
+    ///
+    /// let outcome: SimulationOutcome =
+    ///     simulation.simulate_continuous_generations(Duration::ZERO, false, Some(10));
+    /// assert_eq!(outcome.stop_reason, StopReason::MaximumIterations);
+    /// assert_eq!(outcome.iterations_run, 10);
+    /// assert_eq!(simulation.iteration(), 12);
+    /// ```
+    pub fn simulate_continuous_generations(
+        &mut self,
+        cooldown: Duration,
+        stop_when_finished: bool,
+        max_iterations: Option<u128>,
+    ) -> SimulationOutcome {
+        self.simulate_with_stop_criteria(max_iterations, cooldown, stop_when_finished, |_| false)
+    }
+
+    /// Simulates generations continuously with a specified cooldown period until the given
+    /// condition is met, the simulation is finished, or the maximum number of iterations is
+    /// reached.
+    ///
+    /// # Description
+    /// Each tick, one generation is simulated with `simulate_generation` and then the stop
+    /// criteria are checked in the following order:
+    ///
+    /// 1. If the predicate returns true for the simulation, it stops with
+    ///    `StopReason::PredicateSatisfied`.
+    /// 2. If the simulation has reached a finished (periodic) state, it stops with
+    ///    `StopReason::Finished`.
+    /// 3. If `max_iterations` generations have been simulated, it stops with
+    ///    `StopReason::MaximumIterations`.
+    ///
+    /// Otherwise, it waits for the cooldown before the next generation. The predicate is only
+    /// checked after a generation is simulated, so it is always called at least once unless
+    /// `max_iterations` is 0. For example, `|simulation| simulation.alive_count() < 10` stops
+    /// once fewer than 10 cells are alive. The predicate can keep state between calls, such as
+    /// the time the run started, to stop after a wall-clock budget.
+    ///
+    /// # Arguments
+    /// * `max_iterations` - The maximum number of generations to simulate, or `None` to keep
+    ///   simulating until the predicate is satisfied or the simulation is finished.
+    /// * `cooldown` - The duration to wait between generations.
+    /// * `predicate` - The condition to stop at, checked after every generation.
+    ///
+    /// # Returns
+    /// A `SimulationOutcome` with the number of generations simulated, the reason the
+    /// simulation stopped, and the time it took.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::{Simulation, SimulationOutcome, StopReason};
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .seed_named("glider")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let outcome: SimulationOutcome = simulation.simulate_until(None, Duration::ZERO, |simulation| {
+    ///     simulation.is_cell_alive(9, 9).unwrap()
+    /// });
+    /// assert_eq!(outcome.stop_reason, StopReason::PredicateSatisfied);
+    /// assert_eq!(outcome.iterations_run, simulation.iteration());
+    ///
+    /// let outcome: SimulationOutcome = simulation.simulate_until(Some(3), Duration::ZERO, |_| false);
+    /// assert_eq!(outcome.stop_reason, StopReason::MaximumIterations);
+    /// assert_eq!(outcome.iterations_run, 3);
+    ///
+    /// // The glider becomes a block in the corner of the rectangle
+    /// let outcome: SimulationOutcome = simulation.simulate_until(Some(100), Duration::ZERO, |_| false);
+    /// assert_eq!(outcome.stop_reason, StopReason::Finished);
+    /// ```
+    pub fn simulate_until<F: FnMut(&Simulation) -> bool>(
+        &mut self,
+        max_iterations: Option<u128>,
+        cooldown: Duration,
+        predicate: F,
+    ) -> SimulationOutcome {
+        self.simulate_with_stop_criteria(max_iterations, cooldown, true, predicate)
+    }
+
+    /// Simulates generations with a specified cooldown period until the predicate is
+    /// satisfied, the simulation is finished (if `stop_when_finished` is true), or
+    /// `max_iterations` generations have been simulated (see `simulate_until`).
+    fn simulate_with_stop_criteria<F: FnMut(&Simulation) -> bool>(
+        &mut self,
+        max_iterations: Option<u128>,
+        cooldown: Duration,
+        stop_when_finished: bool,
+        mut predicate: F,
+    ) -> SimulationOutcome {
+        let start: Instant = Instant::now();
+        let mut iterations_run: u128 = 0;
+        let stop_reason: StopReason = loop {
+            if max_iterations.is_some_and(|max_iterations| iterations_run >= max_iterations) {
+                break StopReason::MaximumIterations;
+            }
+            if iterations_run > 0 {
+                sleep(cooldown)
+            }
+            self.simulate_generation();
+            iterations_run += 1;
+            if predicate(self) {
+                break StopReason::PredicateSatisfied;
+            }
+            if stop_when_finished && self.is_finished() {
+                break StopReason::Finished;
+            }
+        };
+        SimulationOutcome {
+            iterations_run,
+            stop_reason,
+            elapsed: start.elapsed(),
+        }
+    }
+
+    /// Simulates up to the specified number of generations, stopping early once the simulation
+    /// is finished (see `is_finished`).
+    ///
+    /// # Arguments
+    /// * `n` - The maximum number of generations to simulate.
+    ///
+    /// # Returns
+    /// The number of generations simulated. It is less than `n` only if the simulation finished
+    /// early, and it counts the generation that finished the simulation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(5)
+    ///     .width(5)
+    ///     .seed_named("blinker")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // The blinker returns to its seed after 2 generations
+    /// assert_eq!(simulation.simulate_n_or_until_finished(100), 2);
+    /// assert_eq!(simulation.iteration(), 2);
+    /// ```
+    pub fn simulate_n_or_until_finished(&mut self, n: u128) -> u128 {
+        let mut simulated: u128 = 0;
+        while simulated < n {
+            self.simulate_generation();
+            simulated += 1;
+            if self.is_finished() {
+                break;
+            }
+        }
+        simulated
+    }
+
+    /// Simulates generations until the simulation finishes, stagnates, or reaches the maximum
+    /// number of iterations.
+    ///
+    /// # Description
+    /// This function simulates one generation at a time, exactly like `simulate_generation`,
+    /// and checks the stop criteria after each generation in the following order:
+    ///
+    /// 1. If the simulation has reached a finished (periodic) state, it stops with
+    ///    `StopReason::Finished`.
+    /// 2. If a `stagnation_limit` was given and the simulation is stagnant according to it,
+    ///    it stops with `StopReason::Stagnant`.
+    /// 3. If `maximum_iterations` generations have been simulated, it stops with
+    ///    `StopReason::MaximumIterations`.
+    ///
+    /// # Arguments
+    /// * `maximum_iterations` - The maximum number of generations to simulate.
+    /// * `stagnation_limit` - The optional stagnation stop criterion.
+    ///
+    /// # Returns
+    /// The reason the simulation stopped.
+    pub fn run_to_completion(
+        &mut self,
+        maximum_iterations: u128,
+        stagnation_limit: Option<StagnationLimit>,
+    ) -> StopReason {
+        for _ in 0..maximum_iterations {
+            self.simulate_generation();
+            if self.is_finished() {
+                return StopReason::Finished;
+            }
+            if let Some(limit) = stagnation_limit {
+                if self.is_stagnant(limit.window, limit.tolerance) {
+                    return StopReason::Stagnant;
+                }
+            }
+        }
+        StopReason::MaximumIterations
+    }
+
+    /// Simulates generations on a separate thread, streaming each generation through a channel.
+    ///
+    /// # Description
+    /// This function moves the simulation onto a newly spawned thread and simulates the specified
+    /// number of generations there, sending the `generation_string` of each new generation over
+    /// an `mpsc` channel as soon as it has been simulated.
+    ///
+    /// The returned `Receiver` can be read from another thread (such as a GUI thread) to update
+    /// a display without blocking on the simulation itself. Generations arrive in the order they
+    /// were simulated, and the channel is closed as soon as the last generation has been sent.
+    /// If the `Receiver` is dropped early, the simulation thread stops at the next generation.
+    ///
+    /// The simulation is consumed, so simulate a clone to keep using it afterwards. Observers
+    /// registered with `on_generation` are moved along with it and called on the new thread.
+    ///
+    /// # Arguments
+    /// * `steps` - The number of generations to simulate and send.
+    /// * `cooldown` - The duration to wait between sending generations.
+    ///
+    /// # Returns
+    /// A `Receiver` that yields the string representation of each simulated generation.
+    ///
+    /// # Panics
+    /// Display windows can not be moved between threads, so this function panics if the
+    /// simulation was built with `display` set to true.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    /// use std::sync::mpsc::Receiver;
+    /// use std::time::Duration;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .height(5)
+    ///     .width(5)
+    ///     .seed_named("blinker")
+    ///     .display(false)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let receiver: Receiver<String> =
+    ///     simulation.simulate_generations_async_with_channel(4, Duration::ZERO);
+    /// let generations: Vec<String> = receiver.iter().collect();
+    /// assert_eq!(generations.len(), 4);
+    /// assert_eq!(generations[0], generations[2]);
+    /// ```
+    pub fn simulate_generations_async_with_channel(
+        self,
+        steps: u128,
+        cooldown: Duration,
+    ) -> Receiver<String> {
+        assert!(
+            !self.display,
+            "Simulations with a display can not be simulated asynchronously"
+        );
+        let headless: HeadlessSimulation = self.into_headless();
+        let (sender, receiver) = channel();
+        spawn(move || {
+            let mut simulation: Simulation = headless.into_simulation();
+            for step in 0..steps {
+                if step > 0 {
+                    sleep(cooldown);
+                }
+                simulation.simulate_generation();
+                if sender.send(simulation.generation_string()).is_err() {
+                    break;
+                }
+            }
+        });
+        receiver
+    }
+
+    /// Drops the simulation's display window, so the rest of it can be moved to another thread.
+    fn into_headless(self) -> HeadlessSimulation {
+        // Every field is listed, so a new field can not be left out without a compiler error
+        let Simulation {
+            seed,
+            rng_seed,
+            rng,
+            random_alive_probability,
+            surface_type,
+            rule,
+            neighborhood,
+            rows,
+            columns,
+            generation,
+            iteration,
+            statistics,
+            generation_delta,
+            save_history,
+            maximum_saves,
+            print,
+            detect_translated_cycles,
+            track_boundary_effects,
+            stamp_palette,
+            stamp_collision_mode,
+            render_config,
+            render_chars,
+            display: _,
+            window_data: _,
+            observers,
+        } = self;
+        HeadlessSimulation {
+            seed,
+            rng_seed,
+            rng,
+            random_alive_probability,
+            surface_type,
+            rule,
+            neighborhood,
+            rows,
+            columns,
+            generation,
+            iteration,
+            statistics,
+            generation_delta,
+            save_history,
+            maximum_saves,
+            print,
+            detect_translated_cycles,
+            track_boundary_effects,
+            stamp_palette,
+            stamp_collision_mode,
+            render_config,
+            render_chars,
+            observers,
+        }
+    }
+
+    /// Returns the count of alive cells in the current generation.
+    pub fn alive_count(&self) -> u64 {
+        self.generation.len() as u64
+    }
+
+    /// Returns the proportion of alive cells in the current generation.
+    pub fn alive_proportion(&self) -> f64 {
+        self.alive_count() as f64 / self.area() as f64
+    }
+
+    /// Returns the total area (number of cells) in the simulation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// // Grids can have more cells than fit in a u16
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(500)
+    ///     .width(500)
+    ///     .surface_ball()
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(simulation.area(), 250_000);
+    /// assert_eq!(simulation.generation_string().len(), 250_000);
+    ///
+    /// simulation.simulate_generations(2);
+    /// assert_eq!(
+    ///     simulation.alive_count() + simulation.dead_count(),
+    ///     simulation.area() as u64
+    /// );
+    /// assert!(simulation.alive_proportion() <= 1.0);
+    /// ```
+    pub fn area(&self) -> u32 {
+        self.rows as u32 * self.columns as u32
+    }
+
+    /// Returns the count of dead cells in the current generation.
+    pub fn dead_count(&self) -> u64 {
+        self.area() as u64 - self.alive_count()
+    }
+
+    /// Returns the number of alive cells in each row of the current generation, from the first
+    /// row to the last.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .seed("-*-\n--*\n***")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(simulation.alive_count_by_row(), vec![1, 1, 3]);
+    /// assert_eq!(simulation.alive_count_by_column(), vec![1, 2, 2]);
+    /// assert_eq!(simulation.alive_count_in_region(1, 1, 2, 2), Ok(3));
+    /// assert!(simulation.alive_count_in_region(0, 0, 3, 0).is_err());
+    /// ```
+    pub fn alive_count_by_row(&self) -> Vec<u64> {
+        let mut counts: Vec<u64> = vec![0; self.rows as usize];
+        for cell in &self.generation {
+            counts[cell.row as usize] += 1;
+        }
+        counts
+    }
+
+    /// Returns the number of alive cells in each column of the current generation, from the
+    /// first column to the last.
+    pub fn alive_count_by_column(&self) -> Vec<u64> {
+        let mut counts: Vec<u64> = vec![0; self.columns as usize];
+        for cell in &self.generation {
+            counts[cell.column as usize] += 1;
+        }
+        counts
+    }
+
+    /// Returns the number of alive cells of the current generation in the region from
+    /// `(r1, c1)` to `(r2, c2)` (inclusive).
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The number of alive cells in the region.
+    /// * `Err(String)` - An error message if the region is empty or exceeds the grid.
+    pub fn alive_count_in_region(&self, r1: u16, c1: u16, r2: u16, c2: u16) -> Result<u64, String> {
+        self.check_region(r1, c1, r2, c2)?;
+        Ok(self
+            .generation
+            .iter()
+            .filter(|cell| (r1..=r2).contains(&cell.row) && (c1..=c2).contains(&cell.column))
+            .count() as u64)
+    }
+
+    /// Returns the number of cells that are alive in the current generation of exactly one of
+    /// this simulation and another (the Hamming distance between the generations).
+    ///
+    /// # Arguments
+    /// * `other` - The simulation to compare this one with.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - The number of cells that differ.
+    /// * `Err(String)` - An error message if the simulations do not have the same number of rows
+    ///   and columns.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let a: Simulation = SimulationBuilder::new().height(2).width(2).seed("**--").build().unwrap();
+    /// let b: Simulation = SimulationBuilder::new().height(2).width(2).seed("*-*-").build().unwrap();
+    ///
+    /// assert_eq!(a.structural_distance(&b).unwrap(), 2);
+    /// assert_eq!(a.structural_similarity(&b).unwrap(), 0.5);
+    /// assert_eq!(a.structural_similarity(&a).unwrap(), 1.0);
+    /// ```
+    pub fn structural_distance(&self, other: &Simulation) -> Result<u32, String> {
+        self.check_same_size(other)?;
+        Ok(hamming_distance(&self.generation, &other.generation) as u32)
+    }
+
+    /// Returns the proportion of cells that are the same in the current generation of this
+    /// simulation and another, from `0.0` (every cell differs) to `1.0` (the generations are
+    /// equal). This is `1.0 - structural_distance / area` (see `structural_distance`).
+    pub fn structural_similarity(&self, other: &Simulation) -> Result<f64, String> {
+        let distance: u32 = self.structural_distance(other)?;
+        Ok(1.0 - distance as f64 / self.area() as f64)
+    }
+
+    /// Returns the smallest rectangle that contains every alive cell of the current generation.
+    ///
+    /// # Returns
+    /// * `Some((min_row, min_column, max_row, max_column))` - The first and last row and column
+    ///   with an alive cell (inclusive).
+    /// * `None` - If there are no alive cells.
+    ///
+    /// # Note
+    /// The rectangle does not wrap around the edges of the grid, so a pattern crossing a
+    /// wrapping edge gets a box stretching across the grid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .height(4)
+    ///     .width(4)
+    ///     .seed("-----*-----*--**")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(simulation.bounding_box(), Some((1, 1, 3, 3)));
+    /// assert_eq!(simulation.center_of_mass(), Some((2.25, 2.25)));
+    /// ```
+    pub fn bounding_box(&self) -> Option<(u16, u16, u16, u16)> {
+        self.generation
+            .iter()
+            .fold(None, |bounds, cell| match bounds {
+                None => Some((cell.row, cell.column, cell.row, cell.column)),
+                Some((min_row, min_column, max_row, max_column)) => Some((
+                    min_row.min(cell.row),
+                    min_column.min(cell.column),
+                    max_row.max(cell.row),
+                    max_column.max(cell.column),
+                )),
+            })
+    }
+
+    /// Returns the mean row and mean column of the alive cells of the current generation, or
+    /// `None` if there are no alive cells. As with `bounding_box`, the edges are not wrapped
+    /// around.
+    pub fn center_of_mass(&self) -> Option<(f64, f64)> {
+        if self.generation.is_empty() {
+            return None;
+        }
+        let (row_sum, column_sum): (u64, u64) =
+            self.generation
+                .iter()
+                .fold((0, 0), |(row_sum, column_sum), cell| {
+                    (row_sum + cell.row as u64, column_sum + cell.column as u64)
+                });
+        let alive_count: f64 = self.generation.len() as f64;
+        Some((
+            row_sum as f64 / alive_count,
+            column_sum as f64 / alive_count,
+        ))
+    }
+
+    /// Shifts the alive cells of the current generation (see `shift`) so that their bounding
+    /// box is centered on the grid.
+    ///
+    /// # Note
+    /// If the bounding box can not be centered exactly (the grid and the box differ in size by
+    /// an odd number of rows or columns), the box is placed one row above or one column left of
+    /// the exact center. Nothing is changed if there are no alive cells.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(8)
+    ///     .width(7)
+    ///     .seed_named("glider")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// simulation.simulate_generations(8);
+    /// simulation.center_pattern();
+    /// // The 3x3 glider leaves 5 rows (2 above) and 4 columns (2 on the left)
+    /// assert_eq!(simulation.bounding_box(), Some((2, 2, 4, 4)));
+    /// ```
+    pub fn center_pattern(&mut self) {
+        if let Some((min_row, min_column, max_row, max_column)) = self.bounding_box() {
+            let top: i32 = (self.rows - (max_row - min_row + 1)) as i32 / 2;
+            let left: i32 = (self.columns - (max_column - min_column + 1)) as i32 / 2;
+            self.shift(top - min_row as i32, left - min_column as i32);
+        }
+    }
+
+    /// Returns true if the current generation is unchanged by `flip_horizontal`, so for every
+    /// alive cell at `(row, column)` the cell at `(row, columns - 1 - column)` is also alive.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// // A glider has none of the symmetries, and a blinker has all of them
+    /// let glider: Simulation = SimulationBuilder::new().seed("-*-\n--*\n***").build().unwrap();
+    /// let blinker: Simulation = SimulationBuilder::new().seed("-*-\n-*-\n-*-").build().unwrap();
+    /// assert!(!glider.is_symmetric_horizontally());
+    /// assert!(!glider.is_symmetric_vertically());
+    /// assert!(!glider.is_symmetric_rotationally_180());
+    /// assert!(blinker.is_symmetric_horizontally());
+    /// assert!(blinker.is_symmetric_vertically());
+    /// assert!(blinker.is_symmetric_rotationally_180());
+    ///
+    /// // Two opposite corners are only symmetric under a half turn
+    /// let corners: Simulation = SimulationBuilder::new().seed("*--\n---\n--*").build().unwrap();
+    /// assert!(!corners.is_symmetric_horizontally());
+    /// assert!(!corners.is_symmetric_vertically());
+    /// assert!(corners.is_symmetric_rotationally_180());
+    /// ```
+    pub fn is_symmetric_horizontally(&self) -> bool {
+        let columns: u16 = self.columns;
+        self.is_symmetric(|row, column| (row, columns - 1 - column))
+    }
+
+    /// Returns true if the current generation is unchanged by `flip_vertical`, so for every alive
+    /// cell at `(row, column)` the cell at `(rows - 1 - row, column)` is also alive.
+    pub fn is_symmetric_vertically(&self) -> bool {
+        let rows: u16 = self.rows;
+        self.is_symmetric(|row, column| (rows - 1 - row, column))
+    }
+
+    /// Returns true if the current generation is unchanged by `rotate_180`, so for every alive
+    /// cell at `(row, column)` the cell at `(rows - 1 - row, columns - 1 - column)` is also alive.
+    pub fn is_symmetric_rotationally_180(&self) -> bool {
+        let (rows, columns): (u16, u16) = (self.rows, self.columns);
+        self.is_symmetric(|row, column| (rows - 1 - row, columns - 1 - column))
+    }
+
+    /// Returns true if the cell each alive cell of the current generation is mapped to is also
+    /// alive.
+    fn is_symmetric<F: Fn(u16, u16) -> (u16, u16)>(&self, mirror: F) -> bool {
+        self.generation.iter().all(|cell| {
+            let (row, column): (u16, u16) = mirror(cell.row, cell.column);
+            self.generation.contains(&Cell::new(ALIVE, row, column))
+        })
+    }
+
+    /// Returns the grid indices (`row * columns + column`) of the alive cells in the current
+    /// generation in ascending (row-major) order.
+    pub fn alive_cells_as_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self
+            .generation
+            .iter()
+            .map(|cell| cell.row as usize * self.columns as usize + cell.column as usize)
+            .collect();
+        indices.sort_unstable();
+        indices
+    }
+
+    /// Returns an iterator over the `(row, column)` positions of the alive cells in the current
+    /// generation, in no particular order.
+    pub fn alive_cells_iter(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        self.generation.iter().map(|cell| (cell.row, cell.column))
+    }
+
+    /// Returns the `(row, column)` positions of the alive cells in the current generation in
+    /// ascending (row-major) order.
+    pub fn alive_cells_as_vec(&self) -> Vec<(u16, u16)> {
+        let mut positions: Vec<(u16, u16)> = self.alive_cells_iter().collect();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// Returns an iterator over the `(row, column)` positions of the alive cells in the current
+    /// generation in ascending (row-major) order (see `alive_cells_as_vec`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .seed("-*-\n--*\n***")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let alive_cells: Vec<(u16, u16)> = simulation.alive_cells().collect();
+    /// assert_eq!(alive_cells, vec![(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)]);
+    ///
+    /// let first_row: Vec<(u16, u16, bool)> = simulation.cells().take(3).collect();
+    /// assert_eq!(first_row, vec![(0, 0, false), (0, 1, true), (0, 2, false)]);
+    /// assert_eq!(simulation.cells().count(), 9);
+    /// ```
+    pub fn alive_cells(&self) -> impl Iterator<Item = (u16, u16)> {
+        self.alive_cells_as_vec().into_iter()
+    }
+
+    /// Returns an iterator over every position of the grid in ascending (row-major) order, as
+    /// `(row, column, alive)`, where `alive` is true if the cell is alive in the current
+    /// generation.
+    pub fn cells(&self) -> impl Iterator<Item = (u16, u16, bool)> + '_ {
+        (0..self.rows).flat_map(move |row| {
+            (0..self.columns).map(move |column| {
+                let alive: bool = self.generation.contains(&Cell::new(ALIVE, row, column));
+                (row, column, alive)
+            })
+        })
+    }
+
+    /// Partitions the dead cells of the current generation by their nearest alive cell.
+    ///
+    /// # Description
+    /// This function computes a discrete Voronoi diagram of the grid, where each alive cell is
+    /// a site and every dead cell belongs to the region of the alive cell nearest to it.
+    ///
+    /// Distances are measured with the Euclidean distance between cell positions on the flat
+    /// grid, without taking the surface type's wrapping into account. When a dead cell is
+    /// equally close to multiple alive cells, it belongs to the region of the alive cell that
+    /// comes first in `alive_cells_as_indices`.
+    ///
+    /// # Returns
+    /// A `Vec` with one entry per alive cell, in the same order as `alive_cells_as_indices`,
+    /// where each entry contains the `(row, column)` positions of the dead cells in that alive
+    /// cell's region. If there are no alive cells, the returned `Vec` is empty.
+    pub fn alive_cells_as_voronoi_diagram(&self) -> Vec<Vec<(u16, u16)>> {
+        let columns: usize = self.columns as usize;
+        let sites: Vec<(u16, u16)> = self
+            .alive_cells_as_indices()
+            .into_iter()
+            .map(|index| ((index / columns) as u16, (index % columns) as u16))
+            .collect();
+        let mut regions: Vec<Vec<(u16, u16)>> = vec![Vec::new(); sites.len()];
+        if sites.is_empty() {
+            return regions;
+        }
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                if self.get_cell(row, column).is_alive() {
+                    continue;
+                }
+                let mut nearest_site: usize = 0;
+                let mut nearest_distance: u64 = u64::MAX;
+                for (site_index, (site_row, site_column)) in sites.iter().enumerate() {
+                    let row_distance: u64 = row.abs_diff(*site_row) as u64;
+                    let column_distance: u64 = column.abs_diff(*site_column) as u64;
+                    let squared_distance: u64 =
+                        row_distance * row_distance + column_distance * column_distance;
+                    if squared_distance < nearest_distance {
+                        nearest_site = site_index;
+                        nearest_distance = squared_distance;
+                    }
+                }
+                regions[nearest_site].push((row, column));
+            }
+        }
+        regions
+    }
+
+    /// Resets the simulation to the initial seed, clearing the save history.
+    /// # Note
+    /// Resetting is preferred over creating a new simulation since it will continue in the same
+    /// window. You can not have multiple windows at once.
+    pub fn reset(&mut self) {
+        let seed: String = self.seed.clone();
+        self.generation = generation_from_string(seed, self.rows, self.columns).unwrap();
+        self.iteration = 0;
+        self.save_history = SaveHistory::default();
+        self.statistics =
+            Statistics::new(self.rows as usize * self.columns as usize, &self.generation);
+        self.generation_delta = GenerationDelta::default();
+    }
+
+    /// Resets the simulation to the specified seed, clearing the save history.
+    /// # Note
+    /// Resetting is preferred over creating a new simulation since it will continue in the same
+    /// window. You can not have multiple windows at once.
+    ///
+    /// # Panics
+    /// This function panics if the seed does not have `rows * columns` cells or is not written
+    /// in a single `SeedAlphabet` (see `generation_from_string`).
+    pub fn reset_to(&mut self, seed: &str) {
+        self.generation =
+            generation_from_string(String::from(seed), self.rows, self.columns).unwrap();
+        self.seed = string_from_generation(self.generation.clone(), self.rows, self.columns);
+        self.iteration = 0;
+        self.save_history = SaveHistory::default();
+        self.statistics =
+            Statistics::new(self.rows as usize * self.columns as usize, &self.generation);
+        self.generation_delta = GenerationDelta::default();
+    }
+
+    /// Resets the simulation to a random seed, clearing the save history.
+    ///
+    /// # Note
+    /// Resetting is preferred over creating a new simulation since it will continue in the same
+    /// window. You can not have multiple windows at once.
+    ///
+    /// If the simulation was built with `SimulationBuilder::rng_seed` (or reset with
+    /// `reset_to_rand_seeded`), the seed is the next one drawn from the same random number
+    /// generator, so a sequence of resets is reproducible. Otherwise, it is drawn from
+    /// `rand::thread_rng`. If the simulation was built with
+    /// `SimulationBuilder::alive_probability`, each cell is alive with that chance.
+    pub fn reset_to_rand(&mut self) {
+        let seed: String = draw_random_seed(
+            self.rows,
+            self.columns,
+            self.random_alive_probability,
+            self.rng.as_mut(),
+        );
+        self.generation = generation_from_string(seed.clone(), self.rows, self.columns).unwrap();
+        self.seed = seed;
+        self.iteration = 0;
+        self.save_history = SaveHistory::default();
+        self.statistics =
+            Statistics::new(self.rows as usize * self.columns as usize, &self.generation);
+        self.generation_delta = GenerationDelta::default();
+    }
+
+    /// Resets the simulation to a random seed drawn from a random number generator seeded with
+    /// the given number, clearing the save history.
+    ///
+    /// # Description
+    /// Later calls to `reset_to_rand` keep drawing seeds from the same generator, so resetting
+    /// two simulations of the same size with the same number gives them the same seeds.
+    ///
+    /// # Arguments
+    /// * `rng_seed` - The number to seed the random number generator with.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new().height(20).width(20).build().unwrap();
+    /// let mut other: Simulation = SimulationBuilder::new().height(20).width(20).build().unwrap();
+    ///
+    /// simulation.reset_to_rand_seeded(7);
+    /// other.reset_to_rand_seeded(7);
+    /// assert_eq!(simulation.seed(), other.seed());
+    /// simulation.reset_to_rand();
+    /// other.reset_to_rand();
+    /// assert_eq!(simulation.seed(), other.seed());
+    /// ```
+    pub fn reset_to_rand_seeded(&mut self, rng_seed: u64) {
+        self.rng_seed = Some(rng_seed);
+        self.rng = Some(StdRng::seed_from_u64(rng_seed));
+        self.reset_to_rand();
+    }
+
+    /// Returns true if the simulation is in a still state (a period of 1).
+    pub fn is_still(&self) -> bool {
+        self.is_periodic(1)
+    }
+
+    /// Returns true if the simulation is in a periodic state with the specified period (see
+    /// `is_periodic`).
+    pub fn has_period(&self, period: usize) -> bool {
+        self.is_periodic(period)
+    }
+
+    /// Returns true if the simulation is in a periodic state with the specified period. A
+    /// period of 0 is never matched.
+    pub fn is_periodic(&self, period: usize) -> bool {
+        period > 0
+            && self.save_history.len() >= period
+            && self.save_history.len() - period >= self.save_history.trajectory_start()
+            && self
+                .save_history
+                .matches(self.save_history.len() - (period), &self.generation)
+    }
+
+    /// Returns the smallest period for which `is_periodic` is true, or `None` if the current
+    /// generation does not match any generation in the save history.
+    ///
+    /// # Note
+    /// The save history is scanned once from the most recent generation backwards, stopping at
+    /// the first match, instead of calling `is_periodic` for every possible period.
+    pub fn detect_period(&self) -> Option<usize> {
+        self.save_history
+            .candidates(&self.generation, false)
+            .find(|&index| self.save_history.get(index) == self.generation)
+            .map(|index| self.save_history.len() - index)
+    }
+
+    /// Finds the period of the cycle the simulation is in or is about to enter, without using
+    /// the save history.
+    ///
+    /// # Description
+    /// This function uses Brent's cycle detection algorithm, so no generations other than the
+    /// two being compared are kept. A "tortoise" copy of a generation is held still while the
+    /// simulation's own generation, the "hare", is advanced one generation at a time. If the
+    /// hare reaches the tortoise, the number of generations it took is the period. Otherwise,
+    /// the tortoise is moved to the hare after every power of two generations, so that it
+    /// eventually lands inside the cycle.
+    ///
+    /// Unlike `detect_period`, the current generation does not need to be part of the cycle,
+    /// and the number of saves the simulation keeps does not limit which periods can be found.
+    ///
+    /// # Arguments
+    /// * `max_period` - The largest period to look for.
+    ///
+    /// # Returns
+    /// The period of the cycle, or `None` if the simulation does not enter a cycle with a
+    /// period of at most `max_period` within `max_period` generations.
+    ///
+    /// # Note
+    /// The current generation is restored before this function returns, and the generations
+    /// the hare passes through are not counted, saved, drawn, or printed, so the simulation is
+    /// left as it was.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .seed_named("blinker")
+    ///     .maximum_saves(1)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // The save history is too short for detect_period, but the period is still found
+    /// assert_eq!(simulation.find_period(10), Some(2));
+    /// assert_eq!(simulation.find_period(1), None);
+    /// assert_eq!(simulation.iteration(), 0);
+    /// ```
+    pub fn find_period(&mut self, max_period: usize) -> Option<usize> {
+        if max_period == 0 {
+            return None;
+        }
+        let initial_generation: HashSet<Cell> = self.generation.clone();
+        let period: Option<usize> = self.brent_period(max_period);
+        self.generation = initial_generation;
+        period
+    }
+
+    /// Runs Brent's cycle detection algorithm for `find_period`, advancing the current
+    /// generation as the hare.
+    fn brent_period(&mut self, max_period: usize) -> Option<usize> {
+        // Once the power is larger than max_period, the tortoise was placed at least max_period
+        // generations in, and the hare has had at least max_period generations to catch up to it
+        let power_limit: usize = (max_period + 1).checked_next_power_of_two()?;
+        let mut tortoise: HashSet<Cell> = self.generation.clone();
+        self.generation = self.next_generation();
+        let mut power: usize = 1;
+        let mut period: usize = 1;
+        while self.generation != tortoise {
+            if period == power {
+                if power >= power_limit {
+                    return None;
+                }
+                tortoise = self.generation.clone();
+                power *= 2;
+                period = 0;
+            }
+            self.generation = self.next_generation();
+            period += 1;
+        }
+        (period <= max_period).then_some(period)
+    }
+
+    /// Returns true if the simulation has reached a finished state (has any periodic state).
+    ///
+    /// # Note
+    /// If the simulation was built with `detect_translated_cycles`, a generation that is a
+    /// translated copy of a saved generation (such as a lone spaceship) also counts as a
+    /// periodic state.
+    pub fn is_finished(&self) -> bool {
+        self.cycle_info().is_some()
+    }
+
+    /// Returns information about the cycle the simulation is in, if it has reached a finished
+    /// state.
+    ///
+    /// # Description
+    /// This function searches the save history from the most recent generation backwards for a
+    /// generation matching the current generation, so the smallest period is found.
+    ///
+    /// If the simulation was built with `detect_translated_cycles`, each generation is
+    /// normalized by moving its bounding box to the top-left corner before comparing, so a
+    /// generation that is a translated copy of a saved generation also matches. The
+    /// translation is reported as the cycle's displacement. Generations with multiple objects
+    /// only match if every object moved by the same amount, so a spaceship next to a still life
+    /// is never reported as a translated cycle.
+    ///
+    /// # Returns
+    /// A `CycleInfo` describing the period and displacement of the cycle, or `None` if the
+    /// current generation does not match any generation in the save history.
+    pub fn cycle_info(&self) -> Option<CycleInfo> {
+        let normalized: Option<NormalizedGeneration> = if self.detect_translated_cycles {
+            normalize_generation(&self.generation)
+        } else {
+            None
+        };
+        for index in self
+            .save_history
+            .candidates(&self.generation, self.detect_translated_cycles)
+        {
+            let saved_generation: HashSet<Cell> = self.save_history.get(index);
+            let period: u128 = (self.save_history.len() - index) as u128;
+            if saved_generation == self.generation {
+                return Some(CycleInfo {
+                    period,
+                    displacement: (0, 0),
+                });
+            }
+            let (origin, cells) = match &normalized {
+                Some(normalized) => normalized,
+                None => continue,
+            };
+            if let Some((saved_origin, saved_cells)) = normalize_generation(&saved_generation) {
+                if *cells == saved_cells {
+                    return Some(CycleInfo {
+                        period,
+                        displacement: (
+                            origin.0 as i32 - saved_origin.0 as i32,
+                            origin.1 as i32 - saved_origin.1 as i32,
+                        ),
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the number of generations it takes for a change at one cell to possibly reach
+    /// another cell.
+    ///
+    /// # Description
+    /// A cell's next state only depends on its neighbors, so a change can spread by at most
+    /// the rule's radius per generation. In the `Moore` neighborhood, this makes the distance
+    /// the Chebyshev distance between the two cells (the larger of the row and column
+    /// distances) divided by the radius, rounded up. In the `VonNeumann` neighborhood, it is the
+    /// Manhattan distance (the sum of the row and column distances) divided by the radius,
+    /// rounded up.
+    ///
+    /// On wrapping surfaces, the distance along a wrapping axis is measured the shorter way
+    /// around the grid. On a `Mobius` or `KleinBottle` surface, going around the grid
+    /// horizontally flips the row, so the distance around the grid is measured to the flipped
+    /// row.
+    pub(crate) fn influence_distance(&self, from: (u16, u16), to: (u16, u16)) -> u16 {
+        let axis_distance = |a: u16, b: u16, length: u16, wrapping: bool| {
+            let distance: u16 = a.abs_diff(b);
+            if wrapping {
+                distance.min(length - distance)
+            } else {
+                distance
+            }
+        };
+        let combine = |row_distance: u16, column_distance: u16| match self.neighborhood {
+            Neighborhood::Moore => row_distance.max(column_distance),
+            Neighborhood::VonNeumann => row_distance.saturating_add(column_distance),
+        };
+        let row_distance: u16 = axis_distance(
+            from.0,
+            to.0,
+            self.rows,
+            self.surface_type.wraps_vertically(),
+        );
+        let distance: u16 = if self.surface_type.flips_on_horizontal_wrap() {
+            let column_distance: u16 = from.1.abs_diff(to.1);
+            let flipped_row_distance: u16 = axis_distance(
+                from.0,
+                self.rows - 1 - to.0,
+                self.rows,
+                self.surface_type.wraps_vertically(),
+            );
+            combine(row_distance, column_distance).min(combine(
+                flipped_row_distance,
+                self.columns - column_distance,
+            ))
+        } else {
+            let column_distance: u16 = axis_distance(
+                from.1,
+                to.1,
+                self.columns,
+                self.surface_type.wraps_horizontally(),
+            );
+            combine(row_distance, column_distance)
+        };
+        distance.div_ceil(self.rule.radius as u16)
+    }
+
+    /// Computes the light cone of a cell: the earliest generation at which toggling it could
+    /// possibly affect each other cell.
+    ///
+    /// # Description
+    /// This function does not run the simulation. Since a change can spread by at most the
+    /// rule's radius in every direction per generation, the earliest generation a cell could be
+    /// affected is its distance from the origin divided by the radius, taking the surface type's
+    /// wrapping into account (see
+    /// `actual_influence_map` for the cells that are really affected).
+    ///
+    /// On a `Custom` surface, the distance can not be computed from the positions alone, so
+    /// the change is spread one generation at a time to every cell that has an affected cell
+    /// as a neighbor, using the surface's wrapping function.
+    ///
+    /// # Arguments
+    /// * `origin` - The `(row, column)` of the cell that would be toggled.
+    /// * `generations` - The number of generations to compute the light cone for.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - The earliest generation each cell could be affected, in row-major order
+    ///   (indexed by `row * columns + column`). Cells that can not be affected within the given
+    ///   number of generations are `u8::MAX`, and larger generations are capped at `u8::MAX`.
+    /// * `Err(String)` - An error message if the origin is outside of the grid.
+    pub fn influence_map(&self, origin: (u16, u16), generations: u128) -> Result<Vec<u8>, String> {
+        self.check_bounds(origin.0, origin.1)?;
+        if let Custom(wrap) = &self.surface_type {
+            return Ok(self.custom_influence_map(origin, generations, wrap));
+        }
+        let mut map: Vec<u8> = Vec::new();
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let distance: u16 = self.influence_distance(origin, (row, column));
+                if distance as u128 > generations {
+                    map.push(u8::MAX);
+                } else {
+                    map.push(distance.min(u8::MAX as u16) as u8);
+                }
+            }
+        }
+        Ok(map)
+    }
+
+    /// Computes the light cone of a cell on a `Custom` surface, by spreading the change from
+    /// the origin to the cells that have it as a neighbor, one generation at a time.
+    fn custom_influence_map(
+        &self,
+        origin: (u16, u16),
+        generations: u128,
+        wrap: &SurfaceWrap,
+    ) -> Vec<u8> {
+        let columns: usize = self.columns as usize;
+        let offsets: Vec<(i32, i32)> = neighbor_offsets(self.neighborhood, self.rule.radius);
+        // The cells each cell is a neighbor of, which a change to it spreads to
+        let mut spreads_to: Vec<Vec<(u16, u16)>> = vec![Vec::new(); self.rows as usize * columns];
+        let mut neighbors: Vec<(u16, u16)> = Vec::new();
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                custom_neighbors(
+                    (row, column),
+                    (self.rows, self.columns),
+                    wrap,
+                    &offsets,
+                    &mut neighbors,
+                );
+                for &(neighbor_row, neighbor_column) in &neighbors {
+                    spreads_to[neighbor_row as usize * columns + neighbor_column as usize]
+                        .push((row, column));
+                }
+            }
+        }
+        let mut map: Vec<u8> = vec![u8::MAX; self.rows as usize * columns];
+        let mut distances: Vec<Option<u128>> = vec![None; map.len()];
+        let mut queue: VecDeque<(u16, u16)> = VecDeque::from([origin]);
+        distances[origin.0 as usize * columns + origin.1 as usize] = Some(0);
+        while let Some((row, column)) = queue.pop_front() {
+            let index: usize = row as usize * columns + column as usize;
+            let distance: u128 = distances[index].unwrap_or_default();
+            map[index] = distance.min(u8::MAX as u128) as u8;
+            if distance == generations {
+                continue;
+            }
+            for &(next_row, next_column) in &spreads_to[index] {
+                let next_index: usize = next_row as usize * columns + next_column as usize;
+                if distances[next_index].is_none() {
+                    distances[next_index] = Some(distance + 1);
+                    queue.push_back((next_row, next_column));
+                }
+            }
+        }
+        map
+    }
+
+    /// Computes the generation at which toggling a cell actually first changed each other cell.
+    ///
+    /// # Description
+    /// This function simulates two copies of the current generation side by side, one of them
+    /// with the origin cell toggled, and records the first generation at which each cell
+    /// differs between the two. The origin cell itself differs at generation 0. The simulation
+    /// itself is not advanced.
+    ///
+    /// Every value is at least the corresponding value of `influence_map`, since changes can
+    /// not spread faster than the light cone.
+    ///
+    /// # Arguments
+    /// * `origin` - The `(row, column)` of the cell to toggle.
+    /// * `generations` - The number of generations to simulate.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - The first generation each cell differed, in row-major order (indexed
+    ///   by `row * columns + column`). Cells that never differed within the given number of
+    ///   generations are `u8::MAX`, and larger generations are capped at `u8::MAX`.
+    /// * `Err(String)` - An error message if the origin is outside of the grid.
+    pub fn actual_influence_map(
+        &self,
+        origin: (u16, u16),
+        generations: u128,
+    ) -> Result<Vec<u8>, String> {
+        self.check_bounds(origin.0, origin.1)?;
+        let mut original: Simulation = self.headless_clone();
+        let mut perturbed: Simulation = self.headless_clone();
+        let origin_cell: Cell = Cell::new(ALIVE, origin.0, origin.1);
+        if !perturbed.generation.remove(&origin_cell) {
+            perturbed.generation.insert(origin_cell);
+        }
+        let columns: usize = self.columns as usize;
+        let mut map: Vec<u8> = vec![u8::MAX; self.rows as usize * columns];
+        let mut generation: u128 = 0;
+        loop {
+            for cell in original
+                .generation
+                .symmetric_difference(&perturbed.generation)
+            {
+                let index: usize = cell.row as usize * columns + cell.column as usize;
+                if map[index] == u8::MAX {
+                    map[index] = generation.min(u8::MAX as u128) as u8;
+                }
+            }
+            if generation == generations {
+                break;
+            }
+            original.generation = original.next_generation();
+            perturbed.generation = perturbed.next_generation();
+            generation += 1;
+        }
+        Ok(map)
+    }
+
+    /// Returns an error if the given row and column are outside of the grid.
+    pub(crate) fn check_bounds(&self, row: u16, column: u16) -> Result<(), String> {
+        if row >= self.rows || column >= self.columns {
+            return Err(format!(
+                "The cell at ({}, {}) is outside of the {}x{} grid",
+                row, column, self.rows, self.columns
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the region from `(r1, c1)` to `(r2, c2)` (inclusive) is empty or is
+    /// not entirely within the grid.
+    pub(crate) fn check_region(&self, r1: u16, c1: u16, r2: u16, c2: u16) -> Result<(), String> {
+        if r1 > r2 || c1 > c2 {
+            return Err(format!(
+                "The region from ({}, {}) to ({}, {}) is empty",
+                r1, c1, r2, c2
+            ));
+        }
+        if r2 >= self.rows || c2 >= self.columns {
+            return Err(format!(
+                "The region from ({}, {}) to ({}, {}) exceeds the {}x{} grid",
+                r1, c1, r2, c2, self.rows, self.columns
+            ));
+        }
+        Ok(())
+    }
+
+    /// Flips the cell at the given row and column between alive and dead.
+    ///
+    /// # Description
+    /// The cell is removed from the current generation if it is alive, or added to it if it is
+    /// dead. If the simulation is set to display in a window, the current generation is redrawn
+    /// afterwards.
+    ///
+    /// # Arguments
+    /// * `row` - The row of the cell to flip.
+    /// * `column` - The column of the cell to flip.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the cell was flipped.
+    /// * `Err(String)` - An error message if the position is outside of the grid.
+    pub fn toggle_cell(&mut self, row: u16, column: u16) -> Result<(), String> {
+        self.check_bounds(row, column)?;
+        let cell: Cell = Cell::new(ALIVE, row, column);
+        if !self.generation.remove(&cell) {
+            self.generation.insert(cell);
+        }
+        if self.display {
+            self.draw_generation();
+        }
+        Ok(())
+    }
+
+    /// Makes the cell at the given row and column alive, redrawing the current generation if
+    /// the simulation is set to display in a window.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the cell is now alive.
+    /// * `Err(String)` - An error message if the position is outside of the grid.
+    pub fn set_cell_alive(&mut self, row: u16, column: u16) -> Result<(), String> {
+        self.check_bounds(row, column)?;
+        self.generation.insert(Cell::new(ALIVE, row, column));
+        if self.display {
+            self.draw_generation();
+        }
+        Ok(())
+    }
+
+    /// Makes the cell at the given row and column dead, redrawing the current generation if the
+    /// simulation is set to display in a window.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the cell is now dead.
+    /// * `Err(String)` - An error message if the position is outside of the grid.
+    pub fn set_cell_dead(&mut self, row: u16, column: u16) -> Result<(), String> {
+        self.check_bounds(row, column)?;
+        self.generation.remove(&Cell::new(ALIVE, row, column));
+        if self.display {
+            self.draw_generation();
+        }
+        Ok(())
+    }
+
+    /// Makes the cell at the given row and column alive or dead (see `set_cell_alive` and
+    /// `set_cell_dead`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(3)
+    ///     .width(3)
+    ///     .seed("-*--*--*-")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Killing the middle of the blinker leaves two cells that die the next generation
+    /// simulation.set_cell(1, 1, false).unwrap();
+    /// assert_eq!(simulation.is_cell_alive(1, 1), Ok(false));
+    /// simulation.simulate_generation();
+    /// assert_eq!(simulation.alive_count(), 0);
+    ///
+    /// simulation.toggle_cell(2, 2).unwrap();
+    /// assert_eq!(simulation.is_cell_alive(2, 2), Ok(true));
+    /// assert!(simulation.set_cell(3, 0, true).is_err());
+    /// assert!(simulation.is_cell_alive(0, 3).is_err());
+    /// ```
+    pub fn set_cell(&mut self, row: u16, column: u16, alive: bool) -> Result<(), String> {
+        if alive {
+            self.set_cell_alive(row, column)
+        } else {
+            self.set_cell_dead(row, column)
+        }
+    }
+
+    /// Returns whether the cell at the given row and column of the current generation is alive.
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - Whether the cell is alive.
+    /// * `Err(String)` - An error message if the position is outside of the grid.
+    pub fn is_cell_alive(&self, row: u16, column: u16) -> Result<bool, String> {
+        self.check_bounds(row, column)?;
+        Ok(self.generation.contains(&Cell::new(ALIVE, row, column)))
+    }
+
+    /// Makes every cell at the given positions alive or dead.
+    ///
+    /// # Description
+    /// Every position is checked before any cell is changed, so either all of the cells are
+    /// set or none of them are. Duplicate positions are allowed. If the simulation is set to
+    /// display in a window, the current generation is redrawn once after every cell has been
+    /// set.
+    ///
+    /// # Arguments
+    /// * `positions` - The `(row, column)` positions of the cells to set.
+    /// * `alive` - Whether the cells are made alive (`true`) or dead (`false`).
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the cells were set.
+    /// * `Err(String)` - An error message if any of the positions is outside of the grid.
+    pub fn set_cells(&mut self, positions: &[(u16, u16)], alive: bool) -> Result<(), String> {
+        for (row, column) in positions {
+            self.check_bounds(*row, *column)?;
+        }
+        for (row, column) in positions {
+            let cell: Cell = Cell::new(ALIVE, *row, *column);
+            if alive {
+                self.generation.insert(cell);
+            } else {
+                self.generation.remove(&cell);
+            }
+        }
+        if self.display {
+            self.draw_generation();
+        }
+        Ok(())
+    }
+
+    /// Makes every cell at the given positions alive (see `set_cells`).
+    pub fn set_cells_alive(&mut self, positions: &[(u16, u16)]) -> Result<(), String> {
+        self.set_cells(positions, true)
+    }
+
+    /// Makes every cell at the given positions dead (see `set_cells`).
+    pub fn set_cells_dead(&mut self, positions: &[(u16, u16)]) -> Result<(), String> {
+        self.set_cells(positions, false)
+    }
+
+    /// Prints the current generation to the console the same way it is printed while
+    /// simulating, whether or not the simulation is set to print.
+    pub fn print_current_generation(&self) {
+        println!("{}", self)
+    }
+
+    /// Prints the simulation's seed to the console as a grid, with the characters set with
+    /// `SimulationBuilder::render_chars`, whether or not the simulation is set to print.
+    ///
+    /// # Arguments
+    /// * `header` - Whether to print a `"SEED"` line before the grid, as the seed is printed
+    ///   while simulating. `None` prints the header.
+    pub fn print_seed_generation(&self, header: Option<bool>) {
+        if header.unwrap_or(true) {
+            println!("SEED");
+        }
+        println!("{}", self.seed_grid())
+    }
+
+    /// Renders the simulation's seed as a grid with the characters set with
+    /// `SimulationBuilder::render_chars`, ending each row with a newline (see
+    /// `print_seed_generation`).
+    pub(crate) fn seed_grid(&self) -> String {
+        let (alive_char, dead_char) = self.render_chars;
+        let characters: Vec<char> = self
+            .seed
+            .chars()
+            .map(|character| {
+                if character == ALIVE_CHAR {
+                    alive_char
+                } else {
+                    dead_char
+                }
+            })
+            .collect();
+        characters
+            .chunks(self.columns as usize)
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+
+    /// Returns a grid showing only the cells that changed since the most recently saved
+    /// generation.
+    ///
+    /// # Description
+    /// This function compares the current generation against the last generation in the save
+    /// history and renders one line per row, where cells that were born are shown as `'+'`,
+    /// cells that died are shown as `'-'`, and unchanged cells are shown as `'·'`.
+    ///
+    /// # Returns
+    /// The rendered difference grid, or `None` if the save history is empty.
+    pub fn generation_diff_string_from_last(&self) -> Option<String> {
+        let previous_generation: &HashSet<Cell> = self.save_history.last()?;
+        let mut diff_string: String = String::new();
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let cell: Cell = Cell::new(ALIVE, row, column);
+                let alive_now: bool = self.generation.contains(&cell);
+                let alive_before: bool = previous_generation.contains(&cell);
+                diff_string.push(match (alive_before, alive_now) {
+                    (false, true) => BORN_CHAR,
+                    (true, false) => DIED_CHAR,
+                    _ => UNCHANGED_CHAR,
+                });
+            }
+            diff_string.push('\n');
+        }
+        Some(diff_string)
+    }
+
+    /// Prints the cells that changed since the most recently saved generation, or nothing if
+    /// the save history is empty.
+    pub fn print_generation_diff_from_last(&self) {
+        if let Some(diff_string) = self.generation_diff_string_from_last() {
+            println!("{}", diff_string)
+        }
+    }
+
+    /// Returns the string representation of the current generation.
+    pub fn generation_string(&self) -> String {
+        string_from_generation(self.generation.clone(), self.rows, self.columns)
+    }
+
+    /// Returns the string representation of the current generation with a newline after every
+    /// row, which can be passed back to `SimulationBuilder::seed` without setting the number of
+    /// rows or columns.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .height(2)
+    ///     .width(3)
+    ///     .seed("*-*-*-")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(simulation.generation_string_pretty(), "*-*\n-*-\n");
+    ///
+    /// let mut copy: Simulation = SimulationBuilder::new()
+    ///     .seed(&simulation.generation_string_pretty())
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!((copy.height(), copy.width()), (2, 3));
+    /// assert_eq!(copy.generation_string(), simulation.generation_string());
+    /// ```
+    pub fn generation_string_pretty(&self) -> String {
+        let generation_string: String = self.generation_string();
+        let characters: Vec<char> = generation_string.chars().collect();
+        characters
+            .chunks(self.columns as usize)
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+
+    /// Renders the current generation as lines of characters, one line per row of the grid.
+    ///
+    /// # Description
+    /// Unlike `generation_string`, which is a single line of `'*'` and `'-'` characters meant
+    /// to be read back as a seed, this is meant to be read by people. Block characters such as
+    /// `'█'` make the shape of a generation much easier to see in a terminal than `'*'`.
+    ///
+    /// The simulation's `Display` output uses this with the characters set with
+    /// `SimulationBuilder::render_chars`, after its `"SEED"` or iteration header.
+    ///
+    /// # Arguments
+    /// * `chars` - The `(alive, dead)` characters to render cells with, or `None` to use
+    ///   `BLOCK_ALIVE_CHAR` (`'█'`) and `BLOCK_DEAD_CHAR` (`'·'`).
+    ///
+    /// # Returns
+    /// A `String` with a line for each row of the grid, each ending with a newline.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .height(3)
+    ///     .width(3)
+    ///     .seed("-*--*--*-")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(simulation.render_generation_as_block_chars(None), "·█·\n·█·\n·█·\n");
+    /// assert_eq!(
+    ///     simulation.render_generation_as_block_chars(Some(('▓', '░'))),
+    ///     "░▓░\n░▓░\n░▓░\n"
+    /// );
+    /// ```
+    pub fn render_generation_as_block_chars(&self, chars: Option<(char, char)>) -> String {
+        let (alive_char, dead_char) = chars.unwrap_or((BLOCK_ALIVE_CHAR, BLOCK_DEAD_CHAR));
+        let mut rendered: String = String::new();
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                rendered.push(if self.get_cell(row, column).is_alive() {
+                    alive_char
+                } else {
+                    dead_char
+                });
+            }
+            rendered.push('\n');
+        }
+        rendered
+    }
+
+    /// Renders the current generation in the plaintext (`.cells`) format.
+    ///
+    /// # Description
+    /// Each row of the grid is written on its own line, with `'O'` for alive cells and `'.'` for
+    /// dead cells. Every row is written at the full width of the grid, so the dimensions of the
+    /// grid are preserved when the output is read back with `generation_from_plaintext`.
+    ///
+    /// If a name is given it is written first as a `!Name: <name>` line, followed by a `!` comment
+    /// line for each line of the description.
+    ///
+    /// # Arguments
+    /// * `name` - The optional name of the pattern.
+    /// * `description` - The optional description of the pattern, which may span several lines.
+    pub fn generation_plaintext(&self, name: Option<&str>, description: Option<&str>) -> String {
+        let mut plaintext: String = String::new();
+        if let Some(name) = name {
+            plaintext += &format!("!Name: {}\n", name);
+        }
+        if let Some(description) = description {
+            for line in description.lines() {
+                plaintext += &format!("!{}\n", line);
+            }
+        }
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                plaintext.push(match self.get_cell(row, column).is_alive() {
+                    true => PLAINTEXT_ALIVE_CHAR,
+                    false => PLAINTEXT_DEAD_CHAR,
+                });
+            }
+            plaintext.push('\n');
+        }
+        plaintext
+    }
+
+    /// Exports a range of generations as numbered text files for building animations.
+    ///
+    /// # Description
+    /// This function treats the save history followed by the current generation as a sequence
+    /// of frames, where frame 0 is the oldest saved generation. Each frame within `step_range`
+    /// is written to its own file in `dir` containing its string representation, named
+    /// `frame_0000.txt`, `frame_0001.txt`, and so on in the order they were exported.
+    ///
+    /// Frames in the range that do not exist (beyond the end of the save history and current
+    /// generation) are skipped. The directory is created if it does not already exist.
+    ///
+    /// With the `png` feature, each frame is instead saved as an image named `frame_0000.png`,
+    /// `frame_0001.png`, and so on, drawn the same way `save_generation_as_png` draws the
+    /// current generation.
+    ///
+    /// # Arguments
+    /// * `dir` - The directory to write the frame files into.
+    /// * `step_range` - The range of frame indices to export.
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The number of frame files written.
+    /// * `Err(std::io::Error)` - An error if the directory or any of the files could not be
+    ///   written, or, with the `png` feature, if the cell width or height is 0.
+    pub fn export_animation_frames(
+        &self,
+        dir: &Path,
+        step_range: Range<u128>,
+    ) -> Result<usize, std::io::Error> {
+        create_dir_all(dir)?;
+        let mut written: usize = 0;
+        for frame in self.animation_frames(step_range) {
+            #[cfg(feature = "png")]
+            self.save_frame_as_png(&frame, &dir.join(format!("frame_{:04}.png", written)))?;
+            #[cfg(not(feature = "png"))]
+            std::fs::write(
+                dir.join(format!("frame_{:04}.txt", written)),
+                string_from_generation(frame, self.rows, self.columns),
+            )?;
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    /// Returns the frames within `step_range` of the save history followed by the current
+    /// generation, where frame 0 is the oldest saved generation (see
+    /// `export_animation_frames`).
+    pub(crate) fn animation_frames(
+        &self,
+        step_range: Range<u128>,
+    ) -> impl Iterator<Item = HashSet<Cell>> + '_ {
+        // The saved generations are rebuilt one at a time, instead of all at once
+        self.save_history
+            .iter()
+            .chain(std::iter::once(self.generation.clone()))
+            .skip(step_range.start.try_into().unwrap_or(usize::MAX))
+            .take(
+                (step_range.end.saturating_sub(step_range.start))
+                    .try_into()
+                    .unwrap_or(usize::MAX),
+            )
+    }
+}
+
+/// Moves a generation so that the top-left corner of its bounding box is at `(0, 0)`.
+///
+/// # Returns
+/// The original top-left corner of the bounding box and the moved cell positions, or `None`
+/// if the generation has no alive cells.
+fn normalize_generation(generation: &HashSet<Cell>) -> Option<NormalizedGeneration> {
+    let minimum_row: u16 = generation.iter().map(|cell| cell.row).min()?;
+    let minimum_column: u16 = generation.iter().map(|cell| cell.column).min()?;
+    let cells: HashSet<(u16, u16)> = generation
+        .iter()
+        .map(|cell| (cell.row - minimum_row, cell.column - minimum_column))
+        .collect();
+    Some(((minimum_row, minimum_column), cells))
+}
+
+/// Crops a generation to a bounding box, moving its top-left corner to `(0, 0)`.
+///
+/// # Arguments
+/// * `generation` - The generation to crop.
+/// * `bounding_box` - The `(min_row, min_column, max_row, max_column)` box to crop to
+///   (inclusive), such as the one from `Simulation::bounding_box`.
+///
+/// # Returns
+/// The cells within the box moved so the box starts at `(0, 0)` (cells outside of the box are
+/// left out), along with the number of rows and columns in the box. The result can be written
+/// as a compact seed with `string_from_generation`.
+///
+/// # Example
+/// ```rust
+/// use simple_game_of_life::simulation::{crop_generation, string_from_generation, Simulation};
+/// use simple_game_of_life::simulation_builder::SimulationBuilder;
+///
+/// let simulation: Simulation = SimulationBuilder::new()
+///     .height(10)
+///     .width(10)
+///     .seed_named("glider")
+///     .build()
+///     .unwrap();
+///
+/// let (cells, rows, columns) =
+///     crop_generation(&simulation.generation(), simulation.bounding_box().unwrap());
+/// assert_eq!((rows, columns), (3, 3));
+/// assert_eq!(string_from_generation(cells, rows, columns), "-*---****");
+/// ```
+pub fn crop_generation(
+    generation: &HashSet<Cell>,
+    bounding_box: (u16, u16, u16, u16),
+) -> (HashSet<Cell>, u16, u16) {
+    let (min_row, min_column, max_row, max_column) = bounding_box;
+    let cells: HashSet<Cell> = generation
+        .iter()
+        .filter(|cell| {
+            (min_row..=max_row).contains(&cell.row)
+                && (min_column..=max_column).contains(&cell.column)
+        })
+        .map(|cell| Cell::new(ALIVE, cell.row - min_row, cell.column - min_column))
+        .collect();
+    (
+        cells,
+        max_row.saturating_sub(min_row) + 1,
+        max_column.saturating_sub(min_column) + 1,
+    )
+}
+
+/// Returns the Hamming distance between two generations (the number of cells that are alive in
+/// exactly one of them).
+pub(crate) fn hamming_distance(generation_a: &HashSet<Cell>, generation_b: &HashSet<Cell>) -> u64 {
+    generation_a.symmetric_difference(generation_b).count() as u64
+}
+
+/// Converts a string seed into a `HashSet` of `Cell` instances.
+///
+/// # Description
+/// This function takes a string seed representation of a generation and converts it into a
+/// `HashSet` of `Cell` instances. The string seed should consist of the characters `'*'`
+/// (alive) and `'-'` (dead), representing the state of each cell in the generation. The other
+/// `SeedAlphabet`s, `'1'` and `'0'` or `'O'` and `'.'`, are also accepted. The alphabet is the
+/// one the seed's first character belongs to, and every other character must belong to the
+/// same alphabet.
+///
+/// Whitespace (including newlines) is ignored, so a seed can be written with one line per row.
+/// This function iterates through each remaining character in the seed string and creates a
+/// `Cell` instance for each alive cell, with the appropriate row and column indices based on
+/// the position of the character in the string and the provided number of columns.
+///
+/// If the seed string does not have exactly `rows * columns` cells, contains any characters
+/// outside of the seed alphabets, or mixes alphabets, an error is returned, so every cell is
+/// within the grid.
+///
+/// The resulting `HashSet` of `Cell` instances represents the generation specified by the seed
+/// string.
+///
+/// # Arguments
+/// * `seed` - A string representation of the generation, where `'*'` represents an alive cell
+///   and `'-'` represents a dead cell.
+/// * `rows` - The number of rows in the generation grid.
+/// * `columns` - The number of columns in the generation grid, used to determine the row and
+///   column indices of each cell from its position in the seed string.
+///
+/// # Returns
+/// * `Ok(HashSet<Cell>)` - A `HashSet` containing `Cell` instances representing the alive cells
+///   in the generation specified by the seed string.
+/// * `Err(String)` - An error message if the seed string is the wrong length or contains
+///   invalid characters.
+///
+/// # Example
+/// ```rust
+/// use simple_game_of_life::simulation::generation_from_string;
+///
+/// let symbols = generation_from_string(String::from("*--*"), 2, 2).unwrap();
+/// let binary = generation_from_string(String::from("10\n01"), 2, 2).unwrap();
+/// assert!(symbols == binary);
+///
+/// // The error names the first character that is not in the seed's alphabet
+/// match generation_from_string(String::from("O-.O"), 2, 2) {
+///     Err(error) => assert!(error.contains("'-' at position 1")),
+///     Ok(_) => panic!("the seed mixes alphabets"),
+/// }
+/// ```
+pub fn generation_from_string(
+    seed: String,
+    rows: u16,
+    columns: u16,
+) -> Result<HashSet<Cell>, String> {
+    let values: Vec<bool> = seed_values(&seed)?;
+    let area: usize = rows as usize * columns as usize;
+    if values.len() != area {
+        return Err(format!(
+            "The provided seed is {} cells long, but a {}x{} grid has {} cells",
+            values.len(),
+            rows,
+            columns,
+            area
+        ));
+    }
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .filter(|(_, alive)| *alive)
+        .map(|(index, _)| {
+            Cell::new(
+                ALIVE,
+                (index / columns as usize) as u16,
+                (index % columns as usize) as u16,
+            )
+        })
+        .collect())
+}
+
+/// Reads whether each cell of a seed string is alive, ignoring whitespace.
+///
+/// # Returns
+/// * `Ok(Vec<bool>)` - Whether each cell is alive, in the order of the seed.
+/// * `Err(String)` - An error message naming the character and its position (counting every
+///   character of the seed) if a character is not in any `SeedAlphabet`, or is not in the same
+///   alphabet as the first character.
+fn seed_values(seed: &str) -> Result<Vec<bool>, String> {
+    let mut alphabet: Option<SeedAlphabet> = None;
+    let mut values: Vec<bool> = Vec::new();
+    for (position, value) in seed.chars().enumerate() {
+        if value.is_whitespace() {
+            continue;
+        }
+        let value_alphabet: SeedAlphabet = match SeedAlphabet::of(value) {
+            Some(value_alphabet) => value_alphabet,
+            None => {
+                return Err(format!(
+                    "Unexpected seed character of \'{}\' at position {}, seeds must only contain \'{}\' or \'{}\', \'{}\' or \'{}\', or \'{}\' or \'{}\'",
+                    value,
+                    position,
+                    DEAD_CHAR,
+                    ALIVE_CHAR,
+                    SeedAlphabet::Binary.dead_char(),
+                    SeedAlphabet::Binary.alive_char(),
+                    PLAINTEXT_DEAD_CHAR,
+                    PLAINTEXT_ALIVE_CHAR
+                ));
+            }
+        };
+        let seed_alphabet: SeedAlphabet = *alphabet.get_or_insert(value_alphabet);
+        if value_alphabet != seed_alphabet {
+            return Err(format!(
+                "Unexpected seed character of \'{}\' at position {}, the seed started with \'{}\' and \'{}\' but mixes in another alphabet",
+                value,
+                position,
+                seed_alphabet.alive_char(),
+                seed_alphabet.dead_char()
+            ));
+        }
+        values.push(value == seed_alphabet.alive_char());
+    }
+    Ok(values)
+}
+
+/// Finds the number of rows and columns of a seed written with one line per row.
+///
+/// # Description
+/// Blank lines are skipped, and whitespace within a line is ignored. The number of rows is the
+/// number of lines, and the number of columns is the number of cells on each line, which must
+/// be the same for every line.
+///
+/// # Arguments
+/// * `seed` - The seed, with a newline between rows.
+/// * `rows` - The number of rows that was set, if any.
+/// * `columns` - The number of columns that was set, if any.
+///
+/// # Returns
+/// * `Ok((u16, u16))` - The number of rows and columns of the seed.
+/// * `Err(String)` - An error message if the lines have different lengths, there are too many
+///   rows or columns, or the seed does not match the number of rows or columns that was set.
+pub(crate) fn seed_grid_dimensions(
+    seed: &str,
+    rows: Option<u16>,
+    columns: Option<u16>,
+) -> Result<(u16, u16), String> {
+    let lines: Vec<usize> = seed
+        .lines()
+        .map(|line| line.chars().filter(|value| !value.is_whitespace()).count())
+        .filter(|length| *length > 0)
+        .collect();
+    let line_length: usize = lines.first().copied().unwrap_or(0);
+    if let Some(line) = lines.iter().position(|length| *length != line_length) {
+        return Err(format!(
+            "Row {} of the provided seed has {} cells, but the first row has {}",
+            line + 1,
+            lines[line],
+            line_length
+        ));
+    }
+    let (seed_rows, seed_columns): (u16, u16) =
+        match (u16::try_from(lines.len()), u16::try_from(line_length)) {
+            (Ok(seed_rows), Ok(seed_columns)) => (seed_rows, seed_columns),
+            _ => {
+                return Err(format!(
+                "The provided seed has {} rows of {} cells, which is more than the maximum of {}",
+                lines.len(),
+                line_length,
+                u16::MAX
+            ));
+            }
+        };
+    if let Some(rows) = rows.filter(|rows| *rows != seed_rows) {
+        return Err(format!(
+            "The provided seed has {} rows, but {} rows were set",
+            seed_rows, rows
+        ));
+    }
+    if let Some(columns) = columns.filter(|columns| *columns != seed_columns) {
+        return Err(format!(
+            "The provided seed has {} columns, but {} columns were set",
+            seed_columns, columns
+        ));
+    }
+    Ok((seed_rows, seed_columns))
+}
+
+/// Rewrites a seed string in any `SeedAlphabet` (with any whitespace) as a seed string of
+/// `'*'` and `'-'` characters without whitespace.
+pub(crate) fn normalize_seed(seed: &str) -> Result<String, String> {
+    Ok(seed_values(seed)?
+        .into_iter()
+        .map(|alive| if alive { ALIVE_CHAR } else { DEAD_CHAR })
+        .collect())
+}
+
+/// Parses a pattern in the plaintext (`.cells`) format.
+///
+/// # Description
+/// Lines starting with `'!'` are comments and are skipped. Every other line is a row of the
+/// grid, where `'O'` (or `'*'`) represents an alive cell and `'.'` (or `'-'`) represents a dead
+/// cell. The number of rows is the number of rows in the text and the number of columns is the
+/// length of the longest row; shorter rows (including empty ones) are padded with dead cells.
+/// Empty rows at the end of the text are ignored.
+///
+/// # Arguments
+/// * `text` - The contents of a `.cells` file.
+///
+/// # Returns
+/// * `Ok((u16, u16, HashSet<Cell>))` - The number of rows, the number of columns, and the alive
+///   cells of the pattern.
+/// * `Err(String)` - An error message if the text contains an invalid character, has no rows,
+///   or is too large.
+pub fn generation_from_plaintext(text: &str) -> Result<(u16, u16, HashSet<Cell>), String> {
+    let mut lines: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.starts_with('!'))
+        .map(|line| line.trim_end_matches('\r'))
+        .collect();
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    if lines.is_empty() {
+        return Err(String::from("The plaintext pattern has no rows"));
+    }
+    let columns: usize = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    if lines.len() > u16::MAX as usize || columns > u16::MAX as usize {
+        return Err(format!(
+            "The plaintext pattern of {} rows and {} columns is too large",
+            lines.len(),
+            columns
+        ));
+    }
+    let mut generation: HashSet<Cell> = HashSet::new();
+    for (row, line) in lines.iter().enumerate() {
+        for (column, value) in line.chars().enumerate() {
+            match value {
+                PLAINTEXT_ALIVE_CHAR | 'o' | ALIVE_CHAR => {
+                    generation.insert(Cell::new(ALIVE, row as u16, column as u16));
+                }
+                PLAINTEXT_DEAD_CHAR | DEAD_CHAR => {}
+                _ => {
+                    return Err(format!(
+                        "Unexpected plaintext character of \'{}\' on row {}, column {}, rows must only contain \'{}\' or \'{}\'",
+                        value,
+                        row + 1,
+                        column + 1,
+                        PLAINTEXT_DEAD_CHAR,
+                        PLAINTEXT_ALIVE_CHAR
+                    ));
+                }
+            }
+        }
+    }
+    Ok((lines.len() as u16, columns as u16, generation))
+}
+
+/// Parses a pattern in the RLE format into a generation for a grid with the given number of
+/// columns.
+///
+/// # Description
+/// The pattern is decoded with the same parser as `patterns::from_golly_clipboard`, so the
+/// standard RLE format is supported: an optional count prefix before each tag, `o` for alive
+/// cells, `b` for dead cells, `$` to end a row, and `!` to end the pattern (such as
+/// `3o$2bo$bo!` for a glider). Optional header lines starting with `#` (comments) or `x =`
+/// (dimensions and rule) are skipped.
+///
+/// The pattern is placed in the top-left corner of the grid, producing the same kind of
+/// `HashSet` as `generation_from_string`.
+///
+/// # Arguments
+/// * `rle` - The RLE string to parse.
+/// * `columns` - The number of columns in the generation grid.
+///
+/// # Returns
+/// * `Ok(HashSet<Cell>)` - A `HashSet` containing `Cell` instances representing the alive cells
+///   of the pattern.
+/// * `Err(String)` - An error message if the RLE string is malformed or the pattern is wider
+///   than the grid.
+pub fn generation_from_rle(rle: &str, columns: u16) -> Result<HashSet<Cell>, String> {
+    let (_, pattern_columns, generation) = rle_pattern(rle)?;
+    if pattern_columns > columns {
+        return Err(format!(
+            "The RLE pattern is {} columns wide, which does not fit within {} columns",
+            pattern_columns, columns
+        ));
+    }
+    Ok(generation)
+}
+
+/// Decodes an RLE string into its number of rows, number of columns, and alive cells.
+fn rle_pattern(rle: &str) -> Result<(u16, u16, HashSet<Cell>), String> {
+    let (rows, columns, cells) = decode_rle(rle)?;
+    let generation: HashSet<Cell> = cells
+        .into_iter()
+        .map(|(row, column)| Cell::new(ALIVE, row, column))
+        .collect();
+    Ok((rows, columns, generation))
+}
+
+/// Creates a seed string for a grid from a pattern in the plaintext (`.cells`) format, placed
+/// in the top-left corner (see `pattern_seed`).
+pub(crate) fn plaintext_seed(
+    plaintext: &str,
+    rows: Option<u16>,
+    columns: Option<u16>,
+) -> Result<(u16, u16, String), String> {
+    pattern_seed(
+        "plaintext",
+        generation_from_plaintext(plaintext)?,
+        rows,
+        columns,
+    )
+}
+
+/// Creates a seed string for a grid from a pattern in the RLE format, placed in the top-left
+/// corner (see `pattern_seed`).
+pub(crate) fn rle_seed(
+    rle: &str,
+    rows: Option<u16>,
+    columns: Option<u16>,
+) -> Result<(u16, u16, String), String> {
+    pattern_seed("RLE", rle_pattern(rle)?, rows, columns)
+}
+
+/// Creates a seed string for a grid with a parsed pattern placed in the top-left corner.
+///
+/// # Description
+/// If the number of rows or columns is not given, the grid is made exactly as large as the
+/// pattern in that dimension.
+///
+/// # Arguments
+/// * `format` - The name of the format the pattern was parsed from, used in error messages.
+/// * `pattern` - The number of rows, the number of columns, and the alive cells of the pattern.
+/// * `rows` - The number of rows in the grid, if it was set.
+/// * `columns` - The number of columns in the grid, if it was set.
+///
+/// # Returns
+/// * `Ok((u16, u16, String))` - The number of rows, the number of columns, and the seed string.
+/// * `Err(String)` - An error message if the pattern does not fit within the grid.
+fn pattern_seed(
+    format: &str,
+    pattern: (u16, u16, HashSet<Cell>),
+    rows: Option<u16>,
+    columns: Option<u16>,
+) -> Result<(u16, u16, String), String> {
+    let (pattern_rows, pattern_columns, generation) = pattern;
+    let rows: u16 = rows.unwrap_or(pattern_rows);
+    let columns: u16 = columns.unwrap_or(pattern_columns);
+    if pattern_rows > rows || pattern_columns > columns {
+        return Err(format!(
+            "The {} pattern ({}x{}) does not fit within the {}x{} grid",
+            format, pattern_rows, pattern_columns, rows, columns
+        ));
+    }
+    Ok((
+        rows,
+        columns,
+        string_from_generation(generation, rows, columns),
+    ))
+}
+
+/// Returns where a position on a grid of the given `(rows, columns)` moves to when the grid is
+/// rotated clockwise by the given number of quarter turns.
+fn rotate_position(
+    (row, column): (u16, u16),
+    (rows, columns): (u16, u16),
+    quarter_turns: u8,
+) -> (u16, u16) {
+    let (mut row, mut column, mut rows, mut columns) = (row, column, rows, columns);
+    for _ in 0..quarter_turns % 4 {
+        (row, column) = (column, rows - 1 - row);
+        (rows, columns) = (columns, rows);
+    }
+    (row, column)
+}
+
+/// Converts a `HashSet` of `Cell` instances into a `String` representation.
+///
+/// # Description
+/// This function takes a `HashSet` of `Cell` instances representing a generation and converts
+/// it into a string representation. The resulting string consists of the characters `'*'`
+/// (alive) and `'-'` (dead), representing the state of each cell in the generation.
+///
+/// This function iterates through each row and column of the generation grid and appends the
+/// corresponding character (`'*'` or `'-'`) to the output string based on whether a `Cell`
+/// instance exists in the provided `HashSet` for that row and column.
+///
+/// The resulting string is a compact representation of the generation, and can be used for
+/// storage or display purposes.
+///
+/// # Arguments
+/// * `generation` - A `HashSet` of `Cell` instances representing the alive cells in the
+/// generation.
+/// * `rows` - The number of rows in the generation grid.
+/// * `columns` - The number of columns in the generation grid.
+///
+/// # Returns
+/// A `String` representation of the generation, where `'*'` represents an alive cell and `'-'`
+/// represents a dead cell.
+pub fn string_from_generation(generation: HashSet<Cell>, rows: u16, columns: u16) -> String {
+    string_from_generation_with_alphabet(generation, rows, columns, SeedAlphabet::Symbols)
+}
+
+/// Converts a `HashSet` of `Cell` instances into a `String` representation written with the
+/// given `SeedAlphabet`.
+///
+/// # Description
+/// This is the same as `string_from_generation`, except alive and dead cells are written with
+/// the alphabet's characters. The result can be read back with `generation_from_string`.
+///
+/// # Arguments
+/// * `generation` - A `HashSet` of `Cell` instances representing the alive cells in the
+///   generation.
+/// * `rows` - The number of rows in the generation grid.
+/// * `columns` - The number of columns in the generation grid.
+/// * `alphabet` - The characters to write alive and dead cells with.
+///
+/// # Returns
+/// A `String` representation of the generation with one character per cell.
+pub fn string_from_generation_with_alphabet(
+    generation: HashSet<Cell>,
+    rows: u16,
+    columns: u16,
+    alphabet: SeedAlphabet,
+) -> String {
+    let mut generation_characters: Vec<char> =
+        vec![alphabet.dead_char(); rows as usize * columns as usize];
+    for cell in generation {
+        generation_characters[cell.row as usize * columns as usize + cell.column as usize] =
+            alphabet.alive_char();
+    }
+    generation_characters.iter().collect()
+}
+
+/// Generates a random seed `String` for the specified number of rows and columns with a random alive probability.
+///
+/// # Description
+/// This function creates a random seed string representing a generation with the given number
+/// of rows and columns and a randomly determined probability for a cell to be alive.
+///
 /// The seed string consists of the characters `'*'` (alive) and `'-'` (dead), with the probability
 /// of `'*'` being randomly determined for each call.
 ///
@@ -770,52 +4137,2230 @@ pub fn string_from_generation(generation: HashSet<Cell>, rows: u16, columns: u16
 /// A `String` representation of a randomly generated generation, where `'*'` represents an alive
 /// cell and `'-'` represents a dead cell.
 pub fn random_seed(rows: u16, columns: u16) -> String {
-    let length: usize = (rows * columns).into();
     let mut rng: ThreadRng = thread_rng();
+    random_seed_with_rng(rows, columns, &mut rng)
+}
+
+/// Generates a random seed `String` for the specified number of rows and columns with a random
+/// alive probability, drawing every random number from the given random number generator.
+///
+/// # Description
+/// This is the same as `random_seed`, but a seeded generator (such as `rand::rngs::StdRng`)
+/// makes the seed reproducible: the same generator state always gives the same seed.
+///
+/// # Arguments
+/// * `rows` - The number of rows in the generation grid.
+/// * `columns` - The number of columns in the generation grid.
+/// * `rng` - The random number generator to draw from.
+///
+/// # Returns
+/// A `String` representation of a randomly generated generation, where `'*'` represents an alive
+/// cell and `'-'` represents a dead cell.
+///
+/// # Example
+/// ```rust
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use simple_game_of_life::simulation::random_seed_with_rng;
+///
+/// let seed: String = random_seed_with_rng(10, 10, &mut StdRng::seed_from_u64(42));
+/// assert_eq!(seed, random_seed_with_rng(10, 10, &mut StdRng::seed_from_u64(42)));
+/// ```
+pub fn random_seed_with_rng<R: Rng + ?Sized>(rows: u16, columns: u16, rng: &mut R) -> String {
+    let alive_probability: f64 = Uniform::from(0.0..1.0).sample(rng);
+    random_seed_probability_with_rng(rows, columns, alive_probability, rng)
+}
+
+/// Generates a random seed `String` for the specified number of rows and columns with a given alive probability.
+///
+/// # Description
+/// This function creates a random seed string representing a generation with the given number
+/// of rows and columns and a specified probability for a cell to be alive.
+///
+/// The seed string consists of the characters `'*'` (alive) and `'-'` (dead), with the probability
+/// of `'*'` being determined by the `alive_probability` parameter.
+///
+/// The resulting seed string can be used as input for the `generation_from_string` function to
+/// create a randomly initialized generation.
+///
+/// # Arguments
+/// * `rows` - The number of rows in the generation grid.
+/// * `columns` - The number of columns in the generation grid.
+/// * `alive_probability` - The probability of a cell being alive.
+///
+/// # Returns
+/// A `String` representation of a randomly generated generation, where `'*'` represents an alive
+/// cell and `'-'` represents a dead cell.
+pub fn random_seed_probability(rows: u16, columns: u16, alive_probability: f64) -> String {
+    let mut rng: ThreadRng = thread_rng();
+    random_seed_probability_with_rng(rows, columns, alive_probability, &mut rng)
+}
+
+/// Generates a random seed `String` for the specified number of rows and columns with a given
+/// alive probability, drawing every random number from the given random number generator (see
+/// `random_seed_with_rng`).
+///
+/// # Arguments
+/// * `rows` - The number of rows in the generation grid.
+/// * `columns` - The number of columns in the generation grid.
+/// * `alive_probability` - The probability of a cell being alive.
+/// * `rng` - The random number generator to draw from.
+///
+/// # Returns
+/// A `String` representation of a randomly generated generation, where `'*'` represents an alive
+/// cell and `'-'` represents a dead cell.
+pub fn random_seed_probability_with_rng<R: Rng + ?Sized>(
+    rows: u16,
+    columns: u16,
+    alive_probability: f64,
+    rng: &mut R,
+) -> String {
+    let length: usize = rows as usize * columns as usize;
     let dist = Uniform::from(0.0..1.0);
-    let alive_probability = dist.sample(&mut rng);
     (0..length)
         .map(|_| {
-            if dist.sample(&mut rng) < alive_probability {
+            if dist.sample(rng) < alive_probability {
                 ALIVE_CHAR
             } else {
-                DEAD_CHAR
+                DEAD_CHAR
+            }
+        })
+        .collect()
+}
+
+/// Generates a random seed for the specified number of rows and columns, with the given alive
+/// probability or a random one if it is `None`, drawn from the given random number generator or
+/// `rand::thread_rng` if it is `None`.
+pub(crate) fn draw_random_seed(
+    rows: u16,
+    columns: u16,
+    alive_probability: Option<f64>,
+    rng: Option<&mut StdRng>,
+) -> String {
+    match (alive_probability, rng) {
+        (Some(alive_probability), Some(rng)) => {
+            random_seed_probability_with_rng(rows, columns, alive_probability, rng)
+        }
+        (Some(alive_probability), None) => {
+            random_seed_probability(rows, columns, alive_probability)
+        }
+        (None, Some(rng)) => random_seed_with_rng(rows, columns, rng),
+        (None, None) => random_seed(rows, columns),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::Pattern;
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// Builds a headless simulation from a seed string with the given number of rows.
+    fn build(rows: u16, seed: &str) -> Simulation {
+        SimulationBuilder::new()
+            .height(rows)
+            .seed(seed)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn flip_that_changes_nothing_is_not_saved() {
+        let mut simulation: Simulation = build(4, "-----**--**-----");
+        simulation.flip_horizontal();
+        simulation.flip_vertical();
+        assert_eq!(simulation.iteration(), 0);
+        assert_eq!(simulation.save_history_size(), 0);
+        assert!(!simulation.is_finished());
+    }
+
+    #[test]
+    fn flip_is_an_edit_not_a_generation() {
+        let mut simulation: Simulation = build(5, "-*-----*--***------------");
+        simulation.simulate_generations(2);
+        let generation: String = simulation.generation_string();
+        let births: u64 = simulation.total_births();
+        let deaths: u64 = simulation.total_deaths();
+        let delta: GenerationDelta = simulation.generation_delta().clone();
+        let population_history: Vec<u64> = simulation.population_history();
+
+        simulation.flip_horizontal();
+        assert_ne!(simulation.generation_string(), generation);
+        assert_eq!(simulation.iteration(), 2);
+        assert_eq!(simulation.total_births(), births);
+        assert_eq!(simulation.total_deaths(), deaths);
+        assert_eq!(*simulation.generation_delta(), delta);
+        assert_eq!(simulation.population_history(), population_history);
+        assert!(!simulation.is_finished());
+
+        assert_eq!(simulation.rollback_generations(1), 1);
+        assert_eq!(simulation.generation_string(), generation);
+        assert_eq!(simulation.iteration(), 2);
+        assert_eq!(simulation.population_history(), population_history);
+    }
+
+    #[test]
+    fn edit_at_iteration_zero_can_be_rolled_back() {
+        let mut simulation: Simulation = build(3, "**-------");
+        simulation.flip_vertical();
+        assert_eq!(simulation.generation_string(), "------**-");
+        assert!(simulation.rollback_generation());
+        assert_eq!(simulation.generation_string(), "**-------");
+        assert!(!simulation.rollback_generation());
+    }
+
+    #[test]
+    fn saves_before_an_edit_are_not_cycles() {
+        // A block flipped to the other side of the grid is a new still life, which is only
+        // finished once it has been simulated
+        let mut simulation: Simulation = build(4, "**--**----------");
+        simulation.simulate_generation();
+        assert!(simulation.is_finished());
+        simulation.flip_horizontal();
+        assert!(!simulation.is_finished());
+        assert_eq!(simulation.detect_period(), None);
+        simulation.flip_horizontal();
+        assert!(!simulation.is_finished());
+        assert!(!simulation.is_periodic(1));
+        simulation.simulate_generation();
+        assert!(simulation.is_still());
+    }
+
+    #[test]
+    fn translate_is_an_edit_not_a_generation() {
+        let mut simulation: Simulation =
+            build(6, &format!("{}{}", "-*------*---***---", "-".repeat(18)));
+        let seed: String = simulation.generation_string();
+        simulation.translate(3, 3);
+        assert_eq!(simulation.iteration(), 0);
+        assert_eq!(simulation.total_births(), 0);
+        assert_eq!(simulation.total_deaths(), 0);
+        assert!(simulation.generation_delta().is_empty());
+        assert_eq!(simulation.alive_count(), 5);
+
+        // Translating by a whole loop of a wrapping surface changes nothing
+        let mut ball: Simulation = SimulationBuilder::new()
+            .height(6)
+            .width(6)
+            .surface_ball()
+            .seed(&seed)
+            .build()
+            .unwrap();
+        ball.translate(6, -12);
+        assert_eq!(ball.save_history_size(), 0);
+
+        assert!(simulation.rollback_generation());
+        assert_eq!(simulation.generation_string(), seed);
+    }
+
+    #[test]
+    fn invert_is_an_edit_not_a_generation() {
+        let mut simulation: Simulation = build(3, "*-------*");
+        simulation.invert_region(0, 0, 1, 1).unwrap();
+        simulation.invert_generation();
+        assert_eq!(simulation.generation_string(), "*-*--***-");
+        assert_eq!(simulation.iteration(), 0);
+        assert_eq!(simulation.total_births(), 0);
+        assert!(simulation.generation_delta().is_empty());
+        assert_eq!(simulation.rollback_generations(2), 2);
+        assert_eq!(simulation.generation_string(), "*-------*");
+    }
+
+    #[test]
+    fn region_changes_are_edits_not_generations() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(10)
+            .width(10)
+            .seed_named("glider")
+            .build()
+            .unwrap();
+        simulation.translate(3, 3);
+        simulation.fill_region(0, 0, 1, 1).unwrap();
+        assert_eq!(simulation.total_births(), 0);
+        assert_eq!(simulation.total_deaths(), 0);
+        assert_eq!(simulation.alive_count(), 9);
+        assert_eq!(simulation.iteration(), 0);
+
+        // Clearing an empty region changes nothing, so it is not saved
+        simulation.clear_region(9, 0, 9, 9).unwrap();
+        assert_eq!(simulation.save_history_size(), 2);
+        simulation.clear_region(0, 0, 1, 1).unwrap();
+        assert_eq!(simulation.rollback_generations(3), 3);
+        assert_eq!(simulation.alive_count(), 5);
+    }
+
+    /// Builds a blinker on a 5x5 grid, which is finished after 2 generations.
+    fn blinker() -> Simulation {
+        SimulationBuilder::new()
+            .height(5)
+            .width(5)
+            .seed_named("blinker")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn continuous_generations_stop_when_finished() {
+        let mut simulation: Simulation = blinker();
+        let outcome: SimulationOutcome =
+            simulation.simulate_continuous_generations(Duration::ZERO, true, None);
+        assert_eq!(outcome.stop_reason, StopReason::Finished);
+        assert_eq!(outcome.iterations_run, 2);
+        assert_eq!(simulation.iteration(), 2);
+    }
+
+    #[test]
+    fn continuous_generations_stop_at_maximum_iterations() {
+        let mut simulation: Simulation = blinker();
+        let outcome: SimulationOutcome =
+            simulation.simulate_continuous_generations(Duration::ZERO, false, Some(7));
+        assert_eq!(outcome.stop_reason, StopReason::MaximumIterations);
+        assert_eq!(outcome.iterations_run, 7);
+        assert_eq!(simulation.iteration(), 7);
+
+        // The limit is checked before the finished state, which is only reached later
+        let mut simulation: Simulation = blinker();
+        let outcome: SimulationOutcome =
+            simulation.simulate_continuous_generations(Duration::ZERO, true, Some(1));
+        assert_eq!(outcome.stop_reason, StopReason::MaximumIterations);
+        assert_eq!(outcome.iterations_run, 1);
+
+        let outcome: SimulationOutcome =
+            simulation.simulate_continuous_generations(Duration::ZERO, true, Some(0));
+        assert_eq!(outcome.stop_reason, StopReason::MaximumIterations);
+        assert_eq!(outcome.iterations_run, 0);
+        assert_eq!(simulation.iteration(), 1);
+    }
+
+    #[test]
+    fn continuous_generations_report_elapsed_time() {
+        let mut simulation: Simulation = blinker();
+        let cooldown: Duration = Duration::from_millis(5);
+        let outcome: SimulationOutcome =
+            simulation.simulate_continuous_generations(cooldown, false, Some(3));
+        // The cooldown is only waited between generations
+        assert!(outcome.elapsed >= 2 * cooldown);
+    }
+
+    #[test]
+    fn simulate_until_stops_when_the_predicate_is_satisfied() {
+        let mut simulation: Simulation = blinker();
+        let outcome: SimulationOutcome =
+            simulation.simulate_until(Some(10), Duration::ZERO, |simulation| {
+                simulation.iteration() == 1
+            });
+        assert_eq!(outcome.stop_reason, StopReason::PredicateSatisfied);
+        assert_eq!(outcome.iterations_run, 1);
+
+        // The predicate is checked before the finished state
+        let outcome: SimulationOutcome =
+            simulation.simulate_until(None, Duration::ZERO, |simulation| simulation.is_finished());
+        assert_eq!(outcome.stop_reason, StopReason::PredicateSatisfied);
+        assert_eq!(outcome.iterations_run, 1);
+    }
+
+    #[test]
+    fn observers_are_not_notified_of_edits() {
+        let mut simulation: Simulation = build(3, "**-------");
+        let count: Arc<std::sync::Mutex<u32>> = Arc::new(std::sync::Mutex::new(0));
+        let observed: Arc<std::sync::Mutex<u32>> = Arc::clone(&count);
+        simulation.on_generation(Box::new(move |_| *observed.lock().unwrap() += 1));
+        simulation.flip_vertical();
+        simulation.simulate_generation();
+        assert_eq!(*count.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn rotate_180_is_an_edit_not_a_generation() {
+        let mut simulation: Simulation = build(3, "**-------");
+        simulation.rotate_180();
+        assert_eq!(simulation.generation_string(), "-------**");
+        assert_eq!(simulation.iteration(), 0);
+        assert_eq!(simulation.rollback_generations(1), 1);
+        assert_eq!(simulation.generation_string(), "**-------");
+    }
+
+    #[test]
+    fn square_rotations_are_rolled_back() {
+        let mut simulation: Simulation = build(3, "**-------");
+        simulation.rotate_90().unwrap();
+        assert_eq!(simulation.generation_string(), "--*--*---");
+        simulation.rotate_270().unwrap();
+        assert_eq!(simulation.generation_string(), "**-------");
+        assert_eq!(simulation.rollback_generations(2), 2);
+        assert_eq!(simulation.generation_string(), "**-------");
+    }
+
+    #[test]
+    fn rotating_a_grid_that_is_not_square_clears_the_save_history() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(2)
+            .width(3)
+            .seed("***---")
+            .build()
+            .unwrap();
+        simulation.simulate_generation();
+        simulation.rotate_90().unwrap();
+        assert_eq!((simulation.height(), simulation.width()), (3, 2));
+        assert_eq!(simulation.seed(), "-*-*-*");
+        assert_eq!(simulation.iteration(), 1);
+        assert_eq!(simulation.rollback_generations(1), 0);
+
+        simulation.reset();
+        assert_eq!(simulation.generation_string(), "-*-*-*");
+    }
+
+    #[test]
+    fn async_generations_arrive_in_order_and_the_channel_closes() {
+        let mut expected: Simulation = SimulationBuilder::new()
+            .height(6)
+            .width(6)
+            .seed_named("glider")
+            .surface_ball()
+            .build()
+            .unwrap();
+        let receiver: Receiver<String> = expected
+            .clone()
+            .simulate_generations_async_with_channel(10, Duration::from_millis(1));
+
+        let received: Vec<String> = receiver.iter().collect();
+        assert_eq!(received.len(), 10);
+        for generation in &received {
+            expected.simulate_generation();
+            assert_eq!(*generation, expected.generation_string());
+        }
+        // The iterator only ends once the channel is closed
+        assert!(receiver.recv().is_err());
+        assert_eq!(expected.iteration(), 10);
+    }
+
+    #[test]
+    fn staggered_rollbacks_match_a_straight_run() {
+        let mut staggered: Simulation = SimulationBuilder::new()
+            .height(5)
+            .width(5)
+            .surface_rectangle()
+            .seed("-*-***--*--*-*-***-*-*-*-")
+            .build()
+            .unwrap();
+        let mut straight: Simulation = staggered.clone();
+
+        staggered.simulate_generations(3);
+        assert_eq!(staggered.rollback_generations(2), 2);
+        staggered.simulate_generations(10);
+        assert_eq!(staggered.rollback_generations(4), 4);
+        staggered.simulate_generations(8);
+        straight.simulate_generations(15);
+
+        assert_eq!(staggered.iteration(), 15);
+        assert_eq!(staggered.generation_string(), straight.generation_string());
+    }
+
+    #[test]
+    fn rollbacks_stop_at_the_oldest_save() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(5)
+            .width(5)
+            .seed("-*-***--*--*-*-***-*-*-*-")
+            .maximum_saves(3)
+            .build()
+            .unwrap();
+        simulation.simulate_generations(10);
+        assert_eq!(simulation.rollback_generations(5), 3);
+        assert_eq!(simulation.iteration(), 7);
+    }
+
+    /// Returns the positions of the alive cells in a generation.
+    fn positions(generation: &HashSet<Cell>) -> HashSet<(u16, u16)> {
+        generation
+            .iter()
+            .map(|cell| (cell.row, cell.column))
+            .collect()
+    }
+
+    #[test]
+    fn sparse_and_dense_generations_match_on_random_soups() {
+        let surfaces: [fn(SimulationBuilder) -> SimulationBuilder; 6] = [
+            SimulationBuilder::surface_rectangle,
+            SimulationBuilder::surface_ball,
+            SimulationBuilder::surface_horizontal_loop,
+            SimulationBuilder::surface_vertical_loop,
+            SimulationBuilder::surface_mobius,
+            SimulationBuilder::surface_klein_bottle,
+        ];
+        for (index, surface) in surfaces.into_iter().enumerate() {
+            for rule in ["B3/S23", "R2,C0,M0,S6..9,B7..8"] {
+                for neighborhood in [Neighborhood::Moore, Neighborhood::VonNeumann] {
+                    for alive_probability in [0.01, 0.05, 0.3] {
+                        for rng_seed in 0..3 {
+                            let mut simulation: Simulation =
+                                surface(SimulationBuilder::new().height(24).width(30))
+                                    .rule(rule.parse().unwrap())
+                                    .neighborhood(neighborhood)
+                                    .alive_probability(alive_probability)
+                                    .rng_seed(rng_seed)
+                                    .build()
+                                    .unwrap();
+                            for generation in 0..5 {
+                                assert_eq!(
+                                    positions(&simulation.sparse_next_generation()),
+                                    positions(&simulation.dense_next_generation()),
+                                    "surface {} with {} in the {:?} neighborhood, soup {} of density {}, generation {}",
+                                    index,
+                                    rule,
+                                    neighborhood,
+                                    rng_seed,
+                                    alive_probability,
+                                    generation
+                                );
+                                simulation.simulate_generation();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lone_glider_takes_as_long_on_a_huge_grid() {
+        let time = |size: u16| -> Duration {
+            let mut simulation: Simulation = SimulationBuilder::new()
+                .height(size)
+                .width(size)
+                .surface_rectangle()
+                .seed_fn(|row, column| {
+                    matches!((row, column), (0, 1) | (1, 2) | (2, 0) | (2, 1) | (2, 2))
+                })
+                .maximum_saves(1)
+                .build()
+                .unwrap();
+            let start: Instant = Instant::now();
+            simulation.simulate_generations(100);
+            assert_eq!(simulation.alive_count(), 5);
+            start.elapsed()
+        };
+        let small: Duration = time(50);
+        let huge: Duration = time(2000);
+        // Scanning the 4,000,000 cells of the huge grid would take seconds, so this leaves a wide
+        // margin for noise
+        assert!(
+            huge < small * 4 + Duration::from_millis(100),
+            "{:?} on a 50x50 grid, {:?} on a 2000x2000 grid",
+            small,
+            huge
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "png"))]
+    fn animation_frames_are_exported_as_numbered_text_files() {
+        let seed: &str = "-*--------\
+                          --*-------\
+                          ***-------\
+                          ----------\
+                          ----------\
+                          ----------\
+                          ----------\
+                          ----------\
+                          ----------\
+                          ----------";
+        let mut simulation: Simulation = build(10, seed);
+        simulation.simulate_generations(4);
+        let dir: std::path::PathBuf = std::env::temp_dir().join(format!(
+            "game_of_life_{}_animation_frames",
+            std::process::id()
+        ));
+
+        let written: usize = simulation.export_animation_frames(&dir, 0..5).unwrap();
+        let frames: Vec<String> = (0..5)
+            .map(|frame| std::fs::read_to_string(dir.join(format!("frame_{:04}.txt", frame))))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let extra_frame_exists: bool = dir.join("frame_0005.txt").exists();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(written, 5);
+        assert!(!extra_frame_exists);
+        for frame in &frames {
+            assert_eq!(frame.chars().count(), 10 * 10);
+        }
+        assert_eq!(frames[0], seed);
+        assert_eq!(frames[4], simulation.generation_string());
+    }
+
+    #[test]
+    fn animation_frames_past_the_current_generation_are_skipped() {
+        let mut simulation: Simulation = build(3, "-*--*--*-");
+        simulation.simulate_generations(2);
+        let dir: std::path::PathBuf = std::env::temp_dir().join(format!(
+            "game_of_life_{}_animation_frames_skipped",
+            std::process::id()
+        ));
+
+        let written: usize = simulation.export_animation_frames(&dir, 1..10).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(written, 2);
+    }
+
+    #[test]
+    fn voronoi_regions_partition_the_dead_cells_by_nearest_alive_cell() {
+        for rng_seed in 0..4 {
+            let simulation: Simulation = SimulationBuilder::new()
+                .height(15)
+                .width(20)
+                .alive_probability(0.05)
+                .rng_seed(rng_seed)
+                .build()
+                .unwrap();
+            let sites: Vec<(u16, u16)> = simulation.alive_cells_as_vec();
+            let regions: Vec<Vec<(u16, u16)>> = simulation.alive_cells_as_voronoi_diagram();
+            assert_eq!(regions.len(), sites.len());
+            assert_eq!(
+                regions.iter().map(Vec::len).sum::<usize>() as u64,
+                simulation.dead_count()
+            );
+
+            let squared_distance =
+                |(row, column): (u16, u16), (site_row, site_column): (u16, u16)| {
+                    (row.abs_diff(site_row) as u64).pow(2)
+                        + (column.abs_diff(site_column) as u64).pow(2)
+                };
+            let mut seen: HashSet<(u16, u16)> = HashSet::new();
+            for (site, region) in sites.iter().zip(&regions) {
+                for &cell in region {
+                    assert!(!simulation.get_cell(cell.0, cell.1).is_alive());
+                    assert!(seen.insert(cell), "{:?} is in two regions", cell);
+                    let nearest: u64 = sites
+                        .iter()
+                        .map(|&other| squared_distance(cell, other))
+                        .min()
+                        .unwrap();
+                    assert_eq!(squared_distance(cell, *site), nearest, "{:?}", cell);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn voronoi_ties_go_to_the_first_alive_cell() {
+        let simulation: Simulation = build(1, "*---*");
+        assert_eq!(
+            simulation.alive_cells_as_voronoi_diagram(),
+            vec![vec![(0, 1), (0, 2)], vec![(0, 3)]]
+        );
+    }
+
+    #[test]
+    fn voronoi_diagram_without_alive_cells_is_empty() {
+        let simulation: Simulation = build(3, "---------");
+        assert!(simulation.alive_cells_as_voronoi_diagram().is_empty());
+        assert_eq!(simulation.dead_count(), 9);
+    }
+
+    #[test]
+    fn lazy_generations_are_only_simulated_when_consumed() {
+        let mut simulation: Simulation = build(5, "-------*----*----*-------");
+        let mut generations = simulation.simulate_generations_lazy(1000);
+        let first_three: Vec<String> = (0..3).map(|_| generations.next().unwrap()).collect();
+        drop(generations);
+        assert_eq!(simulation.iteration(), 3);
+        assert_eq!(first_three[2], simulation.generation_string());
+        assert_eq!(
+            first_three,
+            vec![
+                "-----------***-----------",
+                "-------*----*----*-------",
+                "-----------***-----------"
+            ]
+        );
+    }
+
+    #[test]
+    fn lazy_generations_end_after_the_given_number() {
+        let mut simulation: Simulation = build(5, "-------*----*----*-------");
+        assert_eq!(simulation.simulate_generations_lazy(7).count(), 7);
+        assert_eq!(simulation.iteration(), 7);
+        assert_eq!(simulation.simulate_generations_lazy(0).next(), None);
+        assert_eq!(simulation.iteration(), 7);
+    }
+
+    #[test]
+    fn taking_lazy_generations_leaves_the_simulation_at_the_last_one_taken() {
+        let mut simulation: Simulation = build(
+            10,
+            "-*--------\
+                                                    --*-------\
+                                                    ***-------\
+                                                    ----------\
+                                                    ----------\
+                                                    ----------\
+                                                    ----------\
+                                                    ----------\
+                                                    ----------\
+                                                    ----------",
+        );
+        let mut straight: Simulation = build(10, &simulation.seed());
+        let taken: Vec<String> = simulation.simulate_generations_lazy(50).take(4).collect();
+        straight.simulate_generations(4);
+        assert_eq!(taken.len(), 4);
+        assert_eq!(simulation.iteration(), 4);
+        assert_eq!(simulation.generation_string(), straight.generation_string());
+        assert_eq!(taken[3], straight.generation_string());
+    }
+
+    #[test]
+    fn generation_diff_without_history_is_none() {
+        let simulation: Simulation = build(3, "-*--*--*-");
+        assert_eq!(simulation.generation_diff_string_from_last(), None);
+    }
+
+    #[test]
+    fn generation_diff_marks_exactly_the_changed_cells() {
+        let mut simulation: Simulation = build(
+            6,
+            "------\
+                                                   --*---\
+                                                   ---*--\
+                                                   -***--\
+                                                   ------\
+                                                   ------",
+        );
+        for _ in 0..3 {
+            let previous: HashSet<Cell> = simulation.generation.clone();
+            simulation.simulate_generation();
+            let delta: GenerationDelta =
+                GenerationDelta::between(&previous, &simulation.generation);
+            let diff: String = simulation.generation_diff_string_from_last().unwrap();
+            let rows: Vec<Vec<char>> = diff.lines().map(|line| line.chars().collect()).collect();
+            assert_eq!(rows.len(), 6);
+
+            let marked = |symbol: char| -> Vec<(u16, u16)> {
+                (0..6)
+                    .flat_map(|row| (0..6).map(move |column| (row, column)))
+                    .filter(|&(row, column)| rows[row as usize][column as usize] == symbol)
+                    .collect()
+            };
+            let born: Vec<(u16, u16)> = marked(BORN_CHAR);
+            let died: Vec<(u16, u16)> = marked(DIED_CHAR);
+            assert_eq!(born, delta.born);
+            assert_eq!(died, delta.died);
+            assert_eq!(marked(UNCHANGED_CHAR).len(), 36 - born.len() - died.len());
+            assert_eq!(
+                (born.len() + died.len()) as u64,
+                hamming_distance(&previous, &simulation.generation)
+            );
+        }
+    }
+
+    /// Builds a 20x20 ball with a glider in its top-left corner and, optionally, a block in
+    /// its bottom-left corner.
+    fn glider_on_a_ball(with_block: bool, detect_translated_cycles: bool) -> Simulation {
+        let mut rows: Vec<String> = vec![
+            String::from("--*"),
+            String::from("---*"),
+            String::from("-***"),
+        ];
+        rows.resize(20, String::new());
+        if with_block {
+            rows[16] = String::from("-**");
+            rows[17] = String::from("-**");
+        }
+        let seed: String = rows.iter().map(|row| format!("{:-<20}", row)).collect();
+        SimulationBuilder::new()
+            .height(20)
+            .width(20)
+            .seed(&seed)
+            .surface_ball()
+            .detect_translated_cycles(detect_translated_cycles)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn lone_glider_is_a_translated_cycle_after_one_period() {
+        let mut simulation: Simulation = glider_on_a_ball(false, true);
+        for _ in 0..3 {
+            simulation.simulate_generation();
+            assert!(!simulation.is_finished());
+        }
+        simulation.simulate_generation();
+        assert_eq!(
+            simulation.cycle_info(),
+            Some(CycleInfo {
+                period: 4,
+                displacement: (1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn lone_glider_is_not_a_cycle_without_translated_detection() {
+        let mut simulation: Simulation = glider_on_a_ball(false, false);
+        simulation.simulate_generations(8);
+        assert_eq!(simulation.cycle_info(), None);
+    }
+
+    #[test]
+    fn glider_next_to_a_still_life_is_not_a_translated_cycle() {
+        let mut simulation: Simulation = glider_on_a_ball(true, true);
+        for _ in 0..12 {
+            simulation.simulate_generation();
+            assert_eq!(simulation.cycle_info(), None);
+        }
+    }
+
+    #[test]
+    fn still_life_is_a_cycle_without_displacement() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .seed("----\n-**-\n-**-\n----")
+            .detect_translated_cycles(true)
+            .build()
+            .unwrap();
+        simulation.simulate_generation();
+        assert_eq!(
+            simulation.cycle_info(),
+            Some(CycleInfo {
+                period: 1,
+                displacement: (0, 0),
+            })
+        );
+    }
+
+    /// Returns the influence map of an empty 7x9 grid on the given surface as rows of
+    /// generations.
+    fn influence_rows(
+        surface: fn(SimulationBuilder) -> SimulationBuilder,
+        origin: (u16, u16),
+        generations: u128,
+    ) -> Vec<Vec<u8>> {
+        let simulation: Simulation = surface(SimulationBuilder::new().height(7).width(9))
+            .seed(&"-".repeat(63))
+            .build()
+            .unwrap();
+        simulation
+            .influence_map(origin, generations)
+            .unwrap()
+            .chunks(9)
+            .map(|row| row.to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn light_cone_on_a_rectangle_does_not_wrap() {
+        let map: Vec<Vec<u8>> = influence_rows(SimulationBuilder::surface_rectangle, (0, 0), 100);
+        assert_eq!(map[0][0], 0);
+        assert_eq!(map[1][1], 1);
+        assert_eq!(map[6][0], 6);
+        assert_eq!(map[0][8], 8);
+        assert_eq!(map[6][8], 8);
+    }
+
+    #[test]
+    fn light_cone_wraps_around_loops_and_balls() {
+        let ball: Vec<Vec<u8>> = influence_rows(SimulationBuilder::surface_ball, (0, 0), 100);
+        assert_eq!((ball[6][8], ball[6][0], ball[0][8]), (1, 1, 1));
+        assert_eq!((ball[3][4], ball[4][5]), (4, 4));
+
+        let horizontal: Vec<Vec<u8>> =
+            influence_rows(SimulationBuilder::surface_horizontal_loop, (0, 0), 100);
+        assert_eq!(
+            (horizontal[0][8], horizontal[6][0], horizontal[6][8]),
+            (1, 6, 6)
+        );
+
+        let vertical: Vec<Vec<u8>> =
+            influence_rows(SimulationBuilder::surface_vertical_loop, (0, 0), 100);
+        assert_eq!((vertical[6][0], vertical[0][8], vertical[6][8]), (1, 8, 8));
+    }
+
+    #[test]
+    fn light_cone_flips_around_a_mobius_strip() {
+        let map: Vec<Vec<u8>> = influence_rows(SimulationBuilder::surface_mobius, (0, 0), 100);
+        // Going around the strip from the top-left corner arrives at the bottom-right corner
+        assert_eq!(map[6][8], 1);
+        assert_eq!(map[0][8], 6);
+    }
+
+    #[test]
+    fn light_cone_stops_after_the_given_number_of_generations() {
+        let map: Vec<Vec<u8>> = influence_rows(SimulationBuilder::surface_rectangle, (3, 4), 2);
+        assert_eq!(map[3][4], 0);
+        assert_eq!(map[1][2], 2);
+        assert_eq!(map[0][4], u8::MAX);
+        assert_eq!(map[3][8], u8::MAX);
+    }
+
+    #[test]
+    fn light_cone_matches_spreading_on_an_equivalent_custom_surface() {
+        type Wrap = fn(i32, i32, u16, u16) -> Option<(u16, u16)>;
+        /// Flips the row of a position that wraps past the left or right edge.
+        fn flip(row: i32, column: i32, rows: u16, columns: u16) -> (i32, i32) {
+            if column < 0 || column >= columns as i32 {
+                (rows as i32 - 1 - row, column.rem_euclid(columns as i32))
+            } else {
+                (row, column)
             }
-        })
-        .collect()
-}
+        }
+        fn bounded(position: i32, length: u16) -> Option<u16> {
+            (0..length as i32)
+                .contains(&position)
+                .then_some(position as u16)
+        }
+        fn wrapped(position: i32, length: u16) -> Option<u16> {
+            Some(position.rem_euclid(length as i32) as u16)
+        }
+        let surfaces: [(fn(SimulationBuilder) -> SimulationBuilder, Wrap); 6] = [
+            (
+                SimulationBuilder::surface_rectangle,
+                |row, column, rows, columns| Some((bounded(row, rows)?, bounded(column, columns)?)),
+            ),
+            (
+                SimulationBuilder::surface_ball,
+                |row, column, rows, columns| Some((wrapped(row, rows)?, wrapped(column, columns)?)),
+            ),
+            (
+                SimulationBuilder::surface_horizontal_loop,
+                |row, column, rows, columns| Some((bounded(row, rows)?, wrapped(column, columns)?)),
+            ),
+            (
+                SimulationBuilder::surface_vertical_loop,
+                |row, column, rows, columns| Some((wrapped(row, rows)?, bounded(column, columns)?)),
+            ),
+            (
+                SimulationBuilder::surface_mobius,
+                |row, column, rows, columns| {
+                    let (row, column): (i32, i32) = flip(row, column, rows, columns);
+                    Some((bounded(row, rows)?, bounded(column, columns)?))
+                },
+            ),
+            (
+                SimulationBuilder::surface_klein_bottle,
+                |row, column, rows, columns| {
+                    let (row, column): (i32, i32) = flip(row, column, rows, columns);
+                    Some((wrapped(row, rows)?, bounded(column, columns)?))
+                },
+            ),
+        ];
+        for (index, (surface, wrap)) in surfaces.into_iter().enumerate() {
+            for rule in ["B3/S23", "R2,C0,M0,S6..9,B7..8"] {
+                for neighborhood in [Neighborhood::Moore, Neighborhood::VonNeumann] {
+                    let build = |builder: SimulationBuilder| -> Simulation {
+                        builder
+                            .height(7)
+                            .width(9)
+                            .seed(&"-".repeat(63))
+                            .rule(rule.parse().unwrap())
+                            .neighborhood(neighborhood)
+                            .build()
+                            .unwrap()
+                    };
+                    let simulation: Simulation = build(surface(SimulationBuilder::new()));
+                    let custom: Simulation = build(SimulationBuilder::new().surface_custom(wrap));
+                    for origin in [(0, 0), (3, 4), (6, 1), (2, 8)] {
+                        assert_eq!(
+                            simulation.influence_map(origin, 20),
+                            custom.influence_map(origin, 20),
+                            "surface {} with {} in the {:?} neighborhood from {:?}",
+                            index,
+                            rule,
+                            neighborhood,
+                            origin
+                        );
+                    }
+                }
+            }
+        }
+    }
 
-/// Generates a random seed `String` for the specified number of rows and columns with a given alive probability.
-///
-/// # Description
-/// This function creates a random seed string representing a generation with the given number
-/// of rows and columns and a specified probability for a cell to be alive.
-///
-/// The seed string consists of the characters `'*'` (alive) and `'-'` (dead), with the probability
-/// of `'*'` being determined by the `alive_probability` parameter.
-///
-/// The resulting seed string can be used as input for the `generation_from_string` function to
-/// create a randomly initialized generation.
-///
-/// # Arguments
-/// * `rows` - The number of rows in the generation grid.
-/// * `columns` - The number of columns in the generation grid.
-/// * `alive_probability` - The probability of a cell being alive.
-///
-/// # Returns
-/// A `String` representation of a randomly generated generation, where `'*'` represents an alive
-/// cell and `'-'` represents a dead cell.
-pub fn random_seed_probability(rows: u16, columns: u16, alive_probability: f64) -> String {
-    let length: usize = (rows * columns).into();
-    let mut rng: ThreadRng = thread_rng();
-    let dist = Uniform::from(0.0..1.0);
-    (0..length)
-        .map(|_| {
-            if dist.sample(&mut rng) < alive_probability {
-                ALIVE_CHAR
-            } else {
-                DEAD_CHAR
+    #[test]
+    fn actual_influence_is_never_faster_than_the_light_cone() {
+        for rng_seed in 0..4 {
+            let simulation: Simulation = SimulationBuilder::new()
+                .height(16)
+                .width(16)
+                .surface_ball()
+                .alive_probability(0.3)
+                .rng_seed(rng_seed)
+                .build()
+                .unwrap();
+            let light_cone: Vec<u8> = simulation.influence_map((8, 8), 12).unwrap();
+            let actual: Vec<u8> = simulation.actual_influence_map((8, 8), 12).unwrap();
+            assert_eq!(actual[8 * 16 + 8], 0);
+            for (index, (&earliest, &first_changed)) in light_cone.iter().zip(&actual).enumerate() {
+                assert!(first_changed >= earliest, "cell {}", index);
             }
-        })
-        .collect()
+            assert_eq!(simulation.iteration(), 0);
+        }
+    }
+
+    #[test]
+    fn influence_maps_reject_an_origin_outside_of_the_grid() {
+        let simulation: Simulation = build(3, "---------");
+        assert!(simulation.influence_map((3, 0), 5).is_err());
+        assert!(simulation.actual_influence_map((0, 3), 5).is_err());
+    }
+
+    #[test]
+    fn simulate_until_checks_the_predicate_after_every_generation() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(64)
+            .width(64)
+            .surface_ball()
+            .seed_named("glider")
+            .build()
+            .unwrap();
+        let mut seen: Vec<u128> = Vec::new();
+        let outcome: SimulationOutcome =
+            simulation.simulate_until(None, Duration::ZERO, |simulation| {
+                seen.push(simulation.iteration());
+                simulation.iteration() == 500
+            });
+        assert_eq!(outcome.stop_reason, StopReason::PredicateSatisfied);
+        assert_eq!(outcome.iterations_run, 500);
+        assert_eq!(simulation.iteration(), 500);
+        assert_eq!(seen, (1..=500).collect::<Vec<u128>>());
+    }
+
+    #[test]
+    fn simulate_until_stops_once_the_alive_count_drops() {
+        let mut simulation: Simulation = build(5, "*-*-*-----*-*-*-----*-*-*");
+        let outcome: SimulationOutcome =
+            simulation.simulate_until(Some(10), Duration::ZERO, |simulation| {
+                simulation.alive_count() < 5
+            });
+        assert_eq!(outcome.stop_reason, StopReason::PredicateSatisfied);
+        assert!(simulation.alive_count() < 5);
+        assert_eq!(outcome.iterations_run, simulation.iteration());
+    }
+
+    #[test]
+    fn simulate_until_checks_the_predicate_before_the_finished_state() {
+        let mut satisfied: Simulation = build(4, "-----**--**-----");
+        let outcome: SimulationOutcome =
+            satisfied.simulate_until(Some(10), Duration::ZERO, |_| true);
+        assert_eq!(outcome.stop_reason, StopReason::PredicateSatisfied);
+        assert_eq!(outcome.iterations_run, 1);
+
+        let mut finished: Simulation = build(4, "-----**--**-----");
+        let outcome: SimulationOutcome =
+            finished.simulate_until(Some(10), Duration::ZERO, |_| false);
+        assert_eq!(outcome.stop_reason, StopReason::Finished);
+        assert_eq!(outcome.iterations_run, 1);
+    }
+
+    #[test]
+    fn simulate_until_without_iterations_never_calls_the_predicate() {
+        let mut simulation: Simulation = build(3, "-*--*--*-");
+        let mut calls: u32 = 0;
+        let outcome: SimulationOutcome = simulation.simulate_until(Some(0), Duration::ZERO, |_| {
+            calls += 1;
+            true
+        });
+        assert_eq!(outcome.stop_reason, StopReason::MaximumIterations);
+        assert_eq!(outcome.iterations_run, 0);
+        assert_eq!(calls, 0);
+        assert_eq!(simulation.iteration(), 0);
+    }
+
+    #[test]
+    fn simulate_until_only_waits_between_generations() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(20)
+            .width(20)
+            .surface_ball()
+            .seed_named("glider")
+            .build()
+            .unwrap();
+        let outcome: SimulationOutcome =
+            simulation.simulate_until(Some(3), Duration::from_millis(30), |_| false);
+        assert_eq!(outcome.stop_reason, StopReason::MaximumIterations);
+        assert!(outcome.elapsed >= Duration::from_millis(60));
+        // A third cooldown after the last generation would take it to 90 milliseconds
+        assert!(outcome.elapsed < Duration::from_millis(90));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialized_simulation_round_trips() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(12)
+            .width(14)
+            .surface_mobius()
+            .rule_str("B36/S23")
+            .maximum_saves(5)
+            .seed_named("glider")
+            .build()
+            .unwrap();
+        simulation.simulate_generations(9);
+
+        let json: String = serde_json::to_string(&simulation).unwrap();
+        let mut deserialized: Simulation = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            deserialized.generation_string(),
+            simulation.generation_string()
+        );
+        assert_eq!(deserialized.seed(), simulation.seed());
+        assert_eq!(deserialized.iteration(), 9);
+        assert_eq!((deserialized.rows, deserialized.columns), (12, 14));
+        assert_eq!(format!("{:?}", deserialized.surface_type), "Mobius");
+        assert_eq!(deserialized.rule(), simulation.rule());
+        assert_eq!(deserialized.maximum_saves, 5);
+        assert_eq!(
+            deserialized.save_history_size(),
+            simulation.save_history_size()
+        );
+        assert!(!deserialized.display);
+        assert!(deserialized.window_data.is_none());
+
+        // The deserialized simulation continues exactly like the original
+        simulation.simulate_generations(20);
+        deserialized.simulate_generations(20);
+        assert_eq!(
+            deserialized.generation_string(),
+            simulation.generation_string()
+        );
+        simulation.rollback_generations(3);
+        deserialized.rollback_generations(3);
+        assert_eq!(
+            deserialized.generation_string(),
+            simulation.generation_string()
+        );
+    }
+
+    /// Builds a Möbius strip with the given cells alive.
+    fn mobius_strip(rows: u16, columns: u16, cells: &HashSet<(u16, u16)>) -> Simulation {
+        let cells: HashSet<(u16, u16)> = cells.clone();
+        SimulationBuilder::new()
+            .height(rows)
+            .width(columns)
+            .surface_mobius()
+            .seed_fn(move |row, column| cells.contains(&(row, column)))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn glider_returns_upside_down_around_a_mobius_strip() {
+        let glider: HashSet<(u16, u16)> = HashSet::from([(1, 1), (2, 2), (3, 0), (3, 1), (3, 2)]);
+        let mut simulation: Simulation = mobius_strip(40, 12, &glider);
+        // The glider moves one row down and one column right every 4 generations, so it
+        // crosses the right edge once in 48 generations
+        simulation.simulate_generations(48);
+        let upside_down: HashSet<(u16, u16)> = glider
+            .iter()
+            .map(|&(row, column)| (40 - 1 - (row + 12), column))
+            .collect();
+        assert_eq!(positions(&simulation.generation), upside_down);
+
+        // Upside down, the glider moves up instead of down
+        simulation.simulate_generations(4);
+        let moved_up: HashSet<(u16, u16)> = upside_down
+            .iter()
+            .map(|&(row, column)| (row - 1, column + 1))
+            .collect();
+        assert_eq!(positions(&simulation.generation), moved_up);
+    }
+
+    #[test]
+    fn spaceship_returns_upright_after_going_around_a_mobius_strip_twice() {
+        let spaceship: HashSet<(u16, u16)> = HashSet::from([
+            (3, 1),
+            (3, 4),
+            (4, 0),
+            (5, 0),
+            (5, 4),
+            (6, 0),
+            (6, 1),
+            (6, 2),
+            (6, 3),
+        ]);
+        let mut simulation: Simulation = mobius_strip(16, 20, &spaceship);
+        // The lightweight spaceship moves 2 columns every 4 generations
+        simulation.simulate_generations(40);
+        let flipped: HashSet<(u16, u16)> = spaceship
+            .iter()
+            .map(|&(row, column)| (16 - 1 - row, column))
+            .collect();
+        assert_eq!(positions(&simulation.generation), flipped);
+        simulation.simulate_generations(40);
+        assert_eq!(positions(&simulation.generation), spaceship);
+    }
+
+    #[test]
+    fn rolling_back_past_the_seed_stops_at_the_seed() {
+        let seed: &str = "-------*----*----*-------";
+        let mut simulation: Simulation = build(5, seed);
+        simulation.simulate_generations(4);
+        assert_eq!(simulation.rollback_generations(u128::MAX), 4);
+        assert_eq!(simulation.iteration(), 0);
+        assert_eq!(simulation.generation_string(), seed);
+        assert_eq!(simulation.rollback_generations(3), 0);
+        assert!(!simulation.rollback_generation());
+        assert_eq!(simulation.iteration(), 0);
+    }
+
+    #[test]
+    fn rolling_back_without_saves_keeps_the_generation() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(5)
+            .width(5)
+            .seed("-------*----*----*-------")
+            .maximum_saves(0)
+            .build()
+            .unwrap();
+        simulation.simulate_generations(5);
+        let generation: String = simulation.generation_string();
+        assert_eq!(simulation.save_history_size(), 0);
+        assert_eq!(simulation.rollback_generations(2), 0);
+        assert_eq!(simulation.iteration(), 5);
+        assert_eq!(simulation.generation_string(), generation);
+    }
+
+    #[test]
+    fn reset_clears_the_save_history() {
+        let mut simulation: Simulation = build(5, "-------*----*----*-------");
+        simulation.simulate_generations(3);
+        simulation.reset();
+        assert_eq!(simulation.save_history_size(), 0);
+        assert_eq!(simulation.rollback_generations(1), 0);
+        assert_eq!(simulation.iteration(), 0);
+    }
+
+    #[test]
+    fn grids_larger_than_a_u16_area_are_built_and_simulated() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(500)
+            .width(500)
+            .alive_probability(0.5)
+            .rng_seed(1)
+            .build()
+            .unwrap();
+        assert_eq!(simulation.area(), 250_000);
+        assert_eq!(simulation.seed().len(), 250_000);
+        assert_eq!(simulation.dead_count() + simulation.alive_count(), 250_000);
+        assert!((0.45..0.55).contains(&simulation.alive_proportion()));
+        simulation.simulate_generations(3);
+        assert_eq!(simulation.iteration(), 3);
+        assert_eq!(simulation.generation_string().len(), 250_000);
+    }
+
+    #[test]
+    fn cells_past_a_u16_index_keep_their_positions() {
+        let generation: HashSet<Cell> =
+            HashSet::from([Cell::new(ALIVE, 499, 499), Cell::new(ALIVE, 300, 7)]);
+        let seed: String = string_from_generation(generation, 500, 500);
+        assert_eq!(seed.len(), 250_000);
+        let alive: Vec<usize> = seed
+            .char_indices()
+            .filter(|&(_, char)| char == ALIVE_CHAR)
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(alive, vec![300 * 500 + 7, 249_999]);
+        assert_eq!(
+            random_seed_probability(500, 500, 1.0),
+            ALIVE_CHAR.to_string().repeat(250_000)
+        );
+        assert_eq!(random_seed(300, 300).len(), 90_000);
+    }
+
+    #[test]
+    fn seed_length_is_not_truncated_to_a_u16() {
+        // 65,539 characters would be 3 if truncated to a u16
+        let result: Result<Simulation, String> = SimulationBuilder::new()
+            .height(1)
+            .width(3)
+            .seed(&"-".repeat(65_539))
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn builder_rejects_seeds_that_do_not_fill_the_grid() {
+        let build = |seed: &str| -> Result<Simulation, String> {
+            SimulationBuilder::new()
+                .height(4)
+                .width(9)
+                .seed(seed)
+                .build()
+        };
+        assert!(build(&"*".repeat(36)).is_ok());
+        assert!(build("***").is_err());
+        assert!(build(&"*".repeat(35)).is_err());
+        assert!(build(&"*".repeat(37)).is_err());
+        assert!(build(&"*".repeat(45)).is_err());
+    }
+
+    #[test]
+    fn builder_rejects_empty_grids() {
+        for (rows, columns) in [(0, 5), (5, 0), (0, 0)] {
+            let result: Result<Simulation, String> =
+                SimulationBuilder::new().height(rows).width(columns).build();
+            assert!(result.is_err(), "{}x{}", rows, columns);
+        }
+        assert!(SimulationBuilder::new().seed("").build().is_err());
+    }
+
+    #[test]
+    fn generation_from_string_rejects_the_wrong_number_of_cells() {
+        assert!(generation_from_string(String::from("*--*"), 2, 2).is_ok());
+        assert!(generation_from_string(String::from("*--"), 2, 2).is_err());
+        assert!(generation_from_string(String::from("*--*-"), 2, 2).is_err());
+        assert!(generation_from_string(String::new(), 0, 3)
+            .unwrap()
+            .is_empty());
+    }
+
+    /// Returns the fraction of cells alive in the simulation.
+    fn density(simulation: &Simulation) -> f64 {
+        simulation.alive_count() as f64 / (simulation.rows as f64 * simulation.columns as f64)
+    }
+
+    #[test]
+    fn random_seeds_match_the_alive_probability() {
+        for alive_probability in [0.0, 0.1, 0.3, 0.5, 0.9, 1.0] {
+            let simulation: Simulation = SimulationBuilder::new()
+                .height(200)
+                .width(200)
+                .alive_probability(alive_probability)
+                .rng_seed(11)
+                .build()
+                .unwrap();
+            // 40,000 cells keep the standard deviation of the density below 0.0025
+            assert!(
+                (density(&simulation) - alive_probability).abs() < 0.015,
+                "density {} for probability {}",
+                density(&simulation),
+                alive_probability
+            );
+        }
+    }
+
+    #[test]
+    fn resets_keep_the_alive_probability() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(200)
+            .width(200)
+            .alive_probability(0.1)
+            .rng_seed(5)
+            .build()
+            .unwrap();
+        for _ in 0..3 {
+            simulation.reset_to_rand();
+            assert!((density(&simulation) - 0.1).abs() < 0.015);
+        }
+        simulation.reset_to_rand_seeded(9);
+        assert!((density(&simulation) - 0.1).abs() < 0.015);
+    }
+
+    #[test]
+    fn builder_rejects_alive_probabilities_outside_zero_to_one() {
+        for alive_probability in [-0.1, 1.5, f64::NAN, f64::INFINITY] {
+            let result: Result<Simulation, String> = SimulationBuilder::new()
+                .height(10)
+                .width(10)
+                .alive_probability(alive_probability)
+                .build();
+            assert!(result.is_err(), "{}", alive_probability);
+        }
+    }
+
+    #[test]
+    fn set_cell_and_toggle_cell_edit_the_generation() {
+        let mut simulation: Simulation = build(3, "---------");
+        simulation.set_cell(1, 2, true).unwrap();
+        assert!(simulation.is_cell_alive(1, 2).unwrap());
+        assert_eq!(simulation.generation_string(), "-----*---");
+
+        // Setting a cell to the state it is already in changes nothing
+        simulation.set_cell(1, 2, true).unwrap();
+        simulation.set_cell(0, 0, false).unwrap();
+        assert_eq!(simulation.alive_count(), 1);
+
+        simulation.toggle_cell(0, 0).unwrap();
+        simulation.toggle_cell(1, 2).unwrap();
+        assert!(simulation.is_cell_alive(0, 0).unwrap());
+        assert!(!simulation.is_cell_alive(1, 2).unwrap());
+        assert_eq!(simulation.generation_string(), "*--------");
+
+        simulation.set_cell(0, 0, false).unwrap();
+        assert_eq!(simulation.alive_count(), 0);
+    }
+
+    #[test]
+    fn cell_edits_reject_positions_outside_of_the_grid() {
+        let mut simulation: Simulation = build(3, "----*----");
+        for (row, column) in [(3, 0), (0, 3), (3, 3), (u16::MAX, u16::MAX)] {
+            assert!(simulation.set_cell(row, column, true).is_err());
+            assert!(simulation.set_cell(row, column, false).is_err());
+            assert!(simulation.toggle_cell(row, column).is_err());
+            assert!(simulation.is_cell_alive(row, column).is_err());
+        }
+        assert_eq!(simulation.generation_string(), "----*----");
+        assert!(simulation.is_cell_alive(2, 2).is_ok());
+    }
+
+    #[test]
+    fn edited_cells_are_simulated() {
+        let mut simulation: Simulation = build(5, &"-".repeat(25));
+        for column in 1..4 {
+            simulation.set_cell(2, column, true).unwrap();
+        }
+        simulation.simulate_generation();
+        assert_eq!(
+            simulation.alive_cells_as_vec(),
+            vec![(1, 2), (2, 2), (3, 2)]
+        );
+    }
+
+    #[test]
+    fn four_quarter_turns_of_a_generation_are_the_identity() {
+        for (rows, columns) in [(9, 9), (6, 11)] {
+            let mut simulation: Simulation = SimulationBuilder::new()
+                .height(rows)
+                .width(columns)
+                .alive_probability(0.3)
+                .rng_seed(3)
+                .build()
+                .unwrap();
+            let generation: String = simulation.generation_string();
+            let alive_count: u64 = simulation.alive_count();
+            for turn in 1..=4 {
+                simulation.rotate_90().unwrap();
+                assert_eq!(simulation.alive_count(), alive_count);
+                let swapped: bool = turn % 2 == 1;
+                assert_eq!(
+                    (simulation.height(), simulation.width()),
+                    if swapped {
+                        (columns, rows)
+                    } else {
+                        (rows, columns)
+                    }
+                );
+            }
+            assert_eq!(simulation.generation_string(), generation);
+
+            simulation.rotate_270().unwrap();
+            simulation.rotate_90().unwrap();
+            assert_eq!(simulation.generation_string(), generation);
+
+            let mut flipped: Simulation = simulation.clone();
+            simulation.rotate_180();
+            flipped.flip_horizontal();
+            flipped.flip_vertical();
+            assert_eq!(simulation.generation_string(), flipped.generation_string());
+            assert_eq!(simulation.alive_count(), alive_count);
+        }
+    }
+
+    #[test]
+    fn generation_rotations_match_pattern_rotations() {
+        let glider: Pattern = Pattern::from_rle("bo$2bo$3o!").unwrap();
+        let generation = |pattern: &Pattern| -> String {
+            let mut simulation: Simulation = build(3, &"-".repeat(9));
+            simulation
+                .insert_pattern(pattern, 0, 0, CollisionMode::Merge)
+                .unwrap();
+            simulation.generation_string()
+        };
+        let mut simulation: Simulation = build(3, &generation(&glider));
+        simulation.rotate_90().unwrap();
+        assert_eq!(
+            simulation.generation_string(),
+            generation(&glider.rotate_cw())
+        );
+        simulation.rotate_180();
+        assert_eq!(
+            simulation.generation_string(),
+            generation(&glider.rotate_ccw())
+        );
+        simulation.rotate_90().unwrap();
+        simulation.flip_horizontal();
+        assert_eq!(
+            simulation.generation_string(),
+            generation(&glider.flip_horizontal())
+        );
+        simulation.flip_horizontal();
+        simulation.flip_vertical();
+        assert_eq!(
+            simulation.generation_string(),
+            generation(&glider.flip_vertical())
+        );
+    }
+
+    #[test]
+    fn shift_wraps_or_discards_cells_past_each_edge() {
+        type Surface = fn(SimulationBuilder) -> SimulationBuilder;
+        // The position of a cell shifted past the top, bottom, left, and right edges of a 4x5
+        // grid, or None if it is discarded
+        let surfaces: [(Surface, [Option<(u16, u16)>; 4]); 6] = [
+            (
+                SimulationBuilder::surface_rectangle,
+                [None, None, None, None],
+            ),
+            (
+                SimulationBuilder::surface_ball,
+                [Some((3, 2)), Some((0, 2)), Some((1, 4)), Some((1, 0))],
+            ),
+            (
+                SimulationBuilder::surface_horizontal_loop,
+                [None, None, Some((1, 4)), Some((1, 0))],
+            ),
+            (
+                SimulationBuilder::surface_vertical_loop,
+                [Some((3, 2)), Some((0, 2)), None, None],
+            ),
+            (
+                SimulationBuilder::surface_mobius,
+                [None, None, Some((2, 4)), Some((2, 0))],
+            ),
+            (
+                SimulationBuilder::surface_klein_bottle,
+                [Some((3, 2)), Some((0, 2)), Some((2, 4)), Some((2, 0))],
+            ),
+        ];
+        let shifts: [((u16, u16), (i32, i32)); 4] = [
+            ((0, 2), (-1, 0)),
+            ((3, 2), (1, 0)),
+            ((1, 0), (0, -1)),
+            ((1, 4), (0, 1)),
+        ];
+        for (index, (surface, expected)) in surfaces.into_iter().enumerate() {
+            for (((row, column), (row_delta, column_delta)), expected) in
+                shifts.into_iter().zip(expected)
+            {
+                let mut seed: Vec<char> = vec!['-'; 20];
+                seed[row as usize * 5 + column as usize] = '*';
+                let mut simulation: Simulation =
+                    surface(SimulationBuilder::new().height(4).width(5))
+                        .seed(&seed.into_iter().collect::<String>())
+                        .build()
+                        .unwrap();
+                simulation.shift(row_delta, column_delta);
+                assert_eq!(
+                    simulation.alive_cells_as_vec(),
+                    expected.into_iter().collect::<Vec<(u16, u16)>>(),
+                    "surface {} shifting ({}, {}) by ({}, {})",
+                    index,
+                    row,
+                    column,
+                    row_delta,
+                    column_delta
+                );
+                assert_eq!(simulation.iteration(), 0);
+                assert_eq!(simulation.save_history_size(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn shift_all_the_way_around_a_wrapping_surface_changes_nothing() {
+        let build = |surface: fn(SimulationBuilder) -> SimulationBuilder| -> Simulation {
+            surface(SimulationBuilder::new().height(6).width(7))
+                .alive_probability(0.4)
+                .rng_seed(2)
+                .build()
+                .unwrap()
+        };
+        let mut ball: Simulation = build(SimulationBuilder::surface_ball);
+        let generation: String = ball.generation_string();
+        ball.shift(6, -7);
+        assert_eq!(ball.generation_string(), generation);
+        ball.shift(-60, 70);
+        assert_eq!(ball.generation_string(), generation);
+
+        // Going around a Möbius strip once flips the generation, and twice restores it
+        let mut mobius: Simulation = build(SimulationBuilder::surface_mobius);
+        let generation: String = mobius.generation_string();
+        mobius.shift(0, 7);
+        let mut flipped: Simulation = build(SimulationBuilder::surface_mobius);
+        flipped.flip_vertical();
+        assert_eq!(mobius.generation_string(), flipped.generation_string());
+        mobius.shift(0, 7);
+        assert_eq!(mobius.generation_string(), generation);
+    }
+
+    #[test]
+    fn preview_next_generation_matches_the_simulated_generation() {
+        let surfaces: [fn(SimulationBuilder) -> SimulationBuilder; 4] = [
+            SimulationBuilder::surface_rectangle,
+            SimulationBuilder::surface_ball,
+            SimulationBuilder::surface_mobius,
+            SimulationBuilder::surface_klein_bottle,
+        ];
+        for (index, surface) in surfaces.into_iter().enumerate() {
+            for rule in ["B3/S23", "B36/S23", "B2/S"] {
+                let mut simulation: Simulation =
+                    surface(SimulationBuilder::new().height(12).width(15))
+                        .rule(rule.parse().unwrap())
+                        .alive_probability(0.35)
+                        .rng_seed(index as u64)
+                        .build()
+                        .unwrap();
+                for generation in 0..6 {
+                    let preview: HashSet<Cell> = simulation.preview_next_generation();
+                    simulation.simulate_generation();
+                    assert!(
+                        positions(&preview) == positions(&simulation.generation()),
+                        "surface {} with {}, generation {}",
+                        index,
+                        rule,
+                        generation
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn preview_next_generation_changes_nothing() {
+        let mut simulation: Simulation = build(5, "-------*----*----*-------");
+        simulation.simulate_generation();
+        let generation: String = simulation.generation_string();
+        let population_history: Vec<u64> = simulation.population_history();
+        let delta: GenerationDelta = simulation.generation_delta().clone();
+
+        let preview: HashSet<Cell> = simulation.preview_next_generation();
+        assert_eq!(positions(&preview), HashSet::from([(1, 2), (2, 2), (3, 2)]));
+        assert_eq!(
+            positions(&simulation.preview_next_generation()),
+            positions(&preview)
+        );
+        assert_eq!(simulation.generation_string(), generation);
+        assert_eq!(simulation.iteration(), 1);
+        assert_eq!(simulation.save_history_size(), 1);
+        assert_eq!(simulation.population_history(), population_history);
+        assert!(*simulation.generation_delta() == delta);
+        assert!(!simulation.is_finished());
+    }
+
+    #[test]
+    fn bounding_box_is_none_once_extinct() {
+        let mut simulation: Simulation = build(4, "-----*----*-----");
+        assert_eq!(simulation.bounding_box(), Some((1, 1, 2, 2)));
+        simulation.simulate_generation();
+        assert_eq!(simulation.bounding_box(), None);
+    }
+
+    #[test]
+    fn center_pattern_biases_odd_gaps_up_and_left() {
+        // (rows, columns, expected bounding box of a 2x3 pattern after centering)
+        let cases: [(u16, u16, (u16, u16, u16, u16)); 5] = [
+            (6, 7, (2, 2, 3, 4)),
+            (7, 7, (2, 2, 3, 4)),
+            (6, 8, (2, 2, 3, 4)),
+            (7, 8, (2, 2, 3, 4)),
+            (9, 10, (3, 3, 4, 5)),
+        ];
+        for (rows, columns, expected) in cases {
+            let mut seed: Vec<char> = vec!['-'; rows as usize * columns as usize];
+            for (row, column) in [(0, 0), (0, 2), (1, 1)] {
+                seed[(rows as usize - 2 + row) * columns as usize + column] = '*';
+            }
+            let mut simulation: Simulation = SimulationBuilder::new()
+                .height(rows)
+                .width(columns)
+                .seed(&seed.into_iter().collect::<String>())
+                .build()
+                .unwrap();
+            let (cells, _, _) =
+                crop_generation(&simulation.generation(), simulation.bounding_box().unwrap());
+
+            simulation.center_pattern();
+            assert_eq!(
+                simulation.bounding_box(),
+                Some(expected),
+                "{}x{}",
+                rows,
+                columns
+            );
+            let (centered, _, _) =
+                crop_generation(&simulation.generation(), simulation.bounding_box().unwrap());
+            assert!(positions(&centered) == positions(&cells));
+            assert_eq!(simulation.iteration(), 0);
+
+            // Centering a centered pattern changes nothing
+            simulation.center_pattern();
+            assert_eq!(simulation.bounding_box(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn center_pattern_without_alive_cells_changes_nothing() {
+        let mut simulation: Simulation = build(3, "---------");
+        simulation.center_pattern();
+        assert_eq!(simulation.generation_string(), "---------");
+    }
+
+    #[test]
+    fn crop_generation_leaves_out_cells_outside_of_the_box() {
+        let simulation: Simulation = build(4, "*--*-**--**-*--*");
+        let (cells, rows, columns) = crop_generation(&simulation.generation(), (1, 1, 2, 2));
+        assert_eq!((rows, columns), (2, 2));
+        assert_eq!(string_from_generation(cells, rows, columns), "****");
+
+        let (cells, rows, columns) = crop_generation(&simulation.generation(), (3, 3, 3, 3));
+        assert_eq!((rows, columns), (1, 1));
+        assert_eq!(positions(&cells), HashSet::from([(0, 0)]));
+
+        let (cells, rows, columns) = crop_generation(&simulation.generation(), (0, 1, 0, 2));
+        assert_eq!((rows, columns), (1, 2));
+        assert!(cells.is_empty());
+    }
+
+    #[test]
+    fn rng_seed_makes_random_seeds_reproducible() {
+        let build = |rng_seed: Option<u64>, alive_probability: Option<f64>| -> Simulation {
+            let mut builder: SimulationBuilder = SimulationBuilder::new().height(30).width(40);
+            if let Some(rng_seed) = rng_seed {
+                builder = builder.rng_seed(rng_seed);
+            }
+            if let Some(alive_probability) = alive_probability {
+                builder = builder.alive_probability(alive_probability);
+            }
+            builder.build().unwrap()
+        };
+        for alive_probability in [None, Some(0.25)] {
+            let simulation: Simulation = build(Some(42), alive_probability);
+            assert_eq!(simulation.rng_seed(), Some(42));
+            assert_eq!(simulation.seed().len(), 1200);
+            assert_eq!(build(Some(42), alive_probability).seed(), simulation.seed());
+            assert_ne!(build(Some(43), alive_probability).seed(), simulation.seed());
+        }
+        assert_eq!(build(None, None).rng_seed(), None);
+    }
+
+    #[test]
+    fn builder_draws_from_the_same_rng_as_the_with_rng_functions() {
+        let simulation: Simulation = SimulationBuilder::new()
+            .height(8)
+            .width(9)
+            .rng_seed(7)
+            .build()
+            .unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(7);
+        assert_eq!(simulation.seed(), random_seed_with_rng(8, 9, &mut rng));
+
+        let simulation: Simulation = SimulationBuilder::new()
+            .height(8)
+            .width(9)
+            .alive_probability(0.6)
+            .rng_seed(7)
+            .build()
+            .unwrap();
+        let mut rng: StdRng = StdRng::seed_from_u64(7);
+        assert_eq!(
+            simulation.seed(),
+            random_seed_probability_with_rng(8, 9, 0.6, &mut rng)
+        );
+    }
+
+    #[test]
+    fn with_rng_functions_are_deterministic() {
+        let draw = |rng_seed: u64| -> (String, String) {
+            let mut rng: StdRng = StdRng::seed_from_u64(rng_seed);
+            (
+                random_seed_with_rng(10, 12, &mut rng),
+                random_seed_probability_with_rng(10, 12, 0.4, &mut rng),
+            )
+        };
+        assert_eq!(draw(1), draw(1));
+        assert_ne!(draw(1), draw(2));
+        let (seed, seed_with_probability) = draw(1);
+        assert_eq!((seed.len(), seed_with_probability.len()), (120, 120));
+        assert_ne!(seed, seed_with_probability);
+
+        let mut rng: StdRng = StdRng::seed_from_u64(1);
+        assert_eq!(
+            random_seed_probability_with_rng(3, 3, 0.0, &mut rng),
+            "-".repeat(9)
+        );
+        assert_eq!(
+            random_seed_probability_with_rng(3, 3, 1.0, &mut rng),
+            "*".repeat(9)
+        );
+    }
+
+    #[test]
+    fn find_period_finds_the_period_of_oscillators() {
+        for (name, size, period) in [("block", 6, 1), ("blinker", 5, 2), ("pulsar", 17, 3)] {
+            let mut simulation: Simulation = SimulationBuilder::new()
+                .height(size)
+                .width(size)
+                .seed_named(name)
+                .maximum_saves(0)
+                .build()
+                .unwrap();
+            let generation: String = simulation.generation_string();
+            assert_eq!(simulation.find_period(50), Some(period), "{}", name);
+            assert_eq!(simulation.find_period(period), Some(period), "{}", name);
+            assert_eq!(simulation.find_period(period - 1), None, "{}", name);
+            assert_eq!(simulation.generation_string(), generation);
+            assert_eq!(simulation.iteration(), 0);
+        }
+    }
+
+    #[test]
+    fn find_period_finds_cycles_entered_later() {
+        // An L-tromino becomes a block after one generation
+        let mut tromino: Simulation = build(4, "-----**--*------");
+        assert_eq!(tromino.find_period(5), Some(1));
+        assert_eq!(tromino.generation_string(), "-----**--*------");
+
+        // A glider on a 6x6 ball returns to its position after 24 generations
+        let mut glider: Simulation = SimulationBuilder::new()
+            .height(6)
+            .width(6)
+            .surface_ball()
+            .seed_named("glider")
+            .build()
+            .unwrap();
+        assert_eq!(glider.find_period(100), Some(24));
+        assert_eq!(glider.find_period(23), None);
+    }
+
+    #[test]
+    fn find_period_of_a_dying_pattern_is_that_of_the_empty_grid() {
+        let mut simulation: Simulation = build(5, "------*-----*------------");
+        assert_eq!(simulation.find_period(4), Some(1));
+        assert_eq!(simulation.alive_count(), 2);
+        assert_eq!(simulation.find_period(0), None);
+    }
+
+    #[test]
+    fn cells_about_to_change_match_the_next_generation_delta() {
+        let cases: [(&str, fn(SimulationBuilder) -> SimulationBuilder); 2] = [
+            ("blinker", SimulationBuilder::surface_rectangle),
+            ("glider", SimulationBuilder::surface_ball),
+        ];
+        for (name, surface) in cases {
+            let mut simulation: Simulation = surface(SimulationBuilder::new().height(8).width(8))
+                .seed_named(name)
+                .build()
+                .unwrap();
+            for generation in 0..6 {
+                let born: Vec<(u16, u16)> = simulation.will_be_born_next_generation();
+                let died: Vec<(u16, u16)> = simulation.will_die_next_generation();
+                assert!(!born.is_empty() && !died.is_empty());
+                assert_eq!(simulation.iteration(), generation);
+                assert_eq!(simulation.save_history_size(), generation);
+
+                simulation.simulate_generation();
+                assert_eq!(born, simulation.generation_delta().born, "{}", name);
+                assert_eq!(died, simulation.generation_delta().died, "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn alive_cells_are_the_generation_in_row_major_order() {
+        let simulation: Simulation = SimulationBuilder::new()
+            .height(9)
+            .width(13)
+            .alive_probability(0.4)
+            .rng_seed(8)
+            .build()
+            .unwrap();
+        let alive_cells: Vec<(u16, u16)> = simulation.alive_cells().collect();
+        let mut expected: Vec<(u16, u16)> = simulation
+            .generation()
+            .iter()
+            .map(|cell| {
+                assert!(cell.is_alive());
+                (cell.row(), cell.column())
+            })
+            .collect();
+        expected.sort();
+        assert_eq!(alive_cells, expected);
+        assert_eq!(alive_cells.len() as u64, simulation.alive_count());
+    }
+
+    #[test]
+    fn cells_cover_the_grid_with_the_right_alive_flags() {
+        let simulation: Simulation = SimulationBuilder::new()
+            .height(9)
+            .width(13)
+            .alive_probability(0.4)
+            .rng_seed(8)
+            .build()
+            .unwrap();
+        let cells: Vec<(u16, u16, bool)> = simulation.cells().collect();
+        assert_eq!(cells.len(), 9 * 13);
+        let order: Vec<(u16, u16)> = cells
+            .iter()
+            .map(|(row, column, _)| (*row, *column))
+            .collect();
+        let mut sorted: Vec<(u16, u16)> = order.clone();
+        sorted.sort();
+        assert_eq!(order, sorted);
+        let generation: HashSet<(u16, u16)> = positions(&simulation.generation());
+        for (row, column, alive) in cells {
+            assert_eq!(alive, generation.contains(&(row, column)));
+            assert_eq!(alive, simulation.is_cell_alive(row, column).unwrap());
+        }
+        assert_eq!(
+            simulation.generation_string(),
+            simulation
+                .cells()
+                .map(|(_, _, alive)| if alive { ALIVE_CHAR } else { DEAD_CHAR })
+                .collect::<String>()
+        );
+    }
+
+    #[test]
+    fn simulate_n_or_until_finished_stops_at_the_finishing_generation() {
+        let mut blinker: Simulation = build(5, "-------*----*----*-------");
+        let simulated: u128 = blinker.simulate_n_or_until_finished(100);
+        assert_eq!(simulated, 2);
+        assert!(simulated < 100);
+        assert_eq!(blinker.iteration(), 2);
+        assert!(blinker.is_finished());
+        // Once finished, the next generation finishes it again
+        assert_eq!(blinker.simulate_n_or_until_finished(100), 1);
+    }
+
+    #[test]
+    fn simulate_n_or_until_finished_simulates_n_generations_of_a_long_lived_pattern() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(60)
+            .width(60)
+            .seed_named("r-pentomino")
+            .build()
+            .unwrap();
+        assert_eq!(simulation.simulate_n_or_until_finished(150), 150);
+        assert_eq!(simulation.iteration(), 150);
+        assert!(!simulation.is_finished());
+
+        assert_eq!(simulation.simulate_n_or_until_finished(0), 0);
+        assert_eq!(simulation.iteration(), 150);
+    }
+
+    #[test]
+    fn simulate_with_callback_sees_each_iteration_in_order() {
+        let mut simulation: Simulation = build(5, "-------*----*----*-------");
+        simulation.simulate_generation();
+        let mut seen: Vec<(u128, String)> = Vec::new();
+        simulation.simulate_with_callback(4, |simulation| {
+            seen.push((simulation.iteration(), simulation.generation_string()))
+        });
+        let mut expected: Simulation = build(5, "-------*----*----*-------");
+        expected.simulate_generation();
+        for (iteration, generation) in &seen {
+            expected.simulate_generation();
+            assert_eq!(*iteration, expected.iteration());
+            assert_eq!(*generation, expected.generation_string());
+        }
+        assert_eq!(
+            seen.iter()
+                .map(|(iteration, _)| *iteration)
+                .collect::<Vec<u128>>(),
+            vec![2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn simulate_with_callback_of_zero_generations_never_calls_back() {
+        let mut simulation: Simulation = build(5, "-------*----*----*-------");
+        let mut calls: u32 = 0;
+        simulation.simulate_with_callback(0, |_| calls += 1);
+        assert_eq!(calls, 0);
+        assert_eq!(simulation.iteration(), 0);
+    }
+
+    #[test]
+    fn alive_counts_by_row_and_column() {
+        // *--**
+        // -*---
+        // ***-*
+        let simulation: Simulation = build(3, "*--**-*---***-*");
+        assert_eq!(simulation.alive_count_by_row(), vec![3, 1, 4]);
+        assert_eq!(simulation.alive_count_by_column(), vec![2, 2, 1, 1, 2]);
+        assert_eq!(
+            simulation.alive_count_by_row().iter().sum::<u64>(),
+            simulation.alive_count()
+        );
+
+        let empty: Simulation = build(2, "------");
+        assert_eq!(empty.alive_count_by_row(), vec![0, 0]);
+        assert_eq!(empty.alive_count_by_column(), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn alive_count_in_region_is_inclusive() {
+        let simulation: Simulation = build(3, "*--**-*---***-*");
+        assert_eq!(simulation.alive_count_in_region(0, 0, 2, 4), Ok(8));
+        assert_eq!(simulation.alive_count_in_region(1, 1, 2, 3), Ok(3));
+        assert_eq!(simulation.alive_count_in_region(0, 1, 0, 2), Ok(0));
+        assert_eq!(simulation.alive_count_in_region(2, 4, 2, 4), Ok(1));
+    }
+
+    #[test]
+    fn alive_count_in_region_rejects_regions_outside_of_the_grid() {
+        let simulation: Simulation = build(3, "*--**-*---***-*");
+        assert!(simulation.alive_count_in_region(0, 0, 3, 4).is_err());
+        assert!(simulation.alive_count_in_region(0, 0, 2, 5).is_err());
+        assert!(simulation.alive_count_in_region(2, 0, 1, 4).is_err());
+        assert!(simulation.alive_count_in_region(0, 3, 2, 2).is_err());
+    }
+
+    #[test]
+    fn horizontal_symmetry_mirrors_around_the_middle_column() {
+        // Odd widths mirror around a column, and even widths around the line between two
+        for seed in ["*---*", "-*-*-", "--*--", "*--*", "-**-", "**\n**"] {
+            let rows: u16 = if seed.contains('\n') { 2 } else { 1 };
+            assert!(build(rows, seed).is_symmetric_horizontally(), "{}", seed);
+        }
+        // Each is symmetric around a column one away from the middle
+        for seed in ["*-*--", "-*-*", "*-*-", "--*-"] {
+            assert!(!build(1, seed).is_symmetric_horizontally(), "{}", seed);
+        }
+    }
+
+    #[test]
+    fn vertical_symmetry_mirrors_around_the_middle_row() {
+        for seed in ["*---*", "-*-*-", "--*--", "*--*", "-**-"] {
+            assert!(
+                build(seed.len() as u16, seed).is_symmetric_vertically(),
+                "{}",
+                seed
+            );
+        }
+        for seed in ["*-*--", "-*-*", "*-*-", "--*-"] {
+            assert!(
+                !build(seed.len() as u16, seed).is_symmetric_vertically(),
+                "{}",
+                seed
+            );
+        }
+        // A horizontal line is vertically symmetric only in the middle row
+        assert!(build(3, "---***---").is_symmetric_vertically());
+        assert!(!build(3, "***------").is_symmetric_vertically());
+    }
+
+    #[test]
+    fn rotational_symmetry_maps_each_cell_to_the_opposite_one() {
+        assert!(build(3, "*-------*").is_symmetric_rotationally_180());
+        assert!(build(2, "*------*").is_symmetric_rotationally_180());
+        assert!(build(3, "----*----").is_symmetric_rotationally_180());
+        assert!(!build(3, "*------*-").is_symmetric_rotationally_180());
+        assert!(!build(2, "*-----*-").is_symmetric_rotationally_180());
+
+        // Dominoes in opposite corners are rotationally symmetric without being symmetric across
+        // either axis
+        let dominoes: Simulation = build(3, "**-----**");
+        assert!(dominoes.is_symmetric_rotationally_180());
+        assert!(!dominoes.is_symmetric_horizontally());
+        assert!(!dominoes.is_symmetric_vertically());
+    }
+
+    #[test]
+    fn center_of_mass_of_a_symmetric_pattern_is_its_center() {
+        let mut pulsar: Simulation = SimulationBuilder::new()
+            .height(17)
+            .width(17)
+            .seed_named("pulsar")
+            .build()
+            .unwrap();
+        for _ in 0..3 {
+            assert_eq!(pulsar.center_of_mass(), Some((8.0, 8.0)));
+            pulsar.simulate_generation();
+        }
+        // A block has its center between cells
+        let block: Simulation = build(4, "-----**--**-----");
+        assert_eq!(block.center_of_mass(), Some((1.5, 1.5)));
+        assert_eq!(block.bounding_box(), Some((1, 1, 2, 2)));
+    }
+
+    #[test]
+    fn center_of_mass_is_weighted_by_cells() {
+        // *----
+        // -----
+        // ----*
+        // ----*
+        let simulation: Simulation = build(4, "*-------------*----*");
+        assert_eq!(simulation.center_of_mass(), Some((5.0 / 3.0, 8.0 / 3.0)));
+        assert_eq!(simulation.bounding_box(), Some((0, 0, 3, 4)));
+    }
+
+    #[test]
+    fn center_of_mass_of_an_empty_generation_is_none() {
+        let simulation: Simulation = build(3, "---------");
+        assert_eq!(simulation.center_of_mass(), None);
+        assert_eq!(simulation.bounding_box(), None);
+    }
+
+    #[test]
+    fn shrinking_the_maximum_saves_keeps_the_most_recent_saves() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(10)
+            .width(10)
+            .surface_ball()
+            .seed_named("glider")
+            .build()
+            .unwrap();
+        let mut generations: Vec<String> = vec![simulation.generation_string()];
+        for _ in 0..20 {
+            simulation.simulate_generation();
+            generations.push(simulation.generation_string());
+        }
+        assert_eq!(simulation.save_history_size(), 20);
+
+        simulation.set_maximum_saves(6);
+        assert_eq!(simulation.save_history_size(), 6);
+        for iteration in (14..20).rev() {
+            assert_eq!(simulation.rollback_generations(1), 1);
+            assert_eq!(simulation.iteration(), iteration);
+            assert_eq!(
+                simulation.generation_string(),
+                generations[iteration as usize]
+            );
+        }
+        assert_eq!(simulation.rollback_generations(1), 0);
+
+        // New saves are limited to the new maximum
+        simulation.simulate_generations(10);
+        assert_eq!(simulation.save_history_size(), 6);
+    }
+
+    #[test]
+    fn growing_the_maximum_saves_keeps_every_save() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(10)
+            .width(10)
+            .surface_ball()
+            .seed_named("glider")
+            .maximum_saves(5)
+            .build()
+            .unwrap();
+        simulation.simulate_generations(8);
+        simulation.set_maximum_saves(50);
+        assert_eq!(simulation.save_history_size(), 5);
+        simulation.simulate_generations(10);
+        assert_eq!(simulation.save_history_size(), 15);
+        assert_eq!(simulation.rollback_generations(20), 15);
+        assert_eq!(simulation.iteration(), 3);
+    }
+
+    #[test]
+    fn has_period_matches_the_period_of_oscillators() {
+        let mut blinker: Simulation = build(5, "-------*----*----*-------");
+        assert!(!blinker.has_period(1) && !blinker.has_period(2));
+        blinker.simulate_generation();
+        assert!(!blinker.has_period(1) && !blinker.has_period(2));
+        blinker.simulate_generation();
+        assert!(blinker.has_period(2));
+        assert!(!blinker.has_period(1) && !blinker.has_period(3));
+        // Every multiple of the period matches too, once enough generations are saved
+        blinker.simulate_generations(2);
+        assert!(blinker.has_period(2) && blinker.has_period(4));
+        assert!(!blinker.has_period(0));
+
+        let mut block: Simulation = build(4, "-----**--**-----");
+        block.simulate_generation();
+        assert!(block.has_period(1) && block.is_still());
+        assert!(!block.has_period(0));
+    }
+
+    #[test]
+    fn seed_grid_is_the_seed_as_it_was_displayed() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(5)
+            .width(6)
+            .seed_named("glider")
+            .render_chars('#', '.')
+            .build()
+            .unwrap();
+        let displayed: String = simulation.to_string();
+        simulation.simulate_generations(3);
+        assert_eq!(format!("SEED\n{}", simulation.seed_grid()), displayed);
+        assert_eq!(simulation.seed_grid().lines().count(), 5);
+    }
+
+    #[test]
+    fn simulation_keeps_running_after_quitting_its_window() {
+        let mut simulation: Simulation = build(5, "-------*----*----*-------");
+        // A simulation set to display, whose window was never opened
+        simulation.display = true;
+        simulation.quit_window();
+        assert!(!simulation.display);
+        assert!(simulation.window_data.is_none());
+        simulation.simulate_generations(3);
+        assert_eq!(simulation.iteration(), 3);
+        assert_eq!(simulation.generation_string(), "-----------***-----------");
+
+        // Quitting again is harmless
+        simulation.quit_window();
+        simulation.simulate_generation();
+        assert_eq!(simulation.iteration(), 4);
+    }
+
+    #[test]
+    fn run_to_completion_stops_when_the_grid_dies_out() {
+        let mut simulation: Simulation = build(3, "----*----");
+        let reason: StopReason = simulation.run_to_completion(100, None);
+        assert_eq!(reason, StopReason::Finished);
+        assert_eq!(simulation.alive_count(), 0);
+        // The empty generation is only finished once it has repeated
+        assert_eq!(simulation.iteration(), 2);
+    }
+
+    #[test]
+    fn run_to_completion_stops_on_a_still_life() {
+        let mut simulation: Simulation = build(4, "-----**--**-----");
+        let reason: StopReason = simulation.run_to_completion(100, None);
+        assert_eq!(reason, StopReason::Finished);
+        assert_eq!(simulation.iteration(), 1);
+        assert_eq!(simulation.cycle_info().unwrap().period, 1);
+    }
+
+    #[test]
+    fn run_to_completion_stops_on_an_oscillator() {
+        let mut simulation: Simulation = build(5, "-----------***-----------");
+        let reason: StopReason = simulation.run_to_completion(100, None);
+        assert_eq!(reason, StopReason::Finished);
+        assert_eq!(simulation.iteration(), 2);
+        assert_eq!(simulation.cycle_info().unwrap().period, 2);
+    }
+
+    #[test]
+    fn run_to_completion_stops_when_stagnant() {
+        // With only 2 saved generations, the glider's period of 24 on a 6x6 ball is never
+        // found, but once it has crossed every cell the simulation stops changing
+        let builder: SimulationBuilder = SimulationBuilder::new()
+            .height(6)
+            .width(6)
+            .surface_ball()
+            .seed_named("glider")
+            .maximum_saves(2)
+            .display(false);
+        let limit: StagnationLimit = StagnationLimit {
+            window: 10,
+            tolerance: 0.0,
+        };
+
+        let mut simulation: Simulation = builder.clone().build().unwrap();
+        let reason: StopReason = simulation.run_to_completion(200, Some(limit));
+        assert_eq!(reason, StopReason::Stagnant);
+        assert!(simulation.iteration() < 200);
+        assert!(simulation.is_stagnant(limit.window, limit.tolerance));
+        assert!(!simulation.is_finished());
+
+        let mut unlimited: Simulation = builder.build().unwrap();
+        let reason: StopReason = unlimited.run_to_completion(200, None);
+        assert_eq!(reason, StopReason::MaximumIterations);
+        assert_eq!(unlimited.iteration(), 200);
+    }
+
+    #[test]
+    fn run_to_completion_stops_at_the_maximum_iterations() {
+        let mut simulation: Simulation = glider_on_a_ball(false, false);
+        let reason: StopReason = simulation.run_to_completion(3, None);
+        assert_eq!(reason, StopReason::MaximumIterations);
+        assert_eq!(simulation.iteration(), 3);
+
+        let reason: StopReason = simulation.run_to_completion(0, None);
+        assert_eq!(reason, StopReason::MaximumIterations);
+        assert_eq!(simulation.iteration(), 3);
+    }
 }
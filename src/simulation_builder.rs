@@ -15,12 +15,40 @@
 //!     .unwrap();
 //! ```
 
-use crate::simulation::SurfaceType::{Ball, HorizontalLoop, Rectangle, VerticalLoop};
-use crate::simulation::{generation_from_string, random_seed, Simulation, SurfaceType};
+use crate::cell::CellState::ALIVE;
+use crate::cell::{Cell, ALIVE_CHAR, DEAD_CHAR};
+use crate::delta::GenerationDelta;
+use crate::history::SaveHistory;
+use crate::observer::Observers;
+use crate::patterns::{named_seed, CollisionMode};
+use crate::rule::{Preset, Rule};
+use crate::seeding::SeedGenerator;
+use crate::simulation::SurfaceType::{
+    Ball, Custom, HorizontalLoop, KleinBottle, Mobius, Rectangle, VerticalLoop,
+};
+use crate::simulation::{
+    draw_random_seed, generation_from_string, normalize_seed, plaintext_seed, rle_seed,
+    seed_grid_dimensions, string_from_generation, Neighborhood, RenderConfig, Simulation,
+    SurfaceType,
+};
 use crate::simulation_window::SimulationWindowData;
+use crate::statistics::Statistics;
+use rand::rngs::StdRng;
+use rand::{thread_rng, SeedableRng};
 use simple::Window;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// The width and height of each cell in pixels when drawing images of a simulation without a
+/// display window, if no cell or window size was provided.
+const DEFAULT_CELL_SIZE: u16 = 20;
+
+/// A function that returns whether the cell at a row and column of the seed is alive (see
+/// `SimulationBuilder::seed_fn`).
+type SeedFn = Arc<dyn Fn(u16, u16) -> bool + Send + Sync>;
 
 /// A builder for configuring and creating a new `Simulation`.
+#[derive(Clone)]
 pub struct SimulationBuilder {
     /// The number of rows in the simulation grid.
     rows: Option<u16>,
@@ -30,6 +58,21 @@ pub struct SimulationBuilder {
     surface_type: SurfaceType,
     /// The initial seed string used to generate the simulation.
     seed: Option<String>,
+    /// The name of a pattern from the pattern lexicon to center on the grid as the seed.
+    seed_name: Option<String>,
+    /// The structured generator used to create the seed.
+    seed_generator: Option<SeedGenerator>,
+    /// The function called for every cell to create the seed, which takes priority over every
+    /// other seed.
+    seed_fn: Option<SeedFn>,
+    /// The pattern in the plaintext (`.cells`) format used as the seed.
+    seed_plaintext: Option<String>,
+    /// The pattern in the RLE format used as the seed.
+    seed_rle: Option<String>,
+    /// The number to seed the random number generator for random seeds with.
+    rng_seed: Option<u64>,
+    /// The chance that each cell of a random seed is alive, or `None` for a random chance.
+    random_alive_probability: Option<f64>,
     /// The maximum number of generations to retain in the save history.
     maximum_saves: u128,
     /// The width of each cell in the display in pixels.
@@ -72,6 +115,18 @@ pub struct SimulationBuilder {
     display: bool,
     /// A flag indicating whether the simulation should be printed to the console.
     print: bool,
+    /// The characters that alive and dead cells are printed as.
+    render_chars: (char, char),
+    /// The birth and survival rule used to compute each new generation.
+    rule: Rule,
+    /// The error from the last rule string that could not be parsed, returned when building.
+    rule_error: Option<String>,
+    /// The cells that count as the neighbors of each cell.
+    neighborhood: Neighborhood,
+    /// A flag indicating whether translated repeats should count as cycles.
+    detect_translated_cycles: bool,
+    /// A flag indicating whether births and deaths caused by bounded edges should be counted.
+    track_boundary_effects: bool,
 }
 
 impl Default for SimulationBuilder {
@@ -82,6 +137,13 @@ impl Default for SimulationBuilder {
             columns: None,
             surface_type: Rectangle,
             seed: None,
+            seed_name: None,
+            seed_generator: None,
+            seed_fn: None,
+            seed_plaintext: None,
+            seed_rle: None,
+            rng_seed: None,
+            random_alive_probability: None,
             maximum_saves: 100,
             cell_width: None,
             cell_height: None,
@@ -103,6 +165,12 @@ impl Default for SimulationBuilder {
             window_title: String::from("Game of Life"),
             display: false,
             print: false,
+            render_chars: (ALIVE_CHAR, DEAD_CHAR),
+            rule: Rule::default(),
+            rule_error: None,
+            neighborhood: Neighborhood::Moore,
+            detect_translated_cycles: false,
+            track_boundary_effects: false,
         }
     }
 }
@@ -119,12 +187,72 @@ impl SimulationBuilder {
         self
     }
 
+    /// Sets the characters that alive and dead cells are printed as, such as `'█'` and `'·'`
+    /// (`'*'` and `'-'` by default).
+    pub fn render_chars(mut self, alive: char, dead: char) -> Self {
+        self.render_chars = (alive, dead);
+        self
+    }
+
     /// Enables or disables displaying the simulation in a window.
     pub fn display(mut self, display: bool) -> Self {
         self.display = display;
         self
     }
 
+    /// Sets the birth and survival rule used to compute each new generation (Conway's Game of
+    /// Life, B3/S23, by default).
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self.rule_error = None;
+        self
+    }
+
+    /// Sets the birth and survival rule to one of the named presets, such as `Preset::HighLife`.
+    pub fn rule_preset(self, preset: Preset) -> Self {
+        self.rule(preset.rule())
+    }
+
+    /// Sets the birth and survival rule from B/S notation, such as `"B36/S23"` for HighLife
+    /// (see `Rule::from_str` for the accepted notations).
+    ///
+    /// # Note
+    /// If the rule can not be parsed, `build` returns the parsing error.
+    pub fn rule_str(mut self, rule: &str) -> Self {
+        match rule.parse::<Rule>() {
+            Ok(rule) => self.rule(rule),
+            Err(error) => {
+                self.rule_error = Some(error);
+                self
+            }
+        }
+    }
+
+    /// Sets the cells that count as the neighbors of each cell (`Moore`, all eight surrounding
+    /// cells, by default).
+    pub fn neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    /// Enables or disables treating generations that are translated copies of a saved
+    /// generation (such as a lone spaceship) as cycles when detecting a finished simulation.
+    pub fn detect_translated_cycles(mut self, detect_translated_cycles: bool) -> Self {
+        self.detect_translated_cycles = detect_translated_cycles;
+        self
+    }
+
+    /// Enables or disables counting the births and deaths that bounded edges caused (see
+    /// `Simulation::boundary_effect_counts`).
+    ///
+    /// # Note
+    /// Tracking evaluates the neighbors of every edge cell a second time in each generation,
+    /// so it is disabled by default.
+    pub fn track_boundary_effects(mut self, track_boundary_effects: bool) -> Self {
+        self.track_boundary_effects = track_boundary_effects;
+        self
+    }
+
     /// Sets the width of the display window.
     pub fn window_width(mut self, window_width: u16) -> Self {
         self.window_width = Some(window_width);
@@ -150,19 +278,22 @@ impl SimulationBuilder {
         self
     }
 
-    /// Sets the width of each cell in the display.
+    /// Sets the width of each cell in the display (and in rendered images, such as those from
+    /// `Simulation::record_gif`).
     pub fn cell_width(mut self, cell_width: u16) -> Self {
         self.cell_width = Some(cell_width);
         self
     }
 
-    /// Sets the height of each cell in the display.
+    /// Sets the height of each cell in the display (and in rendered images, such as those from
+    /// `Simulation::record_gif`).
     pub fn cell_height(mut self, cell_height: u16) -> Self {
         self.cell_height = Some(cell_height);
         self
     }
 
-    /// Sets the width and height of each cell in the display to the same value.
+    /// Sets the width and height of each cell in the display (and in rendered images) to the
+    /// same value.
     pub fn cell_size(mut self, cell_size: u16) -> Self {
         self.cell_width = Some(cell_size);
         self.cell_height = Some(cell_size);
@@ -274,13 +405,13 @@ impl SimulationBuilder {
         self
     }
 
-    /// Sets the number of rows in the simulation.
+    /// Sets the number of rows in the simulation (its height in cells).
     pub fn height(mut self, rows: u16) -> Self {
         self.rows = Some(rows);
         self
     }
 
-    /// Sets the number of columns in the simulation.
+    /// Sets the number of columns in the simulation (its width in cells).
     pub fn width(mut self, columns: u16) -> Self {
         self.columns = Some(columns);
         self
@@ -310,12 +441,280 @@ impl SimulationBuilder {
         self
     }
 
+    /// Sets the surface type to Möbius strip for the simulation.
+    pub fn surface_mobius(mut self) -> Self {
+        self.surface_type = Mobius;
+        self
+    }
+
+    /// Sets the surface type to Klein bottle for the simulation.
+    pub fn surface_klein_bottle(mut self) -> Self {
+        self.surface_type = KleinBottle;
+        self
+    }
+
+    /// Sets the surface type of the simulation to a custom surface, where the given function
+    /// decides how neighbor positions wrap onto the grid.
+    ///
+    /// # Description
+    /// To find the neighbors of a cell, the function is called with the position of each of
+    /// its neighbors (the cell's row and column plus each offset of the neighborhood) along
+    /// with the number of rows and columns in the grid. The position can be negative or past
+    /// the last row or column, and the function returns the cell on the grid it wraps to, or
+    /// `None` if there is no neighbor there (as past the edge of a `Rectangle`). The function is
+    /// also called for positions within the grid, which it usually returns unchanged.
+    ///
+    /// As with the other surface types, each distinct cell is counted once as a neighbor, a
+    /// cell is never its own neighbor, and positions returned outside of the grid are treated
+    /// as `None`.
+    ///
+    /// # Arguments
+    /// * `wrap` - The function from `(row, column, rows, columns)` to the wrapped position.
+    ///
+    /// # Note
+    /// Every cell of the grid is evaluated every generation, since the function can wrap
+    /// neighbors anywhere. Simulations with a custom surface can not be saved with
+    /// `save_to_file` or serialized, and do not track boundary effects. Patterns inserted past
+    /// an edge are placed with the function, and must not fall on a position it maps to `None`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// // A surface that wraps on every edge, the same as the ball surface
+    /// let mut custom: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .surface_custom(|row, column, rows, columns| {
+    ///         Some((
+    ///             row.rem_euclid(rows as i32) as u16,
+    ///             column.rem_euclid(columns as i32) as u16,
+    ///         ))
+    ///     })
+    ///     .seed_named("glider")
+    ///     .build()
+    ///     .unwrap();
+    /// let mut ball: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .surface_ball()
+    ///     .seed_named("glider")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// custom.simulate_generations(40);
+    /// ball.simulate_generations(40);
+    /// assert_eq!(custom.generation_string(), ball.generation_string());
+    /// ```
+    pub fn surface_custom<F>(mut self, wrap: F) -> Self
+    where
+        F: Fn(i32, i32, u16, u16) -> Option<(u16, u16)> + Send + Sync + 'static,
+    {
+        self.surface_type = Custom(Arc::new(wrap));
+        self
+    }
+
     /// Sets the initial seed string for the simulation.
+    ///
+    /// # Note
+    /// The seed can be written in any `SeedAlphabet` (`'*'` and `'-'`, `'1'` and `'0'`, or `'O'`
+    /// and `'.'`), and whitespace within a line is ignored. The simulation's `seed` is always
+    /// written with `'*'` and `'-'`.
+    ///
+    /// If the seed has more than one line, each line is a row, and the number of rows and
+    /// columns are taken from the lines (blank lines are skipped). Building fails if the lines
+    /// have different lengths, or do not match the number of rows or columns that was set.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .seed(
+    ///         "
+    ///         .O.
+    ///         .O.
+    ///         .O.
+    ///         ",
+    ///     )
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(simulation.seed(), "-*--*--*-");
+    /// assert_eq!((simulation.height(), simulation.width()), (3, 3));
+    ///
+    /// // Every line must be a full row
+    /// assert!(SimulationBuilder::new().seed("**\n*").build().is_err());
+    /// ```
     pub fn seed(mut self, seed: &str) -> Self {
         self.seed = Some(String::from(seed));
         self
     }
 
+    /// Sets the initial seed to a named pattern from the pattern lexicon (such as
+    /// `"r-pentomino"`), centered on the grid.
+    ///
+    /// # Note
+    /// If the number of rows or columns is not set, the grid will be exactly as large as the
+    /// pattern in that dimension. See `patterns::lookup` for how names are matched.
+    pub fn seed_named(mut self, name: &str) -> Self {
+        self.seed_name = Some(String::from(name));
+        self
+    }
+
+    /// Sets the initial seed to one created by a structured seed generator (see the `seeding`
+    /// module) when the simulation is built.
+    ///
+    /// # Note
+    /// Both the number of rows and columns must be set. The seed is generated with
+    /// `rand::thread_rng`, or with a seeded random number generator if `rng_seed` is set.
+    pub fn seed_generator(mut self, seed_generator: SeedGenerator) -> Self {
+        self.seed_generator = Some(seed_generator);
+        self
+    }
+
+    /// Sets the initial seed to the cells for which the given function returns true.
+    ///
+    /// # Description
+    /// When the simulation is built, the function is called with the row and column of every
+    /// cell in the grid, and the cell is alive in the seed if it returns true. This makes
+    /// structured seeds such as checkerboards, stripes, or rings simple to describe. The
+    /// simulation's `seed` is the resulting generation as a seed string.
+    ///
+    /// # Arguments
+    /// * `seed_fn` - The function that returns whether the cell at a row and column is alive.
+    ///
+    /// # Note
+    /// Both the number of rows and columns must be set. The function takes priority over every
+    /// other seed (including a random seed's `alive_probability`), which are ignored if it is
+    /// set.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .height(3)
+    ///     .width(4)
+    ///     .seed_fn(|row, column| (row + column) % 2 == 0)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(simulation.seed(), "*-*--*-**-*-");
+    ///
+    /// assert!(SimulationBuilder::new()
+    ///     .height(3)
+    ///     .seed_fn(|row, _| row == 0)
+    ///     .build()
+    ///     .is_err());
+    /// ```
+    pub fn seed_fn<F>(mut self, seed_fn: F) -> Self
+    where
+        F: Fn(u16, u16) -> bool + Send + Sync + 'static,
+    {
+        self.seed_fn = Some(Arc::new(seed_fn));
+        self
+    }
+
+    /// Sets the initial seed to a pattern in the plaintext (`.cells`) format.
+    ///
+    /// # Note
+    /// If the number of rows or columns is not set, it is taken from the number of rows or the
+    /// length of the longest row in the pattern. Larger grids are padded with dead cells below
+    /// and to the right of the pattern. See `simulation::generation_from_plaintext` for the
+    /// accepted format.
+    pub fn seed_plaintext(mut self, plaintext: &str) -> Self {
+        self.seed_plaintext = Some(String::from(plaintext));
+        self
+    }
+
+    /// Sets the initial seed to a pattern in the RLE format (such as `"3o$2bo$bo!"`).
+    ///
+    /// # Note
+    /// If the number of rows or columns is not set, it is taken from the pattern. Larger grids
+    /// are padded with dead cells below and to the right of the pattern. See
+    /// `simulation::generation_from_rle` for the accepted format.
+    pub fn seed_rle(mut self, rle: &str) -> Self {
+        self.seed_rle = Some(String::from(rle));
+        self
+    }
+
+    /// Seeds the random number generator used for random seeds, so they are reproducible.
+    ///
+    /// # Description
+    /// The generator is a `rand::rngs::StdRng` seeded with the given number. It is used for the
+    /// random seed that the simulation is built with when no seed is provided, and for the seed
+    /// generator if one is set. The simulation keeps the generator, so later calls to
+    /// `Simulation::reset_to_rand` draw from the same stream. Two simulations built with the
+    /// same settings and `rng_seed` have the same seed and the same evolution.
+    ///
+    /// # Arguments
+    /// * `rng_seed` - The number to seed the random number generator with.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let builder: SimulationBuilder = SimulationBuilder::new().height(30).width(30).rng_seed(42);
+    /// let mut simulation: Simulation = builder.clone().build().unwrap();
+    /// let mut other: Simulation = builder.build().unwrap();
+    /// assert_eq!(simulation.seed(), other.seed());
+    ///
+    /// simulation.simulate_generations(10);
+    /// other.simulate_generations(10);
+    /// assert_eq!(simulation.generation_string(), other.generation_string());
+    /// ```
+    pub fn rng_seed(mut self, rng_seed: u64) -> Self {
+        self.rng_seed = Some(rng_seed);
+        self
+    }
+
+    /// Sets the chance that each cell of a random seed is alive.
+    ///
+    /// # Description
+    /// By default, every random seed (the seed the simulation is built with when no seed is
+    /// provided, and the seeds from `Simulation::reset_to_rand`) is drawn with a new random
+    /// chance, so one seed may be almost empty and the next almost full. With this set, every
+    /// random seed is drawn with the same chance instead, so runs can be compared. It has no
+    /// effect on provided seeds.
+    ///
+    /// # Arguments
+    /// * `alive_probability` - The chance that each cell is alive, from `0.0` to `1.0`.
+    ///
+    /// # Note
+    /// A chance outside of `0.0..=1.0` is an error when the simulation is built.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(200)
+    ///     .width(200)
+    ///     .alive_probability(0.3)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Every random seed is close to 30% alive
+    /// for _ in 0..5 {
+    ///     assert!((simulation.alive_proportion() - 0.3).abs() < 0.02);
+    ///     simulation.reset_to_rand();
+    /// }
+    /// assert!(SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .alive_probability(1.5)
+    ///     .build()
+    ///     .is_err());
+    /// ```
+    pub fn alive_probability(mut self, alive_probability: f64) -> Self {
+        self.random_alive_probability = Some(alive_probability);
+        self
+    }
+
     /// Sets the maximum number of generations to retain in the save history.
     pub fn maximum_saves(mut self, maximum_saves: u128) -> Self {
         self.maximum_saves = maximum_saves;
@@ -331,8 +730,12 @@ impl SimulationBuilder {
     ///
     /// This function performs the following steps:
     ///
-    /// 1. Determine the values for `rows`, `columns`, and `seed` based on the provided input.
-    /// If any of these values are missing or invalid, an error is returned.
+    /// 1. Determine the values for `rows`, `columns`, and `seed` based on the provided input,
+    ///    calling the seed function for every cell if one was provided, or looking up and
+    ///    centering the named pattern if a named seed was provided, or running the seed
+    ///    generator if one was provided, or parsing the plaintext or RLE pattern if one was
+    ///    provided.
+    ///    If any of these values are missing or invalid, an error is returned.
     /// 2. If the simulation is configured to display in a window, calculate the window
     /// dimensions and cell dimensions based on the provided values. If the required dimensions
     /// are not provided, an error is returned.
@@ -351,14 +754,148 @@ impl SimulationBuilder {
     /// representing an error message. The error message is returned if any of the provided
     /// parameters are invalid or if there are any issues during the construction of the
     /// simulation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// // The seed must have exactly one character for every cell
+    /// assert!(SimulationBuilder::new().height(4).width(9).seed("***").build().is_err());
+    /// assert!(SimulationBuilder::new()
+    ///     .height(2)
+    ///     .width(2)
+    ///     .seed("*****")
+    ///     .build()
+    ///     .is_err());
+    /// assert!(SimulationBuilder::new().height(2).width(2).seed("*-*-").build().is_ok());
+    ///
+    /// // A simulation must have at least one row and one column
+    /// assert!(SimulationBuilder::new().height(0).width(5).build().is_err());
+    /// assert!(SimulationBuilder::new().height(0).seed("").build().is_err());
+    /// assert!(SimulationBuilder::new().seed("").build().is_err());
+    /// ```
     pub fn build(self) -> Result<Simulation, String> {
-        let (rows, columns, seed) = match (self.rows, self.columns, self.seed) {
+        if let Some(error) = &self.rule_error {
+            return Err(error.clone());
+        }
+        if self.rows == Some(0) || self.columns == Some(0) {
+            return Err("A simulation must have at least one row and one column".to_string());
+        }
+        if let Some(alive_probability) = self.random_alive_probability {
+            if !(0.0..=1.0).contains(&alive_probability) {
+                return Err(format!(
+                    "The alive probability of random seeds must be from 0 to 1, not {}",
+                    alive_probability
+                ));
+            }
+        }
+        let seed_sources: usize = [
+            self.seed.is_some(),
+            self.seed_name.is_some(),
+            self.seed_generator.is_some(),
+            self.seed_plaintext.is_some(),
+            self.seed_rle.is_some(),
+        ]
+        .iter()
+        .filter(|provided| **provided)
+        .count();
+        if seed_sources > 1 && self.seed_fn.is_none() {
+            return Err(
+                "Only one of a seed, a named seed, a seed generator, a plaintext seed, or an RLE seed can be provided"
+                    .to_string(),
+            );
+        }
+        let mut rng: Option<StdRng> = self.rng_seed.map(StdRng::seed_from_u64);
+        let (rows, columns, seed) = match (&self.seed_fn, &self.seed_name, &self.seed_generator) {
+            (Some(seed_fn), _, _) => match (self.rows, self.columns) {
+                (Some(rows), Some(columns)) => {
+                    let mut generation: HashSet<Cell> = HashSet::new();
+                    for row in 0..rows {
+                        for column in 0..columns {
+                            if seed_fn(row, column) {
+                                generation.insert(Cell::new(ALIVE, row, column));
+                            }
+                        }
+                    }
+                    (
+                        Some(rows),
+                        Some(columns),
+                        Some(string_from_generation(generation, rows, columns)),
+                    )
+                }
+                _ => {
+                    return Err(
+                        "Both rows and columns must be provided when using a seed function"
+                            .to_string(),
+                    );
+                }
+            },
+            (None, Some(name), _) => {
+                let (rows, columns, seed) = named_seed(name, self.rows, self.columns)?;
+                (Some(rows), Some(columns), Some(seed))
+            }
+            (None, None, Some(seed_generator)) => match (self.rows, self.columns) {
+                (Some(rows), Some(columns)) => (
+                    Some(rows),
+                    Some(columns),
+                    Some(match &mut rng {
+                        Some(rng) => seed_generator.generate(rows, columns, rng),
+                        None => seed_generator.generate(rows, columns, &mut thread_rng()),
+                    }),
+                ),
+                _ => {
+                    return Err(
+                        "Both rows and columns must be provided when using a seed generator"
+                            .to_string(),
+                    );
+                }
+            },
+            (None, None, None) => match &self.seed_plaintext {
+                Some(plaintext) => {
+                    let (rows, columns, seed) = plaintext_seed(plaintext, self.rows, self.columns)?;
+                    (Some(rows), Some(columns), Some(seed))
+                }
+                None => match &self.seed_rle {
+                    Some(rle) => {
+                        let (rows, columns, seed) = rle_seed(rle, self.rows, self.columns)?;
+                        (Some(rows), Some(columns), Some(seed))
+                    }
+                    None => match &self.seed {
+                        Some(seed) if seed.contains('\n') => {
+                            let (rows, columns) =
+                                seed_grid_dimensions(seed, self.rows, self.columns)?;
+                            (Some(rows), Some(columns), Some(normalize_seed(seed)?))
+                        }
+                        seed => (
+                            self.rows,
+                            self.columns,
+                            seed.as_deref().map(normalize_seed).transpose()?,
+                        ),
+                    },
+                },
+            },
+        };
+        // The number of rows or columns a seed is divided into, which must fit in a u16
+        let dimension = |length: usize| {
+            u16::try_from(length).map_err(|_| {
+                format!(
+                    "The provided seed would be {} cells long in one dimension, which is more than the maximum of {}",
+                    length,
+                    u16::MAX
+                )
+            })
+        };
+        let (rows, columns, seed) = match (rows, columns, seed) {
             (Some(rows), Some(columns), Some(seed)) => (rows, columns, seed),
-            (Some(rows), Some(columns), None) => (rows, columns, random_seed(rows, columns)),
+            (Some(rows), Some(columns), None) => (
+                rows,
+                columns,
+                draw_random_seed(rows, columns, self.random_alive_probability, rng.as_mut()),
+            ),
             (Some(rows), None, Some(seed)) => {
-                let seed_length = seed.len() as u16;
-                if seed_length % rows == 0 {
-                    (rows, seed_length / rows, seed)
+                let seed_length: usize = seed.len();
+                if seed_length.is_multiple_of(rows as usize) {
+                    (rows, dimension(seed_length / rows as usize)?, seed)
                 } else {
                     return Err(format!(
                         "The provided seed of \"{}\", must be divisible by the number of rows: {}",
@@ -367,9 +904,9 @@ impl SimulationBuilder {
                 }
             }
             (None, Some(columns), Some(seed)) => {
-                let seed_length: u16 = seed.len() as u16;
-                if seed_length % columns == 0 {
-                    (seed_length / columns, columns, seed)
+                let seed_length: usize = seed.len();
+                if seed_length.is_multiple_of(columns as usize) {
+                    (dimension(seed_length / columns as usize)?, columns, seed)
                 } else {
                     return Err(format!(
                         "The provided seed of \"{}\", must be divisible by the number of columns: {}",
@@ -382,7 +919,7 @@ impl SimulationBuilder {
                 let sqrt: f32 = seed_length.sqrt();
                 let rounded_sqrt: f32 = sqrt.round();
                 if (rounded_sqrt * rounded_sqrt) as usize == seed.len() {
-                    let sqrt = rounded_sqrt as u16;
+                    let sqrt: u16 = dimension(rounded_sqrt as usize)?;
                     (sqrt, sqrt, seed)
                 } else {
                     return Err(format!(
@@ -403,83 +940,122 @@ impl SimulationBuilder {
             }
         };
 
-        let window_data: Option<SimulationWindowData> = if self.display {
-            let (window_width, window_height, cell_width, cell_height) = match (
-                self.window_width,
-                self.window_height,
-                self.cell_width,
-                self.cell_height,
-            ) {
-                (Some(window_width), Some(window_height), None, None) => {
-                    let cell_width: u16 = window_width / columns;
-                    let cell_height: u16 = window_height / rows;
-                    (window_width, window_height, cell_width, cell_height)
-                }
-                (None, None, Some(cell_width), Some(cell_height)) => {
-                    let window_width: u16 = cell_width * columns;
-                    let window_height: u16 = cell_height * rows;
-                    (window_width, window_height, cell_width, cell_height)
-                }
-                (
-                    Some(_window_width),
-                    Some(_window_height),
-                    Some(_cell_width),
-                    Some(_cell_height),
-                ) => {
-                    return Err(
-                        "Only cell dimensions or window dimensions can be provided, not both"
-                            .to_string(),
-                    );
-                }
-                _ => {
-                    return Err(
+        if rows == 0 || columns == 0 {
+            return Err(format!(
+                "A simulation must have at least one row and one column, not {}x{}",
+                rows, columns
+            ));
+        }
+        // The seed is checked before any window is opened
+        let generation: HashSet<Cell> = generation_from_string(seed.clone(), rows, columns)?;
+
+        let (window_data, cell_width, cell_height): (Option<SimulationWindowData>, u16, u16) =
+            if self.display {
+                let (window_width, window_height, cell_width, cell_height) = match (
+                    self.window_width,
+                    self.window_height,
+                    self.cell_width,
+                    self.cell_height,
+                ) {
+                    (Some(window_width), Some(window_height), None, None) => {
+                        let cell_width: u16 = window_width / columns;
+                        let cell_height: u16 = window_height / rows;
+                        (window_width, window_height, cell_width, cell_height)
+                    }
+                    (None, None, Some(cell_width), Some(cell_height)) => {
+                        let window_width: u16 = cell_width * columns;
+                        let window_height: u16 = cell_height * rows;
+                        (window_width, window_height, cell_width, cell_height)
+                    }
+                    (
+                        Some(_window_width),
+                        Some(_window_height),
+                        Some(_cell_width),
+                        Some(_cell_height),
+                    ) => {
+                        return Err(
+                            "Only cell dimensions or window dimensions can be provided, not both"
+                                .to_string(),
+                        );
+                    }
+                    _ => {
+                        return Err(
                         "If the simulation has a display, a cell or window size must be provided"
                             .to_string(),
                     );
-                }
+                    }
+                };
+                (
+                    Some(SimulationWindowData {
+                        window_width,
+                        window_height,
+                        window_title: self.window_title.clone(),
+                        window: Window::new(&self.window_title, window_width, window_height),
+                    }),
+                    cell_width,
+                    cell_height,
+                )
+            } else {
+                (
+                    None,
+                    self.cell_width
+                        .or(self.window_width.map(|window_width| window_width / columns))
+                        .unwrap_or(DEFAULT_CELL_SIZE),
+                    self.cell_height
+                        .or(self.window_height.map(|window_height| window_height / rows))
+                        .unwrap_or(DEFAULT_CELL_SIZE),
+                )
             };
-            Some(SimulationWindowData {
-                window_width,
-                window_height,
-                window_title: self.window_title.clone(),
-                cell_width,
-                cell_height,
-                window: Window::new(&*self.window_title, window_width, window_height),
-                cell_color: (
-                    self.cell_color_red,
-                    self.cell_color_green,
-                    self.cell_color_blue,
-                    self.cell_color_alpha,
-                ),
-                background_color: (
-                    self.background_color_red,
-                    self.background_color_green,
-                    self.background_color_blue,
-                    self.background_color_alpha,
-                ),
-                line_color: (
-                    self.line_color_red,
-                    self.line_color_green,
-                    self.line_color_blue,
-                    self.line_color_alpha,
-                ),
-                line_thickness: self.line_thickness,
-            })
-        } else {
-            None
+        let render_config: RenderConfig = RenderConfig {
+            cell_width,
+            cell_height,
+            cell_color: (
+                self.cell_color_red,
+                self.cell_color_green,
+                self.cell_color_blue,
+                self.cell_color_alpha,
+            ),
+            background_color: (
+                self.background_color_red,
+                self.background_color_green,
+                self.background_color_blue,
+                self.background_color_alpha,
+            ),
+            line_color: (
+                self.line_color_red,
+                self.line_color_green,
+                self.line_color_blue,
+                self.line_color_alpha,
+            ),
+            line_thickness: self.line_thickness,
         };
+        let statistics = Statistics::new(rows as usize * columns as usize, &generation);
         let mut simulation = Simulation {
-            seed: seed.clone(),
+            seed,
+            rng_seed: self.rng_seed,
+            rng,
+            random_alive_probability: self.random_alive_probability,
             surface_type: self.surface_type,
+            rule: self.rule,
+            neighborhood: self.neighborhood,
             rows,
             columns,
-            generation: generation_from_string(seed, columns).unwrap(),
+            generation,
             iteration: 0,
-            save_history: Vec::new(),
+            statistics,
+            generation_delta: GenerationDelta::default(),
+            save_history: SaveHistory::default(),
             maximum_saves: self.maximum_saves,
             display: self.display,
             print: self.print,
+            detect_translated_cycles: self.detect_translated_cycles,
+            track_boundary_effects: self.track_boundary_effects,
+            stamp_palette: Vec::new(),
+            stamp_collision_mode: CollisionMode::Merge,
+            render_config,
+            render_chars: self.render_chars,
             window_data,
+            observers: Observers::default(),
         };
         if simulation.display {
             simulation.draw_generation();
@@ -487,3 +1063,112 @@ impl SimulationBuilder {
         Ok(simulation)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn height_and_width_set_the_rows_and_columns() {
+        let simulation: Simulation = SimulationBuilder::new().height(4).width(9).build().unwrap();
+        assert_eq!((simulation.rows, simulation.columns), (4, 9));
+        assert_eq!((simulation.height(), simulation.width()), (4, 9));
+        assert_eq!(simulation.seed().len(), 36);
+    }
+
+    #[test]
+    fn height_and_width_can_be_set_in_any_order() {
+        let seed: &str = "-*-------*------***-------------------";
+        let height_first: Simulation = SimulationBuilder::new()
+            .height(2)
+            .width(19)
+            .seed(seed)
+            .build()
+            .unwrap();
+        let width_first: Simulation = SimulationBuilder::new()
+            .width(19)
+            .height(2)
+            .seed(seed)
+            .build()
+            .unwrap();
+        let inferred_width: Simulation = SimulationBuilder::new()
+            .height(2)
+            .seed(seed)
+            .build()
+            .unwrap();
+        for simulation in [&width_first, &inferred_width] {
+            assert_eq!(
+                (simulation.rows, simulation.columns),
+                (height_first.rows, height_first.columns)
+            );
+            assert_eq!(
+                simulation.generation_string(),
+                height_first.generation_string()
+            );
+        }
+    }
+
+    #[test]
+    fn surface_shortcuts_set_the_surface_type() {
+        let surface = |builder: SimulationBuilder| -> SurfaceType {
+            builder.height(5).width(5).build().unwrap().surface_type
+        };
+        assert!(matches!(surface(SimulationBuilder::new()), Rectangle));
+        assert!(matches!(
+            surface(SimulationBuilder::new().surface_ball().surface_rectangle()),
+            Rectangle
+        ));
+        assert!(matches!(
+            surface(SimulationBuilder::new().surface_ball()),
+            Ball
+        ));
+        assert!(matches!(
+            surface(SimulationBuilder::new().surface_horizontal_loop()),
+            HorizontalLoop
+        ));
+        assert!(matches!(
+            surface(SimulationBuilder::new().surface_vertical_loop()),
+            VerticalLoop
+        ));
+        assert!(matches!(
+            surface(SimulationBuilder::new().surface_mobius()),
+            Mobius
+        ));
+        assert!(matches!(
+            surface(SimulationBuilder::new().surface_klein_bottle()),
+            KleinBottle
+        ));
+    }
+
+    #[test]
+    fn loop_shortcuts_wrap_only_in_their_direction() {
+        // A vertical blinker on the top edge, and a horizontal one on the left edge
+        let vertical_edge: &str = "-*---\n-*---\n-----\n-----\n-*---";
+        let horizontal_edge: &str = "-----\n-----\n**--*\n-----\n-----";
+        let next = |builder: SimulationBuilder, seed: &str| -> String {
+            let mut simulation: Simulation = builder.seed(seed).build().unwrap();
+            simulation.simulate_generation();
+            simulation.generation_string()
+        };
+        let rectangle = || SimulationBuilder::new().surface_rectangle();
+        let vertical = || SimulationBuilder::new().surface_vertical_loop();
+        let horizontal = || SimulationBuilder::new().surface_horizontal_loop();
+
+        assert_ne!(
+            next(vertical(), vertical_edge),
+            next(rectangle(), vertical_edge)
+        );
+        assert_eq!(
+            next(horizontal(), vertical_edge),
+            next(rectangle(), vertical_edge)
+        );
+        assert_ne!(
+            next(horizontal(), horizontal_edge),
+            next(rectangle(), horizontal_edge)
+        );
+        assert_eq!(
+            next(vertical(), horizontal_edge),
+            next(rectangle(), horizontal_edge)
+        );
+    }
+}
@@ -1,8 +1,22 @@
-use crate::simulation::Simulation;
-use simple::{Rect, Window};
+use crate::patterns::{lookup, CollisionMode, Pattern, PatternDef, Transform};
+use crate::simulation::{RenderConfig, Simulation};
+use simple::{Event, Key, MouseButton, Rect, Window};
 use std::thread::sleep;
 use std::time::{Duration, Instant};
 
+/// The names of the patterns in the default stamp palette, in number key order.
+const DEFAULT_STAMP_PALETTE: [&str; 9] = [
+    "glider",
+    "blinker",
+    "block",
+    "lightweight spaceship",
+    "r-pentomino",
+    "beehive",
+    "toad",
+    "pulsar",
+    "gosper glider gun",
+];
+
 /// Represents the data related to the display window for the simulation.
 pub(crate) struct SimulationWindowData {
     /// The window object used for rendering the simulation.
@@ -13,18 +27,6 @@ pub(crate) struct SimulationWindowData {
     pub(crate) window_height: u16,
     /// The title of the display window.
     pub(crate) window_title: String,
-    /// The width of each cell in the display in pixels.
-    pub(crate) cell_width: u16,
-    /// The height of each cell in the display in pixels.
-    pub(crate) cell_height: u16,
-    /// The color of the cells in the display, represented as an RGBA tuple.
-    pub(crate) cell_color: (u8, u8, u8, u8),
-    /// The background color of the display, represented as an RGBA tuple.
-    pub(crate) background_color: (u8, u8, u8, u8),
-    /// The color of the grid lines in the display, represented as an RGBA tuple.
-    pub(crate) line_color: (u8, u8, u8, u8),
-    /// The thickness of the grid lines in the display in pixels.
-    pub(crate) line_thickness: u16,
 }
 
 impl Clone for SimulationWindowData {
@@ -35,17 +37,308 @@ impl Clone for SimulationWindowData {
             window_height: self.window_height,
             window_title: self.window_title.clone(),
             window: Window::new(&*self.window_title, self.window_width, self.window_height),
-            cell_width: self.cell_width,
-            cell_height: self.cell_height,
-            cell_color: self.cell_color,
-            background_color: self.background_color,
-            line_color: self.line_color,
-            line_thickness: self.line_thickness,
         }
     }
 }
 
+/// A rectangle filled when drawing a generation, in pixels. Rectangles can extend past the
+/// edges of the frame they are drawn on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct FrameRect {
+    /// The horizontal position of the left edge.
+    pub(crate) x: i32,
+    /// The vertical position of the top edge.
+    pub(crate) y: i32,
+    /// The width of the rectangle.
+    pub(crate) width: u32,
+    /// The height of the rectangle.
+    pub(crate) height: u32,
+}
+
+impl FrameRect {
+    /// Converts the rectangle into the rectangle type used by the display window.
+    fn to_window_rect(self) -> Rect {
+        Rect::new(self.x, self.y, self.width, self.height)
+    }
+}
+
+/// The stamp waiting to be placed when editing a simulation in its display window.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct PendingStamp {
+    /// The index of the selected pattern in the stamp palette.
+    pub(crate) selected: usize,
+    /// The rotation or reflection applied to the selected pattern.
+    pub(crate) transform: Transform,
+}
+
+impl PendingStamp {
+    /// Selects the pattern at the given index of the stamp palette, clearing any rotation or
+    /// reflection.
+    pub(crate) fn select(&mut self, index: usize) {
+        self.selected = index;
+        self.transform = Transform::Identity;
+    }
+
+    /// Rotates the stamp 90 degrees clockwise.
+    pub(crate) fn rotate(&mut self) {
+        self.transform = self.transform.then(Transform::Rotate90);
+    }
+
+    /// Mirrors the stamp left to right.
+    pub(crate) fn mirror(&mut self) {
+        self.transform = self.transform.then(Transform::FlipHorizontal);
+    }
+
+    /// Returns the selected pattern (in its first phase) with the stamp's transform applied, or
+    /// `None` if the palette has no pattern at the selected index.
+    pub(crate) fn pattern(&self, palette: &[PatternDef]) -> Option<Pattern> {
+        palette
+            .get(self.selected)
+            .map(|pattern| pattern.phases[0].transformed(self.transform))
+    }
+}
+
+/// Returns the index of the stamp palette selected by a number key, if the key is 1 to 9.
+fn palette_index(key: Key) -> Option<usize> {
+    match key {
+        Key::Num1 => Some(0),
+        Key::Num2 => Some(1),
+        Key::Num3 => Some(2),
+        Key::Num4 => Some(3),
+        Key::Num5 => Some(4),
+        Key::Num6 => Some(5),
+        Key::Num7 => Some(6),
+        Key::Num8 => Some(7),
+        Key::Num9 => Some(8),
+        _ => None,
+    }
+}
+
+/// Returns the rectangle of the cell at the given row and column when drawn with the given cell
+/// size.
+pub(crate) fn cell_rect(row: u16, column: u16, cell_width: u16, cell_height: u16) -> FrameRect {
+    FrameRect {
+        x: column as i32 * cell_width as i32,
+        y: row as i32 * cell_height as i32,
+        width: cell_width as u32,
+        height: cell_height as u32,
+    }
+}
+
 impl Simulation {
+    /// Sets the patterns selected with the number keys 1 to 9 when editing the simulation in
+    /// its display window (see `run_interactive`). Only the first 9 patterns can be selected.
+    pub fn set_stamp_palette(&mut self, palette: Vec<PatternDef>) {
+        self.stamp_palette = palette;
+    }
+
+    /// Sets how patterns stamped in the display window treat the cells already there.
+    pub fn set_stamp_collision_mode(&mut self, collision_mode: CollisionMode) {
+        self.stamp_collision_mode = collision_mode;
+    }
+
+    /// Returns the stamp palette, or the default palette if none was set.
+    fn stamp_palette_or_default(&self) -> Vec<PatternDef> {
+        if self.stamp_palette.is_empty() {
+            DEFAULT_STAMP_PALETTE
+                .iter()
+                .filter_map(|name| lookup(name))
+                .collect()
+        } else {
+            self.stamp_palette.clone()
+        }
+    }
+
+    /// Returns the position of a stamp's top-left corner when it is centered on the given cell,
+    /// or `None` if it does not fit on the grid.
+    ///
+    /// # Note
+    /// On bounded edges, the stamp is moved inward so that it fits whenever it is no larger
+    /// than the grid.
+    pub(crate) fn stamp_placement(
+        &self,
+        pattern: &Pattern,
+        row: u16,
+        column: u16,
+    ) -> Option<(u16, u16)> {
+        let place = |center: u16, size: u16, length: u16, wraps: bool| -> Option<u16> {
+            if wraps {
+                Some(((center as i32 - (size / 2) as i32).rem_euclid(length as i32)) as u16)
+            } else if size > length {
+                None
+            } else {
+                Some(center.saturating_sub(size / 2).min(length - size))
+            }
+        };
+        Some((
+            place(
+                row,
+                pattern.rows,
+                self.rows,
+                self.surface_type.wraps_vertically(),
+            )?,
+            place(
+                column,
+                pattern.columns,
+                self.columns,
+                self.surface_type.wraps_horizontally(),
+            )?,
+        ))
+    }
+
+    /// Returns the grid cells covered by a stamp centered on the given cell, or an empty list if
+    /// it does not fit on the grid.
+    pub(crate) fn stamp_preview_cells(
+        &self,
+        pattern: &Pattern,
+        row: u16,
+        column: u16,
+    ) -> Vec<(u16, u16)> {
+        self.stamp_placement(pattern, row, column)
+            .and_then(|(top, left)| {
+                self.place_cells(pattern.cells.iter().copied(), pattern, top, left, false)
+                    .ok()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the cell under the given window position, if it is within the grid.
+    fn cell_at_position(&self, x: i32, y: i32) -> Option<(u16, u16)> {
+        self.window_data.as_ref()?;
+        if x < 0 || y < 0 {
+            return None;
+        }
+        let row: u16 = (y / self.render_config.cell_height as i32) as u16;
+        let column: u16 = (x / self.render_config.cell_width as i32) as u16;
+        if row < self.rows && column < self.columns {
+            Some((row, column))
+        } else {
+            None
+        }
+    }
+
+    /// Draws a ghost preview of a stamp's cells on the simulation display window, in a color
+    /// halfway between the cell color and the background color.
+    fn draw_stamp_preview(&mut self, cells: &[(u16, u16)]) {
+        let style: RenderConfig = self.render_config;
+        let window_data: &mut SimulationWindowData = self.window_data.as_mut().unwrap();
+        let (cell, background) = (style.cell_color, style.background_color);
+        let blend = |a: u8, b: u8| ((a as u16 + b as u16) / 2) as u8;
+        window_data.window.set_color(
+            blend(cell.0, background.0),
+            blend(cell.1, background.1),
+            blend(cell.2, background.2),
+            cell.3,
+        );
+        for (row, column) in cells {
+            window_data.window.fill_rect(
+                cell_rect(*row, *column, style.cell_width, style.cell_height).to_window_rect(),
+            );
+        }
+    }
+
+    /// Runs the simulation in its display window, allowing patterns to be stamped into it
+    /// with the mouse.
+    ///
+    /// # Description
+    /// The simulation starts paused. The following controls are available:
+    ///
+    /// * `Space` - Pauses or resumes the simulation.
+    /// * `1` to `9` - Selects a pattern from the stamp palette (see `set_stamp_palette`).
+    /// * `T` - Rotates the selected pattern 90 degrees clockwise.
+    /// * `Y` - Mirrors the selected pattern left to right.
+    /// * `Left click` - Stamps the selected pattern centered on the clicked cell while paused,
+    ///   using the stamp collision mode (see `set_stamp_collision_mode`).
+    ///
+    /// While paused, a ghost preview of the selected pattern follows the cursor. While running,
+    /// one generation is simulated every `cooldown`. This function returns once the window is
+    /// closed.
+    ///
+    /// # Arguments
+    /// * `cooldown` - The duration to wait between generations while running.
+    ///
+    /// # Panics
+    /// This function panics if the simulation was built without a display.
+    pub fn run_interactive(&mut self, cooldown: Duration) {
+        assert!(
+            self.window_data.is_some(),
+            "Only simulations with a display can be run interactively"
+        );
+        let palette: Vec<PatternDef> = self.stamp_palette_or_default();
+        let mut stamp: PendingStamp = PendingStamp {
+            selected: 0,
+            transform: Transform::Identity,
+        };
+        let mut paused: bool = true;
+        let mut last_step: Instant = Instant::now();
+        loop {
+            while self.window_data.as_ref().unwrap().window.has_event() {
+                match self.window_data.as_mut().unwrap().window.next_event() {
+                    Event::Keyboard { is_down: true, key } => match key {
+                        Key::Space => paused = !paused,
+                        Key::T => stamp.rotate(),
+                        Key::Y => stamp.mirror(),
+                        _ => {
+                            if let Some(index) = palette_index(key) {
+                                if index < palette.len() {
+                                    stamp.select(index);
+                                }
+                            }
+                        }
+                    },
+                    Event::Mouse {
+                        is_down: true,
+                        button: MouseButton::Left,
+                        mouse_x,
+                        mouse_y,
+                    } if paused => {
+                        let pattern: Option<Pattern> = stamp.pattern(&palette);
+                        if let (Some(pattern), Some((row, column))) =
+                            (pattern, self.cell_at_position(mouse_x, mouse_y))
+                        {
+                            if let Some((top, left)) = self.stamp_placement(&pattern, row, column) {
+                                // A rejected stamp simply leaves the generation unchanged, and
+                                // the generation is drawn below along with everything else
+                                self.display = false;
+                                let _ = self.insert_pattern(
+                                    &pattern,
+                                    top,
+                                    left,
+                                    self.stamp_collision_mode,
+                                );
+                                self.display = true;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            if !paused && last_step.elapsed() >= cooldown {
+                last_step = Instant::now();
+                // The generation is drawn below along with everything else in this frame
+                self.display = false;
+                self.simulate_generation();
+                self.display = true;
+            }
+            self.draw_alive_cells();
+            if paused {
+                let (x, y) = self.window_data.as_ref().unwrap().window.mouse_position();
+                let preview: Vec<(u16, u16)> =
+                    match (stamp.pattern(&palette), self.cell_at_position(x, y)) {
+                        (Some(pattern), Some((row, column))) => {
+                            self.stamp_preview_cells(&pattern, row, column)
+                        }
+                        _ => Vec::new(),
+                    };
+                self.draw_stamp_preview(&preview);
+            }
+            self.draw_cell_grid();
+            if !self.window_data.as_mut().unwrap().window.next_frame() {
+                break;
+            }
+        }
+    }
+
     /// Draws the grid lines representing the cell boundaries on the simulation display window.
     ///
     /// # Description
@@ -60,28 +353,19 @@ impl Simulation {
     /// This function should be called after the alive cells have been drawn to ensure that the
     /// grid lines are visible on top of the cells.
     fn draw_cell_grid(&mut self) {
+        let window_data: &SimulationWindowData = self.window_data.as_ref().unwrap();
+        let lines: Vec<FrameRect> = self.grid_line_rects(
+            &self.render_config,
+            window_data.window_width as u32,
+            window_data.window_height as u32,
+        );
+        let line_color: (u8, u8, u8, u8) = self.render_config.line_color;
         let window_data: &mut SimulationWindowData = self.window_data.as_mut().unwrap();
-        let line_color: (u8, u8, u8, u8) = window_data.line_color;
         window_data
             .window
             .set_color(line_color.0, line_color.1, line_color.2, line_color.3);
-        let cell_width: u16 = window_data.cell_width;
-        let cell_height: u16 = window_data.cell_height;
-        for column in 1..self.columns {
-            window_data.window.fill_rect(Rect::new(
-                ((column * cell_width) - (window_data.line_thickness / 2)) as i32,
-                0,
-                window_data.line_thickness as u32,
-                window_data.window_height as u32,
-            ));
-        }
-        for row in 1..self.rows {
-            window_data.window.fill_rect(Rect::new(
-                0,
-                ((row * cell_height) - (window_data.line_thickness / 2)) as i32,
-                window_data.window_width as u32,
-                window_data.line_thickness as u32,
-            ));
+        for line in lines {
+            window_data.window.fill_rect(line.to_window_rect());
         }
     }
 
@@ -102,8 +386,10 @@ impl Simulation {
     /// This function should be called before drawing the grid lines to ensure that the alive
     /// cells are visible underneath the grid lines.
     fn draw_alive_cells(&mut self) {
+        let style: RenderConfig = self.render_config;
+        let cells: Vec<FrameRect> = self.alive_cell_rects(&style);
         let window_data: &mut SimulationWindowData = self.window_data.as_mut().unwrap();
-        let background_color: (u8, u8, u8, u8) = window_data.background_color;
+        let background_color: (u8, u8, u8, u8) = style.background_color;
         window_data.window.set_color(
             background_color.0,
             background_color.1,
@@ -116,26 +402,61 @@ impl Simulation {
             window_data.window_width as u32,
             window_data.window_height as u32,
         ));
-        let cell_color: (u8, u8, u8, u8) = window_data.cell_color;
+        let cell_color: (u8, u8, u8, u8) = style.cell_color;
         window_data
             .window
             .set_color(cell_color.0, cell_color.1, cell_color.2, cell_color.3);
-        let cell_width: u16 = window_data.cell_width;
-        let cell_height: u16 = window_data.cell_height;
-        for cell in &self.generation {
-            if cell.is_alive() {
-                let x: i32 = (cell.column * cell_width) as i32;
-                let y: i32 = (cell.row * cell_height) as i32;
-                window_data.window.fill_rect(Rect::new(
-                    x,
-                    y,
-                    cell_width as u32,
-                    cell_height as u32,
-                ));
-            }
+        for cell in cells {
+            window_data.window.fill_rect(cell.to_window_rect());
         }
     }
 
+    /// Returns the rectangles of the alive cells in the current generation when drawn with the
+    /// given configuration's cell size.
+    ///
+    /// # Note
+    /// This is shared by the display window and by images of the simulation (such as those from
+    /// `export_png`), so both draw generations identically.
+    pub(crate) fn alive_cell_rects(&self, config: &RenderConfig) -> Vec<FrameRect> {
+        self.alive_cells_iter()
+            .map(|(row, column)| cell_rect(row, column, config.cell_width, config.cell_height))
+            .collect()
+    }
+
+    /// Returns the rectangles of the grid lines between the cells when drawn with the given
+    /// configuration on a frame of the given width and height.
+    ///
+    /// # Description
+    /// A vertical line is drawn between each pair of columns and a horizontal line between each
+    /// pair of rows, spanning the whole frame. Each line is as thick as the configuration's line
+    /// thickness and is centered on the edge between the cells.
+    ///
+    /// # Note
+    /// This is shared by the display window and by images of the simulation (such as those from
+    /// `export_png`), so both draw generations identically.
+    pub(crate) fn grid_line_rects(
+        &self,
+        config: &RenderConfig,
+        width: u32,
+        height: u32,
+    ) -> Vec<FrameRect> {
+        let (cell_width, cell_height) = (config.cell_width, config.cell_height);
+        let thickness: u16 = config.line_thickness;
+        let vertical = (1..self.columns).map(|column| FrameRect {
+            x: column as i32 * cell_width as i32 - (thickness / 2) as i32,
+            y: 0,
+            width: thickness as u32,
+            height,
+        });
+        let horizontal = (1..self.rows).map(|row| FrameRect {
+            x: 0,
+            y: row as i32 * cell_height as i32 - (thickness / 2) as i32,
+            width,
+            height: thickness as u32,
+        });
+        vertical.chain(horizontal).collect()
+    }
+
     /// Draws the current generation of cells on the simulation display window.
     ///
     /// # Description
@@ -153,16 +474,48 @@ impl Simulation {
     ///
     /// This function is called whenever the simulation generation changes to update the
     /// visualization in the display window.
+    ///
+    /// # Note
+    /// If the simulation was built without a display, there is no window, so nothing is drawn.
+    /// The same goes for `freeze_window`, `freeze_window_for`, and `quit_window`, which return
+    /// immediately.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let mut simulation: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .seed_named("glider")
+    ///     .display(false)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// simulation.simulate_generations(5);
+    /// simulation.rollback_generations(2);
+    /// simulation.draw_generation();
+    /// simulation.freeze_window_for(Duration::from_secs(60));
+    /// simulation.freeze_window();
+    /// simulation.quit_window();
+    /// ```
     pub fn draw_generation(&mut self) {
+        if self.window_data.is_none() {
+            return;
+        }
         self.draw_alive_cells();
         self.draw_cell_grid();
-        self.window_data.as_mut().unwrap().window.next_frame();
+        if let Some(window_data) = self.window_data.as_mut() {
+            window_data.window.next_frame();
+        }
     }
 
     /// Freezes the simulation window indefinitely to keep the current generation displayed.
     pub fn freeze_window(&mut self) {
-        loop {
-            self.window_data.as_mut().unwrap().window.next_frame();
+        while let Some(window_data) = self.window_data.as_mut() {
+            window_data.window.next_frame();
             sleep(Duration::from_millis(100));
         }
     }
@@ -171,17 +524,241 @@ impl Simulation {
     /// generation displayed.
     pub fn freeze_window_for(&mut self, duration: Duration) {
         let start_time = Instant::now();
-        loop {
+        while let Some(window_data) = self.window_data.as_mut() {
             if Instant::now().duration_since(start_time) >= duration {
                 break;
             }
-            self.window_data.as_mut().unwrap().window.next_frame();
+            window_data.window.next_frame();
             sleep(Duration::from_millis(100));
         }
     }
 
     /// Quits and closes the display window for the simulation.
-    pub fn quit_window(self) {
-        self.window_data.unwrap().window.quit();
+    ///
+    /// # Note
+    /// The simulation can still be used afterwards, but it is no longer displayed, so another
+    /// simulation can open a window (you can not have multiple windows at once).
+    pub fn quit_window(&mut self) {
+        if let Some(mut window_data) = self.window_data.take() {
+            window_data.window.quit();
+        }
+        self.display = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation_builder::SimulationBuilder;
+    use std::collections::HashSet;
+
+    /// Returns a stamp with the glider (the first pattern of the default palette) selected.
+    fn glider_stamp() -> PendingStamp {
+        PendingStamp {
+            selected: 0,
+            transform: Transform::Identity,
+        }
+    }
+
+    /// Returns the default stamp palette.
+    fn palette() -> Vec<PatternDef> {
+        DEFAULT_STAMP_PALETTE
+            .iter()
+            .filter_map(|name| lookup(name))
+            .collect()
+    }
+
+    /// Returns an empty 10x10 simulation with the given surface, without a window.
+    fn simulation(builder: fn(SimulationBuilder) -> SimulationBuilder) -> Simulation {
+        builder(SimulationBuilder::new().height(10).width(10))
+            .build()
+            .unwrap()
+    }
+
+    /// Returns the positions of the alive cells in a pattern.
+    fn cells(pattern: &Pattern) -> HashSet<(u16, u16)> {
+        pattern.cells.clone()
+    }
+
+    #[test]
+    fn stamp_pattern_is_the_first_phase_of_the_selected_pattern() {
+        let pattern: Pattern = glider_stamp().pattern(&palette()).unwrap();
+        assert_eq!(
+            cells(&pattern),
+            HashSet::from([(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)])
+        );
+    }
+
+    #[test]
+    fn rotated_stamp_pattern_is_turned_clockwise() {
+        let mut stamp: PendingStamp = glider_stamp();
+        stamp.rotate();
+        let pattern: Pattern = stamp.pattern(&palette()).unwrap();
+        assert_eq!(
+            cells(&pattern),
+            HashSet::from([(0, 0), (1, 0), (2, 0), (1, 2), (2, 1)])
+        );
+    }
+
+    #[test]
+    fn mirrored_stamp_pattern_is_flipped_left_to_right() {
+        let mut stamp: PendingStamp = glider_stamp();
+        stamp.mirror();
+        let pattern: Pattern = stamp.pattern(&palette()).unwrap();
+        assert_eq!(
+            cells(&pattern),
+            HashSet::from([(0, 1), (1, 0), (2, 0), (2, 1), (2, 2)])
+        );
+    }
+
+    #[test]
+    fn four_rotations_or_two_mirrors_restore_the_stamp() {
+        let palette: Vec<PatternDef> = palette();
+        let original: Pattern = glider_stamp().pattern(&palette).unwrap();
+
+        let mut rotated: PendingStamp = glider_stamp();
+        for _ in 0..4 {
+            rotated.rotate();
+        }
+        assert_eq!(rotated.pattern(&palette).unwrap(), original);
+
+        let mut mirrored: PendingStamp = glider_stamp();
+        mirrored.mirror();
+        mirrored.mirror();
+        assert_eq!(mirrored.pattern(&palette).unwrap(), original);
+    }
+
+    #[test]
+    fn rotating_a_stamp_swaps_its_dimensions() {
+        let palette: Vec<PatternDef> = palette();
+        let mut stamp: PendingStamp = glider_stamp();
+        stamp.select(3);
+        let upright: Pattern = stamp.pattern(&palette).unwrap();
+        stamp.rotate();
+        let rotated: Pattern = stamp.pattern(&palette).unwrap();
+        assert_eq!((upright.rows, upright.columns), (4, 5));
+        assert_eq!((rotated.rows, rotated.columns), (5, 4));
+    }
+
+    #[test]
+    fn selecting_a_stamp_clears_its_transform() {
+        let palette: Vec<PatternDef> = palette();
+        let mut stamp: PendingStamp = glider_stamp();
+        stamp.rotate();
+        stamp.mirror();
+        stamp.select(0);
+        assert_eq!(stamp.transform, Transform::Identity);
+        assert_eq!(stamp.pattern(&palette), glider_stamp().pattern(&palette));
+    }
+
+    #[test]
+    fn stamp_outside_the_palette_has_no_pattern() {
+        let mut stamp: PendingStamp = glider_stamp();
+        stamp.select(9);
+        assert_eq!(stamp.pattern(&palette()), None);
+    }
+
+    #[test]
+    fn stamp_is_moved_inward_on_bounded_edges() {
+        let simulation: Simulation = simulation(SimulationBuilder::surface_rectangle);
+        let pattern: Pattern = glider_stamp().pattern(&palette()).unwrap();
+        assert_eq!(simulation.stamp_placement(&pattern, 5, 5), Some((4, 4)));
+        assert_eq!(simulation.stamp_placement(&pattern, 0, 0), Some((0, 0)));
+        assert_eq!(simulation.stamp_placement(&pattern, 9, 9), Some((7, 7)));
+        assert_eq!(simulation.stamp_placement(&pattern, 0, 9), Some((0, 7)));
+    }
+
+    #[test]
+    fn stamp_larger_than_a_bounded_grid_has_no_placement() {
+        let simulation: Simulation = simulation(SimulationBuilder::surface_rectangle);
+        let pattern: Pattern = Pattern::from_cells([(0, 0), (10, 0)]);
+        assert_eq!(simulation.stamp_placement(&pattern, 5, 5), None);
+        assert!(simulation.stamp_preview_cells(&pattern, 5, 5).is_empty());
+    }
+
+    #[test]
+    fn stamp_wraps_around_wrapping_edges() {
+        let pattern: Pattern = glider_stamp().pattern(&palette()).unwrap();
+
+        let ball: Simulation = simulation(SimulationBuilder::surface_ball);
+        assert_eq!(ball.stamp_placement(&pattern, 0, 0), Some((9, 9)));
+
+        let horizontal_loop: Simulation = simulation(SimulationBuilder::surface_horizontal_loop);
+        assert_eq!(
+            horizontal_loop.stamp_placement(&pattern, 0, 0),
+            Some((0, 9))
+        );
+
+        let vertical_loop: Simulation = simulation(SimulationBuilder::surface_vertical_loop);
+        assert_eq!(vertical_loop.stamp_placement(&pattern, 0, 0), Some((9, 0)));
+    }
+
+    #[test]
+    fn stamp_preview_wraps_around_the_corner_of_a_ball() {
+        let simulation: Simulation = simulation(SimulationBuilder::surface_ball);
+        let pattern: Pattern = glider_stamp().pattern(&palette()).unwrap();
+        let preview: HashSet<(u16, u16)> = simulation
+            .stamp_preview_cells(&pattern, 0, 0)
+            .into_iter()
+            .collect();
+        assert_eq!(
+            preview,
+            HashSet::from([(9, 0), (0, 1), (1, 9), (1, 0), (1, 1)])
+        );
+    }
+
+    #[test]
+    fn rotated_stamp_preview_matches_the_rotated_pattern() {
+        let simulation: Simulation = simulation(SimulationBuilder::surface_rectangle);
+        let mut stamp: PendingStamp = glider_stamp();
+        stamp.rotate();
+        let pattern: Pattern = stamp.pattern(&palette()).unwrap();
+        let preview: HashSet<(u16, u16)> = simulation
+            .stamp_preview_cells(&pattern, 5, 5)
+            .into_iter()
+            .collect();
+        assert_eq!(
+            preview,
+            HashSet::from([(4, 4), (5, 4), (6, 4), (5, 6), (6, 5)])
+        );
+    }
+
+    #[test]
+    fn window_functions_do_nothing_without_a_window() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(10)
+            .width(10)
+            .seed_named("glider")
+            .display(false)
+            .build()
+            .unwrap();
+        // Turning the display on without a window must not try to draw
+        simulation.display = true;
+        simulation.simulate_generations(4);
+        assert_eq!(simulation.rollback_generations(2), 2);
+        simulation.draw_generation();
+        simulation.freeze_window();
+        let start: Instant = Instant::now();
+        simulation.freeze_window_for(Duration::from_secs(5));
+        assert!(start.elapsed() < Duration::from_secs(1));
+        simulation.quit_window();
+        assert!(!simulation.display);
+        assert!(simulation.window_data.is_none());
+        assert_eq!(simulation.iteration(), 2);
+    }
+
+    #[test]
+    fn headless_simulation_builds_simulates_and_rolls_back() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(10)
+            .width(10)
+            .seed_named("glider")
+            .build()
+            .unwrap();
+        assert!(simulation.window_data.is_none());
+        let seed: String = simulation.generation_string();
+        simulation.simulate_generations(8);
+        assert_eq!(simulation.rollback_generations(8), 8);
+        assert_eq!(simulation.generation_string(), seed);
     }
 }
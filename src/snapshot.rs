@@ -0,0 +1,342 @@
+//! Saving and loading simulations to and from snapshot files.
+//!
+//! A snapshot is a small, self-describing text file with one `key: value` line per setting,
+//! such as:
+//!
+//! ```text
+//! # Game of Life snapshot
+//! version: 1
+//! rows: 3
+//! columns: 3
+//! surface: ball
+//! rule: B3/S23
+//! neighborhood: moore
+//! iteration: 12
+//! seed: -*--*--*-
+//! generation: ---***---
+//! ```
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs::{read_to_string, rename, write};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::rule::Rule;
+use crate::simulation::{generation_from_string, Neighborhood, Simulation, SurfaceType};
+use crate::simulation_builder::SimulationBuilder;
+use crate::statistics::Statistics;
+
+/// The comment line every snapshot file starts with.
+const SNAPSHOT_HEADER: &str = "# Game of Life snapshot";
+/// The version of the snapshot format written by `save_to_file`.
+const SNAPSHOT_VERSION: u128 = 1;
+
+impl SurfaceType {
+    /// Returns the name of the surface type used in snapshot files, or `None` for a `Custom`
+    /// surface, whose function can not be saved.
+    fn snapshot_name(&self) -> Option<&'static str> {
+        match self {
+            SurfaceType::Ball => Some("ball"),
+            SurfaceType::HorizontalLoop => Some("horizontal-loop"),
+            SurfaceType::VerticalLoop => Some("vertical-loop"),
+            SurfaceType::Rectangle => Some("rectangle"),
+            SurfaceType::Mobius => Some("mobius"),
+            SurfaceType::KleinBottle => Some("klein-bottle"),
+            SurfaceType::Custom(_) => None,
+        }
+    }
+}
+
+impl Neighborhood {
+    /// Returns the name of the neighborhood used in snapshot files.
+    fn snapshot_name(&self) -> &'static str {
+        match self {
+            Neighborhood::Moore => "moore",
+            Neighborhood::VonNeumann => "von-neumann",
+        }
+    }
+}
+
+impl Simulation {
+    /// Saves the simulation to a snapshot file.
+    ///
+    /// # Description
+    /// The snapshot contains the number of rows and columns, the surface type, the rule, the
+    /// neighborhood, the iteration number, the seed, and the current generation, so the
+    /// simulation can be resumed later with `load_from_file`. The save history, statistics, and
+    /// display settings are not saved.
+    ///
+    /// The snapshot is first written to a temporary file next to `path`, which then replaces
+    /// `path`, so an interrupted save never leaves a partially written snapshot behind.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the snapshot file to write.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the snapshot was written.
+    /// * `Err(std::io::Error)` - An error if the file could not be written, or an `InvalidInput`
+    ///   error if the simulation has a custom surface (see `SimulationBuilder::surface_custom`),
+    ///   since its wrapping function can not be saved.
+    pub fn save_to_file(&self, path: &Path) -> io::Result<()> {
+        let surface: &str = self.surface_type.snapshot_name().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Simulations with a custom surface can not be saved to a snapshot",
+            )
+        })?;
+        let snapshot: String = format!(
+            "{}\nversion: {}\nrows: {}\ncolumns: {}\nsurface: {}\nrule: {}\nneighborhood: {}\niteration: {}\nseed: {}\ngeneration: {}\n",
+            SNAPSHOT_HEADER,
+            SNAPSHOT_VERSION,
+            self.rows,
+            self.columns,
+            surface,
+            self.rule,
+            self.neighborhood.snapshot_name(),
+            self.iteration,
+            self.seed,
+            self.generation_string()
+        );
+        let mut temporary_name: OsString = path.as_os_str().to_owned();
+        temporary_name.push(".tmp");
+        let temporary_path: PathBuf = PathBuf::from(temporary_name);
+        write(&temporary_path, snapshot)?;
+        rename(&temporary_path, path)
+    }
+
+    /// Loads a simulation from a snapshot file written by `save_to_file`.
+    ///
+    /// # Description
+    /// The simulation is rebuilt with the saved settings and seed, and then resumed at the saved
+    /// iteration with the saved generation as its current generation. The loaded simulation is
+    /// neither displayed nor printed, and its save history and statistics start over from the
+    /// saved generation.
+    ///
+    /// The `rule` and `neighborhood` lines are optional and default to Conway's rule and the
+    /// `Moore` neighborhood. The `version` line is also optional, since snapshots written before
+    /// the format was versioned do not have one, and such snapshots are read as version 1.
+    /// Empty lines and lines starting with `'#'` are ignored.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the snapshot file to read.
+    ///
+    /// # Returns
+    /// * `Ok(Simulation)` - The loaded simulation.
+    /// * `Err(String)` - An error message if the file could not be read, the snapshot was
+    ///   written by a newer version of the format, a line could not be parsed, a required line
+    ///   is missing, or the seed or generation is not `rows * columns` characters long.
+    pub fn load_from_file(path: &Path) -> Result<Simulation, String> {
+        let text: String = read_to_string(path)
+            .map_err(|error| format!("Could not read {}: {}", path.display(), error))?;
+        let mut values: HashMap<&str, &str> = HashMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line: &str = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once(':').ok_or_else(|| {
+                format!(
+                    "Line {} of the snapshot is not a \"key: value\" pair: \"{}\"",
+                    index + 1,
+                    line
+                )
+            })?;
+            values.insert(key.trim(), value.trim());
+        }
+        let value = |key: &str| -> Result<&str, String> {
+            values
+                .get(key)
+                .copied()
+                .ok_or_else(|| format!("The snapshot is missing the \"{}\" line", key))
+        };
+        let number = |key: &str| -> Result<u128, String> {
+            let text: &str = value(key)?;
+            text.parse::<u128>()
+                .map_err(|_| format!("The snapshot's {} of \"{}\" is not a number", key, text))
+        };
+
+        if values.contains_key("version") {
+            let version: u128 = number("version")?;
+            if version != SNAPSHOT_VERSION {
+                return Err(format!(
+                    "The snapshot uses version {} of the snapshot format, but only version {} is supported",
+                    version, SNAPSHOT_VERSION
+                ));
+            }
+        }
+
+        let rows: u16 = u16::try_from(number("rows")?)
+            .map_err(|_| String::from("The snapshot's number of rows is too large"))?;
+        let columns: u16 = u16::try_from(number("columns")?)
+            .map_err(|_| String::from("The snapshot's number of columns is too large"))?;
+        let iteration: u128 = number("iteration")?;
+        let seed: &str = value("seed")?;
+        let generation: &str = value("generation")?;
+        let area: usize = rows as usize * columns as usize;
+        for (name, string) in [("seed", seed), ("generation", generation)] {
+            if string.chars().count() != area {
+                return Err(format!(
+                    "The snapshot's {} is {} characters long, but a {}x{} grid needs {}",
+                    name,
+                    string.chars().count(),
+                    rows,
+                    columns,
+                    area
+                ));
+            }
+        }
+
+        let builder: SimulationBuilder = SimulationBuilder::new()
+            .height(rows)
+            .width(columns)
+            .seed(seed)
+            .display(false)
+            .print(false);
+        let builder: SimulationBuilder = match value("surface")? {
+            "ball" => builder.surface_ball(),
+            "horizontal-loop" => builder.surface_horizontal_loop(),
+            "vertical-loop" => builder.surface_vertical_loop(),
+            "rectangle" => builder.surface_rectangle(),
+            "mobius" => builder.surface_mobius(),
+            "klein-bottle" => builder.surface_klein_bottle(),
+            surface => {
+                return Err(format!(
+                    "The snapshot's surface of \"{}\" is not one of \"ball\", \"horizontal-loop\", \"vertical-loop\", \"rectangle\", \"mobius\", or \"klein-bottle\"",
+                    surface
+                ));
+            }
+        };
+        let builder: SimulationBuilder = match values.get("rule") {
+            Some(rule) => builder.rule(rule.parse::<Rule>()?),
+            None => builder,
+        };
+        let builder: SimulationBuilder = match values.get("neighborhood").copied() {
+            Some("moore") | None => builder.neighborhood(Neighborhood::Moore),
+            Some("von-neumann") => builder.neighborhood(Neighborhood::VonNeumann),
+            Some(neighborhood) => {
+                return Err(format!(
+                    "The snapshot's neighborhood of \"{}\" is not one of \"moore\" or \"von-neumann\"",
+                    neighborhood
+                ));
+            }
+        };
+
+        let mut simulation: Simulation = builder.build()?;
+        simulation.generation = generation_from_string(String::from(generation), rows, columns)?;
+        simulation.iteration = iteration;
+        simulation.statistics = Statistics::new(area, &simulation.generation);
+        Ok(simulation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{create_dir_all, remove_dir_all};
+
+    use super::*;
+    use crate::rule::Preset;
+
+    /// Creates an empty directory in the temporary directory that is unique to this test.
+    fn temporary_dir(name: &str) -> PathBuf {
+        let dir: PathBuf = temp_dir().join(format!(
+            "game_of_life_{}_snapshot_{}",
+            std::process::id(),
+            name
+        ));
+        create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn saved_simulations_load_with_the_same_state() {
+        let dir: PathBuf = temporary_dir("round_trip");
+        let builders: [SimulationBuilder; 6] = [
+            SimulationBuilder::new().surface_ball(),
+            SimulationBuilder::new().surface_horizontal_loop(),
+            SimulationBuilder::new().surface_vertical_loop(),
+            SimulationBuilder::new().surface_rectangle(),
+            SimulationBuilder::new().surface_mobius(),
+            SimulationBuilder::new()
+                .surface_klein_bottle()
+                .rule_preset(Preset::HighLife)
+                .neighborhood(Neighborhood::VonNeumann),
+        ];
+        for (index, builder) in builders.into_iter().enumerate() {
+            let mut simulation: Simulation = builder
+                .height(9)
+                .width(11)
+                .alive_probability(0.4)
+                .rng_seed(index as u64)
+                .display(false)
+                .build()
+                .unwrap();
+            simulation.simulate_generations(7);
+            let path: PathBuf = dir.join(format!("simulation_{}.txt", index));
+            simulation.save_to_file(&path).unwrap();
+
+            let loaded: Simulation = Simulation::load_from_file(&path).unwrap();
+            assert_eq!(loaded.generation_string(), simulation.generation_string());
+            assert_eq!(loaded.iteration(), 7);
+            assert_eq!(loaded.seed(), simulation.seed());
+            assert_eq!(loaded.rule(), simulation.rule());
+            assert_eq!(loaded.neighborhood(), simulation.neighborhood());
+            assert_eq!(
+                loaded.surface_type.snapshot_name(),
+                simulation.surface_type.snapshot_name()
+            );
+            assert_eq!((loaded.rows, loaded.columns), (9, 11));
+        }
+        let leftover_temporary_files: bool = dir
+            .read_dir()
+            .unwrap()
+            .any(|entry| entry.unwrap().path().extension() == Some("tmp".as_ref()));
+        remove_dir_all(&dir).unwrap();
+        assert!(!leftover_temporary_files);
+    }
+
+    #[test]
+    fn corrupt_snapshots_are_rejected() {
+        let dir: PathBuf = temporary_dir("corrupt");
+        let valid: &str = "rows: 3\ncolumns: 3\nsurface: ball\niteration: 2\nseed: -*--*--*-\ngeneration: ---***---\n";
+        let corrupt: [String; 8] = [
+            String::from("\u{0}\u{1}not a snapshot at all"),
+            valid.replace("rows: 3", "rows: three"),
+            valid.replace("iteration: 2\n", ""),
+            valid.replace("---***---", "---***--"),
+            valid.replace("ball", "sphere"),
+            valid.replace("rows: 3", "rows: 70000"),
+            format!("version: 2\n{}", valid),
+            format!("rule: B9/S23\n{}", valid),
+        ];
+        let path: PathBuf = dir.join("valid.txt");
+        write(&path, valid).unwrap();
+        assert!(Simulation::load_from_file(&path).is_ok());
+        for (index, text) in corrupt.iter().enumerate() {
+            let path: PathBuf = dir.join(format!("corrupt_{}.txt", index));
+            write(&path, text).unwrap();
+            assert!(Simulation::load_from_file(&path).is_err(), "{:?}", text);
+        }
+        assert!(Simulation::load_from_file(&dir.join("missing.txt")).is_err());
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn custom_surfaces_can_not_be_saved() {
+        let dir: PathBuf = temporary_dir("custom");
+        let simulation: Simulation = SimulationBuilder::new()
+            .height(4)
+            .width(4)
+            .surface_custom(|_, _, _, _| None)
+            .display(false)
+            .build()
+            .unwrap();
+        let path: PathBuf = dir.join("custom.txt");
+        let error: io::Error = simulation.save_to_file(&path).unwrap_err();
+        let exists: bool = path.exists();
+        remove_dir_all(&dir).unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(!exists);
+    }
+}
@@ -0,0 +1,326 @@
+//! Statistics collected while a simulation runs.
+
+use std::collections::HashSet;
+
+use crate::cell::Cell;
+use crate::cell::CellState::ALIVE;
+use crate::neighbors::NeighborGrid;
+use crate::simulation::{Simulation, SurfaceType};
+
+/// Counts of the fates that a single bounded edge changed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeStats {
+    /// The number of cells that stayed dead but would have been born if the surface wrapped.
+    pub suppressed_births: u64,
+    /// The number of cells that died but would have survived if the surface wrapped.
+    pub caused_deaths: u64,
+}
+
+/// Counts of the fates that the bounded edges of a simulation changed, compared to the same
+/// generations on a `Ball` (fully wrapping) surface.
+///
+/// # Note
+/// A corner cell is counted for both of the edges it touches, but only once in `total`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundaryStats {
+    /// The fates changed along the top edge.
+    pub top: EdgeStats,
+    /// The fates changed along the bottom edge.
+    pub bottom: EdgeStats,
+    /// The fates changed along the left edge.
+    pub left: EdgeStats,
+    /// The fates changed along the right edge.
+    pub right: EdgeStats,
+    /// The fates changed along any edge.
+    pub total: EdgeStats,
+}
+
+/// Represents the statistics collected while a simulation runs.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Statistics {
+    /// The total number of cells born since the simulation was built or last reset.
+    pub(crate) total_births: u64,
+    /// The total number of cells that died since the simulation was built or last reset.
+    pub(crate) total_deaths: u64,
+    /// The number of alive cells in each generation since the simulation was built or last
+    /// reset, starting with the seed generation.
+    pub(crate) population_history: Vec<u64>,
+    /// The number of cells that have changed state at least once, recorded for each
+    /// generation since the simulation was built or last reset.
+    pub(crate) active_cell_history: Vec<u64>,
+    /// The number of times each cell has changed state, indexed by `row * columns + column`.
+    pub(crate) activity_map: Vec<u32>,
+    /// The number of cells in the activity map that have changed state at least once.
+    pub(crate) active_cells: u64,
+    /// The fates changed by bounded edges, if the simulation tracks boundary effects.
+    pub(crate) boundary_stats: BoundaryStats,
+}
+
+impl Statistics {
+    /// Creates a new `Statistics` instance for a grid with the given number of cells, starting
+    /// from the given generation.
+    pub(crate) fn new(area: usize, generation: &HashSet<Cell>) -> Statistics {
+        Statistics {
+            total_births: 0,
+            total_deaths: 0,
+            population_history: vec![generation.len() as u64],
+            active_cell_history: vec![0],
+            activity_map: vec![0; area],
+            active_cells: 0,
+            boundary_stats: BoundaryStats::default(),
+        }
+    }
+
+    /// Records the changes between a generation and the generation that followed it.
+    pub(crate) fn record(
+        &mut self,
+        old_generation: &HashSet<Cell>,
+        new_generation: &HashSet<Cell>,
+        columns: u16,
+    ) {
+        for cell in old_generation.symmetric_difference(new_generation) {
+            if new_generation.contains(cell) {
+                self.total_births += 1;
+            } else {
+                self.total_deaths += 1;
+            }
+            let index: usize = cell.row as usize * columns as usize + cell.column as usize;
+            if self.activity_map[index] == 0 {
+                self.active_cells += 1;
+            }
+            self.activity_map[index] = self.activity_map[index].saturating_add(1);
+        }
+        self.population_history.push(new_generation.len() as u64);
+        self.active_cell_history.push(self.active_cells);
+    }
+
+    /// Forgets the per-generation history of the given number of most recent generations.
+    ///
+    /// # Note
+    /// Births, deaths, and the activity map are not rolled back, since they count changes that
+    /// already happened.
+    pub(crate) fn rollback(&mut self, iterations: usize) {
+        let length: usize = self
+            .population_history
+            .len()
+            .saturating_sub(iterations)
+            .max(1);
+        self.population_history.truncate(length);
+        self.active_cell_history.truncate(length);
+    }
+}
+
+impl Simulation {
+    /// Returns the number of times each cell has changed state since the simulation was built
+    /// or last reset, in row-major order (indexed by `row * columns + column`).
+    pub fn activity_map(&self) -> Vec<u32> {
+        self.statistics.activity_map.clone()
+    }
+
+    /// Returns the number of alive cells in each generation since the simulation was built or
+    /// last reset, starting with the seed generation.
+    pub fn population_history(&self) -> Vec<u64> {
+        self.statistics.population_history.clone()
+    }
+
+    /// Returns the number of births suppressed and deaths caused by each bounded edge since the
+    /// simulation was built or last reset.
+    ///
+    /// # Note
+    /// The counts are only collected if the simulation was built with
+    /// `track_boundary_effects(true)`, and are always zero on a `Ball` surface, since it has no
+    /// bounded edges.
+    pub fn boundary_effect_counts(&self) -> BoundaryStats {
+        self.statistics.boundary_stats
+    }
+
+    /// Counts the fates of edge cells that differ between the new generation and the
+    /// generation the current one would have produced on a fully wrapping surface.
+    ///
+    /// # Description
+    /// For every cell on a bounded edge, the number of alive neighbors is counted again as if
+    /// the surface wrapped on every edge. With a rule radius greater than 1, every cell within
+    /// the radius of a bounded edge counts as being on it, since its neighbors reach past it. A cell that stayed dead but would have been born with
+    /// the wrapped count is a suppressed birth, and a cell that died but would have survived with
+    /// the wrapped count is a caused death. Each is tallied for every bounded edge the cell lies
+    /// on.
+    ///
+    /// # Arguments
+    /// * `new_generation` - The generation computed from the current generation.
+    ///
+    /// # Note
+    /// On a `Mobius` surface, the wrapped counts are those of a `Ball`, so edge cells within
+    /// the radius of the left or right edge are compared without the flip. On a `Custom`
+    /// surface, the edges are not known, so nothing is counted.
+    pub(crate) fn record_boundary_effects(&mut self, new_generation: &HashSet<Cell>) {
+        if let SurfaceType::Custom(_) = self.surface_type {
+            return;
+        }
+        let bounded_vertically: bool = !self.surface_type.wraps_vertically();
+        let bounded_horizontally: bool = !self.surface_type.wraps_horizontally();
+        let mut stats: BoundaryStats = self.statistics.boundary_stats;
+        // The neighbors each edge cell would have if the surface wrapped on every edge
+        let wrapped_grid: NeighborGrid = NeighborGrid::new(
+            &self.generation,
+            self.rows,
+            self.columns,
+            &SurfaceType::Ball,
+            self.neighborhood,
+            self.rule.radius,
+        );
+        // With a larger radius, cells further in than the edge also have neighbors past it
+        let band: u16 = self.rule.radius as u16;
+        let near_left = |column: u16| bounded_horizontally && column < band;
+        let near_right =
+            |column: u16| bounded_horizontally && column >= self.columns.saturating_sub(band);
+        for row in 0..self.rows {
+            let on_top_edge: bool = bounded_vertically && row < band;
+            let on_bottom_edge: bool = bounded_vertically && row >= self.rows.saturating_sub(band);
+            let edge_columns: Vec<u16> = if on_top_edge || on_bottom_edge {
+                (0..self.columns).collect()
+            } else if bounded_horizontally {
+                (0..self.columns)
+                    .filter(|&column| near_left(column) || near_right(column))
+                    .collect()
+            } else {
+                continue;
+            };
+            for column in edge_columns {
+                let cell: Cell = Cell::new(ALIVE, row, column);
+                let was_alive: bool = self.generation.contains(&cell);
+                if new_generation.contains(&cell) {
+                    continue;
+                }
+                let wrapped_neighbors: u8 = wrapped_grid.alive_neighbors(row, column);
+                let would_be_alive: bool = if was_alive {
+                    self.rule.survives(wrapped_neighbors)
+                } else {
+                    self.rule.births(wrapped_neighbors)
+                };
+                if !would_be_alive {
+                    continue;
+                }
+                let edges: [(bool, &mut EdgeStats); 5] = [
+                    (on_top_edge, &mut stats.top),
+                    (on_bottom_edge, &mut stats.bottom),
+                    (near_left(column), &mut stats.left),
+                    (near_right(column), &mut stats.right),
+                    (true, &mut stats.total),
+                ];
+                for (on_edge, edge) in edges {
+                    if !on_edge {
+                        continue;
+                    }
+                    if was_alive {
+                        edge.caused_deaths += 1;
+                    } else {
+                        edge.suppressed_births += 1;
+                    }
+                }
+            }
+        }
+        self.statistics.boundary_stats = stats;
+    }
+
+    /// Returns true if the simulation has effectively stabilized over the most recent
+    /// generations.
+    ///
+    /// # Description
+    /// Exact periodicity detection misses soups that have settled into isolated oscillators
+    /// plus gliders that keep colliding on a wrapping surface, since the generation never
+    /// repeats exactly. This heuristic instead considers the simulation stagnant when, over the
+    /// last `window` generations, both of the following hold:
+    ///
+    /// 1. Every population stayed within `tolerance` (as a fraction, such as `0.05` for 5%) of
+    ///    the population at the start of the window.
+    /// 2. The number of cells in the activity map that have ever changed state did not grow,
+    ///    meaning nothing has reached a part of the grid that was not already active.
+    ///
+    /// # Arguments
+    /// * `window` - The number of most recent generations to consider.
+    /// * `tolerance` - The allowed fractional deviation of the population.
+    ///
+    /// # Returns
+    /// True if the simulation is stagnant, or false if it is not or fewer than `window`
+    /// generations have been simulated since it was built or last reset.
+    pub fn is_stagnant(&self, window: u128, tolerance: f64) -> bool {
+        let populations: &Vec<u64> = &self.statistics.population_history;
+        let active_cells: &Vec<u64> = &self.statistics.active_cell_history;
+        if window == 0 || window >= populations.len() as u128 {
+            return false;
+        }
+        let start: usize = populations.len() - 1 - window as usize;
+        let start_population: f64 = populations[start] as f64;
+        let band: f64 = start_population * tolerance;
+        let population_stable: bool = populations[start..]
+            .iter()
+            .all(|population| (*population as f64 - start_population).abs() <= band);
+        population_stable && active_cells[active_cells.len() - 1] <= active_cells[start]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulation::Simulation;
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// Builds a simulation of a named pattern centered on a grid with the given surface.
+    fn build(name: &str, size: u16, ball: bool) -> Simulation {
+        let builder: SimulationBuilder = SimulationBuilder::new()
+            .height(size)
+            .width(size)
+            .seed_named(name)
+            .display(false);
+        match ball {
+            true => builder.surface_ball(),
+            false => builder.surface_rectangle(),
+        }
+        .build()
+        .unwrap()
+    }
+
+    #[test]
+    fn stagnation_needs_a_full_window_of_generations() {
+        let mut simulation: Simulation = build("blinker", 5, false);
+        simulation.simulate_generations(2);
+        assert!(!simulation.is_stagnant(2, 0.0));
+        simulation.simulate_generation();
+        assert!(simulation.is_stagnant(2, 0.0));
+        assert!(!simulation.is_stagnant(3, 0.0));
+        assert!(!simulation.is_stagnant(0, 0.0));
+    }
+
+    #[test]
+    fn stagnation_allows_populations_within_the_tolerance() {
+        // The beacon alternates between 8 and 6 alive cells
+        let mut simulation: Simulation = build("beacon", 6, false);
+        simulation.simulate_generations(6);
+        assert_eq!(simulation.population_history(), vec![8, 6, 8, 6, 8, 6, 8]);
+        assert!(!simulation.is_stagnant(4, 0.1));
+        assert!(simulation.is_stagnant(4, 0.5));
+    }
+
+    #[test]
+    fn stagnation_requires_no_new_active_cells() {
+        // The glider's population never changes, but it keeps reaching new cells
+        let mut simulation: Simulation = build("glider", 20, true);
+        simulation.simulate_generations(20);
+        assert!(!simulation.is_stagnant(8, 0.0));
+
+        // Once the glider has crossed every cell of a small ball, nothing new is reached
+        let mut simulation: Simulation = build("glider", 6, true);
+        simulation.simulate_generations(40);
+        assert!(simulation.is_stagnant(8, 0.0));
+    }
+
+    #[test]
+    fn growing_patterns_are_not_stagnant() {
+        let mut simulation: Simulation = build("r-pentomino", 40, true);
+        simulation.simulate_generations(30);
+        assert!(!simulation.is_stagnant(5, 0.05));
+        assert!(!simulation.is_stagnant(5, 10.0));
+    }
+}
@@ -0,0 +1,248 @@
+//! Rendering generations as SVG images.
+//!
+//! SVG images are drawn from the same rectangles as the display window and PNG images (see
+//! `Simulation::frame_raster`), so they line up with them exactly but can be scaled to any size.
+
+use std::fmt::Write;
+
+use crate::simulation::{RenderConfig, Simulation};
+use crate::simulation_window::FrameRect;
+
+impl Simulation {
+    /// Renders the current generation as a self-contained SVG 1.1 image.
+    ///
+    /// # Description
+    /// The image has one `<rect>` for the background, one `<rect>` for each alive cell, and a
+    /// `<line>` for each grid line, using the colors and sizes of the given configuration. The
+    /// cells and grid lines are placed exactly where `render_to_pixel_buffer` draws them, so
+    /// the SVG image looks the same as the pixel image at its natural size of
+    /// `columns * cell_width` by `rows * cell_height`, and stays sharp at any other size.
+    ///
+    /// Colors with an alpha component below 255 are drawn with the matching opacity.
+    ///
+    /// # Arguments
+    /// * `config` - The colors and sizes to draw the generation with. Use `render_config` for
+    ///   the simulation's own colors and sizes.
+    ///
+    /// # Returns
+    /// A `String` containing the SVG document.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use simple_game_of_life::simulation::Simulation;
+    /// use simple_game_of_life::simulation_builder::SimulationBuilder;
+    ///
+    /// let simulation: Simulation = SimulationBuilder::new()
+    ///     .height(10)
+    ///     .width(10)
+    ///     .seed_named("glider")
+    ///     .display(false)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let svg: String = simulation.render_to_svg(&simulation.render_config());
+    /// std::fs::write("glider.svg", svg).unwrap();
+    /// ```
+    pub fn render_to_svg(&self, config: &RenderConfig) -> String {
+        let mut svg: String = String::new();
+        // Writing to a String never fails
+        self.write_svg(config, &mut svg).unwrap();
+        svg
+    }
+
+    /// Writes the SVG document of `render_to_svg` to the given output.
+    fn write_svg<W: Write>(&self, config: &RenderConfig, svg: &mut W) -> std::fmt::Result {
+        let width: u32 = self.columns as u32 * config.cell_width as u32;
+        let height: u32 = self.rows as u32 * config.cell_height as u32;
+        writeln!(svg, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">",
+            width, height, width, height
+        )?;
+        writeln!(
+            svg,
+            "<rect x=\"0\" y=\"0\" width=\"{}\" height=\"{}\" {}/>",
+            width,
+            height,
+            svg_paint("fill", config.background_color)
+        )?;
+        let cell_fill: String = svg_paint("fill", config.cell_color);
+        // Sorted so the same generation always gives the same document
+        let mut cells: Vec<FrameRect> = self.alive_cell_rects(config);
+        cells.sort_by_key(|cell| (cell.y, cell.x));
+        for cell in cells {
+            writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" {}/>",
+                cell.x, cell.y, cell.width, cell.height, cell_fill
+            )?;
+        }
+        if config.line_thickness > 0 {
+            let line_stroke: String = format!(
+                "{} stroke-width=\"{}\"",
+                svg_paint("stroke", config.line_color),
+                config.line_thickness
+            );
+            // The vertical lines come first, one between each pair of columns
+            let vertical_lines: usize = self.columns.saturating_sub(1) as usize;
+            for (index, line) in self
+                .grid_line_rects(config, width, height)
+                .into_iter()
+                .enumerate()
+            {
+                let (x1, y1, x2, y2) = line_endpoints(line, index < vertical_lines);
+                writeln!(
+                    svg,
+                    "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" {}/>",
+                    x1, y1, x2, y2, line_stroke
+                )?;
+            }
+        }
+        writeln!(svg, "</svg>")
+    }
+}
+
+/// Returns the attributes that paint a fill or stroke with the given RGBA color, adding an
+/// opacity attribute if the color is not opaque.
+fn svg_paint(attribute: &str, color: (u8, u8, u8, u8)) -> String {
+    let (red, green, blue, alpha) = color;
+    let paint: String = format!("{}=\"#{:02x}{:02x}{:02x}\"", attribute, red, green, blue);
+    if alpha < u8::MAX {
+        format!(
+            "{} {}-opacity=\"{:.3}\"",
+            paint,
+            attribute,
+            alpha as f64 / u8::MAX as f64
+        )
+    } else {
+        paint
+    }
+}
+
+/// Returns the endpoints `(x1, y1, x2, y2)` of the center line of a vertical or horizontal grid
+/// line rectangle, so a stroke as wide as the line's thickness covers the rectangle exactly.
+fn line_endpoints(line: FrameRect, vertical: bool) -> (f64, f64, f64, f64) {
+    let left: f64 = line.x as f64;
+    let top: f64 = line.y as f64;
+    let right: f64 = left + line.width as f64;
+    let bottom: f64 = top + line.height as f64;
+    if vertical {
+        let center: f64 = (left + right) / 2.0;
+        (center, top, center, bottom)
+    } else {
+        let center: f64 = (top + bottom) / 2.0;
+        (left, center, right, center)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::simulation::{RenderConfig, Simulation};
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// Builds a 6x8 simulation with a glider in its top-left corner.
+    fn build() -> Simulation {
+        SimulationBuilder::new()
+            .height(6)
+            .width(8)
+            .seed(
+                "-*------\
+                 --*-----\
+                 ***-----\
+                 --------\
+                 --------\
+                 --------",
+            )
+            .display(false)
+            .build()
+            .unwrap()
+    }
+
+    /// Returns the value of an attribute of an SVG element.
+    fn attribute<'a>(element: &'a str, name: &str) -> &'a str {
+        let start: usize = element.find(&format!(" {}=\"", name)).unwrap() + name.len() + 3;
+        let length: usize = element[start..].find('"').unwrap();
+        &element[start..start + length]
+    }
+
+    #[test]
+    fn svg_has_the_natural_size_and_a_rect_for_each_alive_cell() {
+        let simulation: Simulation = build();
+        let config: RenderConfig = RenderConfig {
+            cell_width: 7,
+            cell_height: 4,
+            cell_color: (255, 0, 0, 255),
+            background_color: (0, 0, 255, 255),
+            line_thickness: 1,
+            ..simulation.render_config()
+        };
+        let svg: String = simulation.render_to_svg(&config);
+
+        let root: &str = svg.lines().find(|line| line.starts_with("<svg")).unwrap();
+        assert_eq!(attribute(root, "width"), "56");
+        assert_eq!(attribute(root, "height"), "24");
+        assert_eq!(attribute(root, "viewBox"), "0 0 56 24");
+
+        let rects: Vec<&str> = svg
+            .lines()
+            .filter(|line| line.starts_with("<rect"))
+            .collect();
+        // The background comes first and covers the whole image
+        assert_eq!(attribute(rects[0], "fill"), "#0000ff");
+        assert_eq!(
+            (attribute(rects[0], "width"), attribute(rects[0], "height")),
+            ("56", "24")
+        );
+
+        let cells: HashSet<(String, String)> = rects[1..]
+            .iter()
+            .map(|rect| {
+                assert_eq!(attribute(rect, "fill"), "#ff0000");
+                assert_eq!(
+                    (attribute(rect, "width"), attribute(rect, "height")),
+                    ("7", "4")
+                );
+                (
+                    attribute(rect, "x").to_string(),
+                    attribute(rect, "y").to_string(),
+                )
+            })
+            .collect();
+        let expected: HashSet<(String, String)> = simulation
+            .alive_cells()
+            .map(|(row, column)| ((column * 7).to_string(), (row * 4).to_string()))
+            .collect();
+        assert_eq!(rects.len() - 1, 5);
+        assert_eq!(cells, expected);
+
+        // One grid line between each pair of columns and each pair of rows
+        assert_eq!(
+            svg.lines().filter(|line| line.starts_with("<line")).count(),
+            7 + 5
+        );
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn svg_draws_translucent_colors_and_no_lines_without_thickness() {
+        let simulation: Simulation = build();
+        let config: RenderConfig = RenderConfig {
+            cell_color: (255, 255, 255, 51),
+            line_thickness: 0,
+            ..simulation.render_config()
+        };
+        let svg: String = simulation.render_to_svg(&config);
+
+        let cell: &str = svg
+            .lines()
+            .filter(|line| line.starts_with("<rect"))
+            .nth(1)
+            .unwrap();
+        assert_eq!(attribute(cell, "fill"), "#ffffff");
+        assert_eq!(attribute(cell, "fill-opacity"), "0.200");
+        assert!(!svg.contains("<line"));
+    }
+}
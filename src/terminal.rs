@@ -0,0 +1,145 @@
+//! Printing generations to the terminal in color with ANSI escape codes.
+
+use std::env::var_os;
+
+use crate::cell::Cell;
+use crate::cell::CellState::ALIVE;
+use crate::simulation::{RenderConfig, Simulation};
+
+/// The escape code that resets every color and style.
+const ANSI_RESET: &str = "\x1b[0m";
+/// The escape code that makes text bold.
+const ANSI_BOLD: &str = "\x1b[1m";
+
+impl Simulation {
+    /// Prints the current generation to the console in color, using the simulation's cell and
+    /// background colors (see `print_generation_colored_with`).
+    pub fn print_generation_colored(&self) {
+        self.print_generation_colored_with(&self.render_config);
+    }
+
+    /// Prints the current generation to the console in color, using the given colors.
+    ///
+    /// # Description
+    /// The generation is printed like the simulation's `Display` output, with a header of
+    /// `"SEED"` or the iteration number, but the header is bold and each cell is printed as a
+    /// block of the configuration's cell color (if alive) or background color (if dead).
+    /// Cells are two characters wide, so they look roughly square in most terminals.
+    ///
+    /// If the terminal does not support colors, the generation is printed without them, the
+    /// same as with `println!("{}", simulation)`. Colors are not used if the `NO_COLOR`
+    /// environment variable is set or `TERM` is `"dumb"`, or on Windows if neither `TERM` nor
+    /// `WT_SESSION` (set by Windows Terminal) is set, since older Windows consoles do not
+    /// support escape codes.
+    ///
+    /// # Arguments
+    /// * `config` - The colors to print the generation with. Only the cell and background
+    ///   colors are used, and their alpha components are ignored.
+    pub fn print_generation_colored_with(&self, config: &RenderConfig) {
+        if terminal_supports_color() {
+            print!("{}", self.generation_string_colored(config));
+        } else {
+            println!("{}", self);
+        }
+    }
+
+    /// Returns the current generation with the ANSI escape codes that
+    /// `print_generation_colored_with` prints it with.
+    ///
+    /// # Arguments
+    /// * `config` - The colors to render the generation with. Only the cell and background
+    ///   colors are used, and their alpha components are ignored.
+    ///
+    /// # Returns
+    /// A `String` with a bold header line followed by one line per row of the grid.
+    pub fn generation_string_colored(&self, config: &RenderConfig) -> String {
+        let alive_block: String = ansi_block(config.cell_color);
+        let dead_block: String = ansi_block(config.background_color);
+        let header: String = if self.iteration == 0 {
+            String::from("SEED")
+        } else {
+            self.iteration.to_string()
+        };
+        let mut colored: String = format!("{}{}{}\n", ANSI_BOLD, header, ANSI_RESET);
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                colored.push_str(
+                    if self.generation.contains(&Cell::new(ALIVE, row, column)) {
+                        &alive_block
+                    } else {
+                        &dead_block
+                    },
+                );
+            }
+            colored.push_str(ANSI_RESET);
+            colored.push('\n');
+        }
+        colored
+    }
+}
+
+/// Returns a two character wide block with the given background color.
+fn ansi_block(color: (u8, u8, u8, u8)) -> String {
+    format!("\x1b[48;2;{};{};{}m  ", color.0, color.1, color.2)
+}
+
+/// Returns true if the terminal is expected to support ANSI colors.
+fn terminal_supports_color() -> bool {
+    if var_os("NO_COLOR").is_some() {
+        return false;
+    }
+    match var_os("TERM") {
+        Some(term) => term != "dumb",
+        None => !cfg!(windows) || var_os("WT_SESSION").is_some(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// Builds a 2x3 simulation with its top-left and bottom-right cells alive.
+    fn build() -> Simulation {
+        SimulationBuilder::new()
+            .height(2)
+            .width(3)
+            .seed("*----*")
+            .display(false)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn colored_generation_uses_exact_escape_codes() {
+        let simulation: Simulation = build();
+        let config: RenderConfig = RenderConfig {
+            cell_color: (255, 200, 0, 128),
+            background_color: (10, 20, 30, 255),
+            ..simulation.render_config()
+        };
+        let alive: &str = "\x1b[48;2;255;200;0m  ";
+        let dead: &str = "\x1b[48;2;10;20;30m  ";
+        let expected: String = format!(
+            "\x1b[1mSEED\x1b[0m\n{alive}{dead}{dead}\x1b[0m\n{dead}{dead}{alive}\x1b[0m\n",
+            alive = alive,
+            dead = dead
+        );
+        assert_eq!(simulation.generation_string_colored(&config), expected);
+    }
+
+    #[test]
+    fn every_line_of_a_colored_generation_ends_with_a_reset() {
+        let mut simulation: Simulation = build();
+        simulation.simulate_generations(3);
+        let colored: String = simulation.generation_string_colored(&simulation.render_config());
+        let lines: Vec<&str> = colored.lines().collect();
+        assert_eq!(lines[0], "\x1b[1m3\x1b[0m");
+        assert_eq!(lines.len(), 3);
+        for line in &lines[1..] {
+            assert!(line.ends_with(ANSI_RESET));
+            assert_eq!(line.matches("\x1b[48;2;").count(), 3);
+        }
+        assert!(colored.ends_with("\x1b[0m\n"));
+    }
+}
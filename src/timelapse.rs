@@ -0,0 +1,277 @@
+//! Time-lapse images that composite every generation of a run into a single picture.
+//!
+//! This module requires the `image` feature.
+//!
+//! # Example
+//! ```rust,no_run
+//! use std::path::Path;
+//! use simple_game_of_life::simulation::Simulation;
+//! use simple_game_of_life::simulation_builder::SimulationBuilder;
+//! use simple_game_of_life::timelapse::{TimelapseMode, TimelapseOptions};
+//!
+//! let mut simulation: Simulation = SimulationBuilder::new()
+//!     .height(60)
+//!     .width(60)
+//!     .surface_ball()
+//!     .seed_named("r-pentomino")
+//!     .build()
+//!     .unwrap();
+//!
+//! // A long exposure of the first 500 generations
+//! simulation
+//!     .export_timelapse_png(
+//!         Path::new("r-pentomino.png"),
+//!         500,
+//!         TimelapseMode::Additive,
+//!         &TimelapseOptions::default(),
+//!     )
+//!     .unwrap();
+//! ```
+
+use std::path::Path;
+
+use crate::raster::{Raster, Rgb};
+use crate::simulation::Simulation;
+
+/// Describes how the generations of a time-lapse are combined.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimelapseMode {
+    /// Each cell is colored by the number of generations it was alive in, like a long-exposure
+    /// photograph of the run.
+    Additive,
+    /// Each cell is colored by the last generation it was alive in, so the history of the run
+    /// appears as a temporal rainbow.
+    Latest,
+}
+
+/// The appearance of a time-lapse image.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimelapseOptions {
+    /// The width and height of each cell in pixels.
+    pub cell_size: u16,
+    /// The colors that cells are shaded with, from the lowest to the highest value. Colors
+    /// between these stops are interpolated.
+    pub gradient: Vec<(u8, u8, u8)>,
+    /// The color of cells that were never alive.
+    pub background_color: (u8, u8, u8),
+    /// The color of the grid lines between cells, or `None` to leave them out.
+    pub grid_color: Option<(u8, u8, u8)>,
+}
+
+impl Default for TimelapseOptions {
+    /// Defines the default appearance of a time-lapse image: 4x4 pixel cells without grid lines,
+    /// shaded from dark blue through cyan and yellow to white on a black background.
+    fn default() -> Self {
+        Self {
+            cell_size: 4,
+            gradient: vec![(0, 0, 128), (0, 160, 255), (255, 255, 0), (255, 255, 255)],
+            background_color: (0, 0, 0),
+            grid_color: None,
+        }
+    }
+}
+
+impl TimelapseOptions {
+    /// Returns the color of the gradient at the given position between 0 and 1.
+    fn gradient_color(&self, position: f64) -> Rgb {
+        match self.gradient.len() {
+            0 => (255, 255, 255),
+            1 => self.gradient[0],
+            stops => {
+                let scaled: f64 = position.clamp(0.0, 1.0) * (stops - 1) as f64;
+                let index: usize = (scaled.floor() as usize).min(stops - 2);
+                let fraction: f64 = scaled - index as f64;
+                let (from, to) = (self.gradient[index], self.gradient[index + 1]);
+                let mix =
+                    |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * fraction).round() as u8;
+                (mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+            }
+        }
+    }
+}
+
+impl Simulation {
+    /// Simulates the given number of generations and saves every generation, composited into a
+    /// single image, as a PNG file.
+    ///
+    /// # Description
+    /// The current generation and each of the simulated generations are combined according to
+    /// the mode:
+    ///
+    /// * `Additive` - Each cell is shaded by the number of generations it was alive in, relative
+    ///   to the cell that was alive the most.
+    /// * `Latest` - Each cell is shaded by the last generation it was alive in, relative to the
+    ///   last generation.
+    ///
+    /// Cells that were never alive are left as the background color. The image is
+    /// `columns * cell_size` pixels wide and `rows * cell_size` pixels high.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the PNG file to write.
+    /// * `generations` - The number of generations to simulate.
+    /// * `mode` - How the generations are combined.
+    /// * `options` - The appearance of the image.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the image was written.
+    /// * `Err(String)` - An error message if the cell size is 0 or the file could not be
+    ///   written.
+    pub fn export_timelapse_png(
+        &mut self,
+        path: &Path,
+        generations: u128,
+        mode: TimelapseMode,
+        options: &TimelapseOptions,
+    ) -> Result<(), String> {
+        if options.cell_size == 0 {
+            return Err(String::from(
+                "The cell size of a time-lapse must be at least 1",
+            ));
+        }
+        let columns: usize = self.columns as usize;
+        // The number of generations each cell was alive in, and the last one it was alive in
+        let mut alive_counts: Vec<u128> = vec![0; self.rows as usize * columns];
+        let mut last_alive: Vec<Option<u128>> = vec![None; self.rows as usize * columns];
+        for step in 0..=generations {
+            if step > 0 {
+                self.simulate_generation();
+            }
+            for cell in &self.generation {
+                let index: usize = cell.row as usize * columns + cell.column as usize;
+                alive_counts[index] += 1;
+                last_alive[index] = Some(step);
+            }
+        }
+        let maximum_count: u128 = alive_counts.iter().copied().max().unwrap_or(0);
+        let cell_size: u32 = options.cell_size as u32;
+        let mut raster: Raster = Raster::new(
+            self.columns as u32 * cell_size,
+            self.rows as u32 * cell_size,
+            options.background_color,
+        );
+        raster.draw_grid(
+            self.rows,
+            self.columns,
+            cell_size,
+            |row, column| {
+                let index: usize = row as usize * columns + column as usize;
+                let position: f64 = match mode {
+                    TimelapseMode::Additive if alive_counts[index] > 0 => {
+                        alive_counts[index] as f64 / maximum_count as f64
+                    }
+                    TimelapseMode::Latest => match last_alive[index] {
+                        Some(_) if generations == 0 => 1.0,
+                        Some(step) => step as f64 / generations as f64,
+                        None => return None,
+                    },
+                    _ => return None,
+                };
+                Some(options.gradient_color(position))
+            },
+            options.grid_color,
+        );
+        raster
+            .save_png(path)
+            .map_err(|error| format!("Could not write {}: {}", path.display(), error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_file;
+    use std::path::PathBuf;
+
+    use image::RgbImage;
+
+    use super::*;
+    use crate::simulation_builder::SimulationBuilder;
+
+    /// Exports a time-lapse of a blinker over 4 generations with 3x3 pixel cells and grid lines,
+    /// and returns the decoded image.
+    fn blinker_timelapse(mode: TimelapseMode) -> RgbImage {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(5)
+            .width(5)
+            .seed_named("blinker")
+            .display(false)
+            .build()
+            .unwrap();
+        let options: TimelapseOptions = TimelapseOptions {
+            cell_size: 3,
+            grid_color: Some((40, 40, 40)),
+            ..TimelapseOptions::default()
+        };
+        let path: PathBuf = temp_dir().join(format!(
+            "game_of_life_{}_timelapse_{:?}.png",
+            std::process::id(),
+            mode
+        ));
+        simulation
+            .export_timelapse_png(&path, 4, mode, &options)
+            .unwrap();
+        let image: RgbImage = image::open(&path).unwrap().to_rgb8();
+        remove_file(&path).unwrap();
+        image
+    }
+
+    /// Returns the color in the middle of the cell at the given row and column.
+    fn cell_color(image: &RgbImage, row: u32, column: u32) -> Rgb {
+        let [red, green, blue] = image.get_pixel(column * 3 + 1, row * 3 + 1).0;
+        (red, green, blue)
+    }
+
+    #[test]
+    fn additive_blinker_matches_the_golden_image() {
+        let image: RgbImage = blinker_timelapse(TimelapseMode::Additive);
+        let golden: RgbImage = image::open("golden/timelapse_blinker_additive.png")
+            .unwrap()
+            .to_rgb8();
+        assert_eq!(image, golden);
+
+        // The middle cell is alive in all 5 generations, the horizontal arms in 3, and the
+        // vertical arms in 2
+        assert_eq!(cell_color(&image, 2, 2), (255, 255, 255));
+        assert_eq!(cell_color(&image, 2, 1), (204, 236, 51));
+        assert_eq!(cell_color(&image, 1, 2), (51, 179, 204));
+        assert_eq!(cell_color(&image, 0, 0), (0, 0, 0));
+        assert_eq!(image.get_pixel(3, 1).0, [40, 40, 40]);
+    }
+
+    #[test]
+    fn latest_blinker_matches_the_golden_image() {
+        let image: RgbImage = blinker_timelapse(TimelapseMode::Latest);
+        let golden: RgbImage = image::open("golden/timelapse_blinker_latest.png")
+            .unwrap()
+            .to_rgb8();
+        assert_eq!(image, golden);
+
+        // The horizontal arms are last alive in generation 4, and the vertical arms in 3
+        assert_eq!(cell_color(&image, 2, 1), (255, 255, 255));
+        assert_eq!(cell_color(&image, 1, 2), (255, 255, 64));
+        assert_eq!(cell_color(&image, 0, 0), (0, 0, 0));
+    }
+
+    #[test]
+    fn timelapse_needs_a_cell_size() {
+        let mut simulation: Simulation = SimulationBuilder::new()
+            .height(5)
+            .width(5)
+            .seed_named("blinker")
+            .display(false)
+            .build()
+            .unwrap();
+        let options: TimelapseOptions = TimelapseOptions {
+            cell_size: 0,
+            ..TimelapseOptions::default()
+        };
+        assert!(simulation
+            .export_timelapse_png(
+                Path::new("unused.png"),
+                4,
+                TimelapseMode::Additive,
+                &options
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,391 @@
+//! Head-to-head evaluation of a fixed list of seeds under identical settings.
+//!
+//! # Example
+//! ```rust,no_run
+//! use simple_game_of_life::simulation::StagnationLimit;
+//! use simple_game_of_life::simulation_builder::SimulationBuilder;
+//! use simple_game_of_life::tournament::{run, TournamentConfig, TournamentReport};
+//!
+//! let seeds: Vec<String> = vec![
+//!     String::from("-*-------*--***----------"),
+//!     String::from("--**--**----*------------"),
+//! ];
+//! let config = TournamentConfig {
+//!     builder: SimulationBuilder::new().height(5).width(5).surface_ball(),
+//!     maximum_iterations: 1000,
+//!     stagnation_limit: Some(StagnationLimit {
+//!         window: 100,
+//!         tolerance: 0.05,
+//!     }),
+//! };
+//!
+//! let report: TournamentReport = run(seeds, config).unwrap();
+//! println!("{}", report.to_table());
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::patterns::{census_with, lexicon_patterns, PatternDef};
+use crate::simulation::{Simulation, StagnationLimit, StopReason};
+use crate::simulation_builder::SimulationBuilder;
+
+/// The settings every seed in a tournament is evaluated under.
+#[derive(Clone)]
+pub struct TournamentConfig {
+    /// The builder used to create the simulation for each seed. Its seed, display, and print
+    /// settings are replaced for every seed.
+    pub builder: SimulationBuilder,
+    /// The maximum number of generations to simulate for each seed.
+    pub maximum_iterations: u128,
+    /// The optional stagnation stop criterion (see `Simulation::run_to_completion`).
+    pub stagnation_limit: Option<StagnationLimit>,
+}
+
+/// The outcome of evaluating a single seed in a tournament.
+#[derive(Clone, Debug)]
+pub struct SeedResult {
+    /// The seed that was evaluated, as a single line of alive and dead characters (see
+    /// `Simulation::seed`).
+    pub seed: String,
+    /// The proportion of alive cells in the seed.
+    pub initial_density: f64,
+    /// The number of generations before the simulation entered its cycle, or the number of
+    /// generations simulated if it never did.
+    pub longevity: u128,
+    /// The reason the simulation stopped.
+    pub stop_reason: StopReason,
+    /// The number of alive cells in the final generation.
+    pub final_population: u64,
+    /// The period of the cycle the simulation entered, if it finished.
+    pub period: Option<u128>,
+    /// The number of objects of each pattern in the final generation (see `patterns::census`).
+    pub census: BTreeMap<String, u64>,
+}
+
+/// The results of a tournament, ranked from the longest to the shortest lasting seed.
+#[derive(Clone, Debug)]
+pub struct TournamentReport {
+    /// The result of every seed, ranked by longevity (ties keep the order the seeds were given
+    /// in).
+    pub results: Vec<SeedResult>,
+    /// The median longevity of all seeds.
+    pub median_longevity: f64,
+    /// The Pearson correlation between the initial density and the longevity of the seeds, or
+    /// `None` if either does not vary between seeds.
+    pub density_longevity_correlation: Option<f64>,
+}
+
+/// Evaluates every seed under identical settings and ranks them by longevity.
+///
+/// # Description
+/// For each seed, a simulation is built from the configured builder (with display and printing
+/// disabled) and run with `run_to_completion` until it finishes, stagnates, or reaches the
+/// maximum number of iterations. The longevity, final population, period, and census of the
+/// final generation are recorded for each seed, and the results are then ranked and
+/// summarized.
+///
+/// With the `parallel` feature, the seeds are evaluated in parallel on Rayon's thread pool.
+/// The results are the same either way.
+///
+/// # Arguments
+/// * `seeds` - The seed strings to evaluate.
+/// * `config` - The settings every seed is evaluated under.
+///
+/// # Returns
+/// * `Ok(TournamentReport)` - The ranked results and aggregate statistics.
+/// * `Err(String)` - An error message if no seeds were given or a simulation could not be
+///   built from one of the seeds.
+pub fn run(seeds: Vec<String>, config: TournamentConfig) -> Result<TournamentReport, String> {
+    if seeds.is_empty() {
+        return Err(String::from("A tournament needs at least one seed"));
+    }
+    let references: Vec<PatternDef> = lexicon_patterns();
+    // Both iterators keep the seeds in order, so ties are ranked the same way
+    #[cfg(feature = "parallel")]
+    let results: Result<Vec<SeedResult>, String> = seeds
+        .into_par_iter()
+        .map(|seed| evaluate(&seed, &config, &references))
+        .collect();
+    #[cfg(not(feature = "parallel"))]
+    let results: Result<Vec<SeedResult>, String> = seeds
+        .into_iter()
+        .map(|seed| evaluate(&seed, &config, &references))
+        .collect();
+    let mut results: Vec<SeedResult> = results?;
+    results.sort_by_key(|result| Reverse(result.longevity));
+    let densities: Vec<f64> = results
+        .iter()
+        .map(|result| result.initial_density)
+        .collect();
+    let longevities: Vec<f64> = results
+        .iter()
+        .map(|result| result.longevity as f64)
+        .collect();
+    let density_longevity_correlation: Option<f64> = correlation(&densities, &longevities);
+    // The results are ranked from the longest to the shortest lasting, so the longevities are
+    // already sorted (in descending order)
+    let middle: usize = longevities.len() / 2;
+    let median_longevity: f64 = if longevities.len().is_multiple_of(2) {
+        (longevities[middle - 1] + longevities[middle]) / 2.0
+    } else {
+        longevities[middle]
+    };
+    Ok(TournamentReport {
+        results,
+        median_longevity,
+        density_longevity_correlation,
+    })
+}
+
+/// Builds a simulation from a seed and runs it until it stops.
+///
+/// # Arguments
+/// * `seed` - The seed string to evaluate.
+/// * `config` - The settings the seed is evaluated under.
+/// * `references` - The patterns counted in the census of the final generation.
+///
+/// # Returns
+/// * `Ok(SeedResult)` - The outcome of the simulation.
+/// * `Err(String)` - An error message if a simulation could not be built from the seed.
+fn evaluate(
+    seed: &str,
+    config: &TournamentConfig,
+    references: &[PatternDef],
+) -> Result<SeedResult, String> {
+    let mut simulation: Simulation = config
+        .builder
+        .clone()
+        .seed(seed)
+        .display(false)
+        .print(false)
+        .build()?;
+    let initial_density: f64 = simulation.alive_proportion();
+    let stop_reason: StopReason =
+        simulation.run_to_completion(config.maximum_iterations, config.stagnation_limit);
+    let period: Option<u128> = match stop_reason {
+        StopReason::Finished => simulation.cycle_info().map(|cycle| cycle.period),
+        _ => None,
+    };
+    Ok(SeedResult {
+        seed: simulation.seed(),
+        initial_density,
+        longevity: simulation.iteration - period.unwrap_or(0),
+        stop_reason,
+        final_population: simulation.alive_count(),
+        period,
+        census: census_with(&simulation, references),
+    })
+}
+
+/// Returns the Pearson correlation between two equally long samples, or `None` if either
+/// sample has no variance.
+fn correlation(a: &[f64], b: &[f64]) -> Option<f64> {
+    let count: f64 = a.len() as f64;
+    let mean_a: f64 = a.iter().sum::<f64>() / count;
+    let mean_b: f64 = b.iter().sum::<f64>() / count;
+    let mut covariance: f64 = 0.0;
+    let mut variance_a: f64 = 0.0;
+    let mut variance_b: f64 = 0.0;
+    for (x, y) in a.iter().zip(b) {
+        covariance += (x - mean_a) * (y - mean_b);
+        variance_a += (x - mean_a).powi(2);
+        variance_b += (y - mean_b).powi(2);
+    }
+    if variance_a == 0.0 || variance_b == 0.0 {
+        return None;
+    }
+    Some(covariance / (variance_a * variance_b).sqrt())
+}
+
+impl StopReason {
+    /// Returns a short lowercase name for the stop reason, used when rendering reports.
+    fn name(&self) -> &'static str {
+        match self {
+            StopReason::Finished => "finished",
+            StopReason::MaximumIterations => "maximum iterations",
+            StopReason::Stagnant => "stagnant",
+            StopReason::PredicateSatisfied => "predicate satisfied",
+        }
+    }
+}
+
+impl SeedResult {
+    /// Renders the census as `name x count` entries separated by semicolons.
+    fn census_string(&self) -> String {
+        self.census
+            .iter()
+            .map(|(name, count)| format!("{} x{}", name, count))
+            .collect::<Vec<String>>()
+            .join("; ")
+    }
+}
+
+impl TournamentReport {
+    /// Renders the report as a ranked text table followed by the aggregate statistics.
+    pub fn to_table(&self) -> String {
+        let headers: [&str; 7] = [
+            "Rank",
+            "Density",
+            "Longevity",
+            "Stop Reason",
+            "Population",
+            "Period",
+            "Census",
+        ];
+        let rows: Vec<[String; 7]> = self
+            .results
+            .iter()
+            .enumerate()
+            .map(|(index, result)| {
+                [
+                    (index + 1).to_string(),
+                    format!("{:.3}", result.initial_density),
+                    result.longevity.to_string(),
+                    String::from(result.stop_reason.name()),
+                    result.final_population.to_string(),
+                    result
+                        .period
+                        .map_or(String::from("-"), |period| period.to_string()),
+                    result.census_string(),
+                ]
+            })
+            .collect();
+        let mut widths: [usize; 7] = headers.map(|header| header.len());
+        for row in &rows {
+            for (width, value) in widths.iter_mut().zip(row) {
+                *width = (*width).max(value.chars().count());
+            }
+        }
+        let format_row = |values: Vec<&str>| -> String {
+            values
+                .iter()
+                .zip(widths)
+                .map(|(value, width)| format!("{:<width$}", value, width = width))
+                .collect::<Vec<String>>()
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        };
+        let mut table: String = format_row(headers.to_vec()) + "\n";
+        table += &(widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<String>>()
+            .join("-+-")
+            + "\n");
+        for row in &rows {
+            table += &(format_row(row.iter().map(|value| value.as_str()).collect()) + "\n");
+        }
+        table += &format!("\nMedian longevity: {}\n", self.median_longevity);
+        table += &format!(
+            "Density/longevity correlation: {}\n",
+            self.density_longevity_correlation.map_or(
+                String::from("undefined"),
+                |correlation| format!("{:.3}", correlation)
+            )
+        );
+        table
+    }
+
+    /// Renders the ranked results as CSV, with a header row and one row per seed.
+    ///
+    /// # Note
+    /// The census column lists `name x count` entries separated by semicolons, and the period
+    /// column is empty for seeds that did not finish.
+    pub fn to_csv(&self) -> String {
+        let mut csv: String = String::from(
+            "rank,seed,initial_density,longevity,stop_reason,final_population,period,census\n",
+        );
+        for (index, result) in self.results.iter().enumerate() {
+            csv += &format!(
+                "{},{},{},{},{},{},{},{}\n",
+                index + 1,
+                result.seed,
+                result.initial_density,
+                result.longevity,
+                result.stop_reason.name(),
+                result.final_population,
+                result
+                    .period
+                    .map_or(String::new(), |period| period.to_string()),
+                result.census_string()
+            );
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A blinker written over several lines, as a user might paste it.
+    const BLINKER: &str = "-----\n--*--\n--*--\n--*--\n-----";
+    /// A block, which never changes.
+    const BLOCK: &str = "-----\n-**--\n-**--\n-----\n-----";
+    /// A single cell, which dies in the first generation.
+    const LONELY: &str = "-----\n-----\n--*--\n-----\n-----";
+    /// A diagonal of cells, which shrinks one generation at a time before dying.
+    const DIAGONAL: &str = "*----\n-*---\n--*--\n---*-\n-----";
+
+    fn config() -> TournamentConfig {
+        TournamentConfig {
+            builder: SimulationBuilder::new().height(5).width(5),
+            maximum_iterations: 100,
+            stagnation_limit: None,
+        }
+    }
+
+    #[test]
+    fn tournament_needs_a_seed() {
+        assert!(run(Vec::new(), config()).is_err());
+    }
+
+    #[test]
+    fn results_are_ranked_by_longevity() {
+        let seeds: Vec<String> = [BLOCK, LONELY, DIAGONAL, BLINKER]
+            .iter()
+            .map(|seed| seed.to_string())
+            .collect();
+        let report: TournamentReport = run(seeds, config()).unwrap();
+
+        let ranking: Vec<(u128, Option<u128>)> = report
+            .results
+            .iter()
+            .map(|result| (result.longevity, result.period))
+            .collect();
+        assert_eq!(
+            ranking,
+            vec![(2, Some(1)), (1, Some(1)), (0, Some(1)), (0, Some(2))]
+        );
+        // The block and the blinker tie, so they keep the order they were given in
+        assert_eq!(report.results[2].initial_density, 4.0 / 25.0);
+        assert_eq!(report.results[3].initial_density, 3.0 / 25.0);
+        assert_eq!(report.median_longevity, 0.5);
+    }
+
+    #[test]
+    fn seeds_are_stored_on_a_single_line() {
+        let report: TournamentReport = run(vec![BLINKER.to_string()], config()).unwrap();
+        assert_eq!(report.results[0].seed, "-------*----*----*-------");
+    }
+
+    #[test]
+    fn csv_has_one_row_per_seed() {
+        let seeds: Vec<String> = [BLOCK, LONELY, BLINKER]
+            .iter()
+            .map(|seed| seed.to_string())
+            .collect();
+        let csv: String = run(seeds, config()).unwrap().to_csv();
+
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        for line in lines {
+            assert_eq!(line.split(',').count(), 8, "{}", line);
+        }
+    }
+}
@@ -0,0 +1,418 @@
+//! Bit-exact replay verification against golden digests.
+//!
+//! A golden run simulates a documented set of canonical scenarios and records a chained hash of
+//! every generation. Comparing the digest of a build against a committed golden digest shows
+//! whether the build produces exactly the same generations, and if not, the first scenario and
+//! generation where it diverges. The golden digest of the reference engine is committed at
+//! `golden/reference_digest.txt` in the repository.
+//!
+//! # Example
+//! ```rust,no_run
+//! use std::path::Path;
+//! use simple_game_of_life::verification::check;
+//!
+//! match check(Path::new("golden/reference_digest.txt")).unwrap() {
+//!     None => println!("Every scenario matches the golden digest"),
+//!     Some(divergence) => println!(
+//!         "The scenario \"{}\" diverges at generation {}",
+//!         divergence.scenario, divergence.generation
+//!     ),
+//! }
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, write};
+use std::path::Path;
+
+use crate::cell::{ALIVE_CHAR, DEAD_CHAR};
+use crate::rule::Rule;
+use crate::simulation::Simulation;
+use crate::simulation_builder::SimulationBuilder;
+
+/// The first line of a digest file.
+const DIGEST_HEADER: &str = "# simple_game_of_life golden digest";
+/// The FNV-1a 64-bit offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+/// The FNV-1a 64-bit prime.
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// A single scenario of a golden run.
+#[derive(Clone)]
+pub struct Scenario {
+    /// The name that identifies the scenario in a digest. It can not contain whitespace.
+    pub name: String,
+    /// The builder used to create the scenario's simulation (its display and print settings
+    /// are disabled).
+    pub builder: SimulationBuilder,
+    /// The rule the scenario is simulated under.
+    pub rule: Rule,
+    /// The number of generations to simulate.
+    pub steps: u128,
+}
+
+/// The scenarios simulated by a golden run.
+#[derive(Clone)]
+pub struct GoldenConfig {
+    /// The scenarios, in the order they are simulated.
+    pub scenarios: Vec<Scenario>,
+}
+
+impl Default for GoldenConfig {
+    /// Defines the canonical scenarios the committed golden digest was produced from.
+    ///
+    /// # Description
+    /// The canonical scenarios cover every surface type, named patterns, fixed pseudo-random
+    /// soups, and a rule other than Conway's:
+    ///
+    /// 1. `r-pentomino-rectangle` - An R-pentomino on a 40x40 rectangle for 200 generations.
+    /// 2. `glider-ball` - A glider on a 12x12 ball for 100 generations.
+    /// 3. `acorn-horizontal-loop` - An acorn on a 30x50 horizontal loop for 200 generations.
+    /// 4. `diehard-vertical-loop` - A diehard on a 30x30 vertical loop for 150 generations.
+    /// 5. `soup-ball` - A 35% soup on a 32x32 ball for 300 generations.
+    /// 6. `soup-highlife-rectangle` - A 40% soup on a 24x24 rectangle under HighLife (B36/S23)
+    ///    for 150 generations.
+    fn default() -> Self {
+        Self {
+            scenarios: vec![
+                Scenario {
+                    name: String::from("r-pentomino-rectangle"),
+                    builder: SimulationBuilder::new()
+                        .height(40)
+                        .width(40)
+                        .surface_rectangle()
+                        .seed_named("r-pentomino"),
+                    rule: Rule::default(),
+                    steps: 200,
+                },
+                Scenario {
+                    name: String::from("glider-ball"),
+                    builder: SimulationBuilder::new()
+                        .height(12)
+                        .width(12)
+                        .surface_ball()
+                        .seed_named("glider"),
+                    rule: Rule::default(),
+                    steps: 100,
+                },
+                Scenario {
+                    name: String::from("acorn-horizontal-loop"),
+                    builder: SimulationBuilder::new()
+                        .height(30)
+                        .width(50)
+                        .surface_horizontal_loop()
+                        .seed_named("acorn"),
+                    rule: Rule::default(),
+                    steps: 200,
+                },
+                Scenario {
+                    name: String::from("diehard-vertical-loop"),
+                    builder: SimulationBuilder::new()
+                        .height(30)
+                        .width(30)
+                        .surface_vertical_loop()
+                        .seed_named("diehard"),
+                    rule: Rule::default(),
+                    steps: 150,
+                },
+                Scenario {
+                    name: String::from("soup-ball"),
+                    builder: SimulationBuilder::new()
+                        .height(32)
+                        .width(32)
+                        .surface_ball()
+                        .seed(&fixed_soup(32, 32, 35, 1)),
+                    rule: Rule::default(),
+                    steps: 300,
+                },
+                Scenario {
+                    name: String::from("soup-highlife-rectangle"),
+                    builder: SimulationBuilder::new()
+                        .height(24)
+                        .width(24)
+                        .surface_rectangle()
+                        .seed(&fixed_soup(24, 24, 40, 2)),
+                    rule: Rule::HIGHLIFE,
+                    steps: 150,
+                },
+            ],
+        }
+    }
+}
+
+/// The chained generation hashes of a single scenario.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScenarioDigest {
+    /// The name of the scenario.
+    pub name: String,
+    /// The chained hash of every generation, starting with the seed generation.
+    pub hashes: Vec<u64>,
+}
+
+/// The chained generation hashes of every scenario of a golden run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GoldenDigest {
+    /// The digest of every scenario, in the order they were simulated.
+    pub scenarios: Vec<ScenarioDigest>,
+}
+
+/// The first place where two golden digests differ.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Divergence {
+    /// The name of the first scenario that differs.
+    pub scenario: String,
+    /// The first generation of the scenario that differs.
+    pub generation: u128,
+}
+
+impl Display for GoldenDigest {
+    /// Renders the digest in the digest file format.
+    ///
+    /// # Description
+    /// The first line is a header comment, followed by one line per scenario containing the
+    /// scenario's name and the hexadecimal hash of every generation, separated by spaces.
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", DIGEST_HEADER)?;
+        for scenario in &self.scenarios {
+            write!(f, "{}", scenario.name)?;
+            for hash in &scenario.hashes {
+                write!(f, " {:016x}", hash)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl GoldenDigest {
+    /// Parses a digest from the digest file format (see the `Display` implementation).
+    ///
+    /// # Returns
+    /// * `Ok(GoldenDigest)` - The parsed digest.
+    /// * `Err(String)` - An error message if a line can not be parsed.
+    pub fn parse(text: &str) -> Result<GoldenDigest, String> {
+        let mut scenarios: Vec<ScenarioDigest> = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line: &str = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let name: String = String::from(fields.next().unwrap());
+            let hashes: Vec<u64> = fields
+                .map(|field| u64::from_str_radix(field, 16))
+                .collect::<Result<Vec<u64>, _>>()
+                .map_err(|_| format!("Invalid hash on line {} of the digest", index + 1))?;
+            scenarios.push(ScenarioDigest { name, hashes });
+        }
+        Ok(GoldenDigest { scenarios })
+    }
+
+    /// Reads a digest from a digest file.
+    pub fn load(path: &Path) -> Result<GoldenDigest, String> {
+        let text: String = read_to_string(path)
+            .map_err(|error| format!("Could not read {}: {}", path.display(), error))?;
+        GoldenDigest::parse(&text)
+    }
+
+    /// Writes the digest to a digest file.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        write(path, self.to_string())
+            .map_err(|error| format!("Could not write {}: {}", path.display(), error))
+    }
+
+    /// Finds the first place where this digest differs from the expected digest.
+    ///
+    /// # Description
+    /// Scenarios are compared in the order of the expected digest. A scenario that is missing
+    /// from this digest diverges at generation 0, and a scenario with fewer or more generations
+    /// diverges at the first generation that only one of the digests has.
+    ///
+    /// # Returns
+    /// The first `Divergence`, or `None` if the digests are identical.
+    pub fn first_divergence(&self, expected: &GoldenDigest) -> Option<Divergence> {
+        for expected_scenario in &expected.scenarios {
+            let divergence = |generation: usize| {
+                Some(Divergence {
+                    scenario: expected_scenario.name.clone(),
+                    generation: generation as u128,
+                })
+            };
+            let actual_scenario: &ScenarioDigest = match self
+                .scenarios
+                .iter()
+                .find(|scenario| scenario.name == expected_scenario.name)
+            {
+                Some(scenario) => scenario,
+                None => return divergence(0),
+            };
+            let actual: &Vec<u64> = &actual_scenario.hashes;
+            let expected: &Vec<u64> = &expected_scenario.hashes;
+            if let Some(generation) = (0..actual.len().max(expected.len()))
+                .find(|generation| actual.get(*generation) != expected.get(*generation))
+            {
+                return divergence(generation);
+            }
+        }
+        self.scenarios
+            .iter()
+            .find(|scenario| {
+                !expected
+                    .scenarios
+                    .iter()
+                    .any(|expected_scenario| expected_scenario.name == scenario.name)
+            })
+            .map(|scenario| Divergence {
+                scenario: scenario.name.clone(),
+                generation: 0,
+            })
+    }
+}
+
+/// Simulates every scenario and records the chained hash of every generation.
+///
+/// # Description
+/// The hash of each generation is an FNV-1a hash of the previous generation's hash followed by
+/// the grid's dimensions and the coordinates of every alive cell in row-major order, so a
+/// difference in any generation changes the hash of every later generation. FNV-1a is used
+/// because, unlike the standard library's hasher, its output is guaranteed to be the same on
+/// every platform and Rust version.
+///
+/// # Arguments
+/// * `config` - The scenarios to simulate (`GoldenConfig::default()` for the canonical ones).
+///
+/// # Returns
+/// * `Ok(GoldenDigest)` - The digest of every scenario.
+/// * `Err(String)` - An error message if a scenario could not be built or its name contains
+///   whitespace.
+pub fn golden_run(config: GoldenConfig) -> Result<GoldenDigest, String> {
+    let mut scenarios: Vec<ScenarioDigest> = Vec::new();
+    for scenario in config.scenarios {
+        if scenario.name.is_empty() || scenario.name.contains(char::is_whitespace) {
+            return Err(format!(
+                "The scenario name \"{}\" must be non-empty and can not contain whitespace",
+                scenario.name
+            ));
+        }
+        let mut simulation: Simulation = scenario.builder.display(false).print(false).build()?;
+        simulation.rule = scenario.rule;
+        let mut hashes: Vec<u64> = vec![generation_hash(FNV_OFFSET_BASIS, &simulation)];
+        for _ in 0..scenario.steps {
+            simulation.simulate_generation();
+            hashes.push(generation_hash(hashes[hashes.len() - 1], &simulation));
+        }
+        scenarios.push(ScenarioDigest {
+            name: scenario.name,
+            hashes,
+        });
+    }
+    Ok(GoldenDigest { scenarios })
+}
+
+/// Runs the canonical scenarios and compares them against a golden digest file.
+///
+/// # Arguments
+/// * `digest_file` - The path to the golden digest file.
+///
+/// # Returns
+/// * `Ok(None)` - If every scenario matches the golden digest.
+/// * `Ok(Some(Divergence))` - The first scenario and generation that differs.
+/// * `Err(String)` - An error message if the digest file could not be read or parsed.
+pub fn check(digest_file: &Path) -> Result<Option<Divergence>, String> {
+    let expected: GoldenDigest = GoldenDigest::load(digest_file)?;
+    Ok(golden_run(GoldenConfig::default())?.first_divergence(&expected))
+}
+
+/// Returns the chained hash of a simulation's current generation.
+fn generation_hash(previous_hash: u64, simulation: &Simulation) -> u64 {
+    let mut cells: Vec<(u16, u16)> = simulation
+        .generation
+        .iter()
+        .map(|cell| (cell.row, cell.column))
+        .collect();
+    cells.sort();
+    let mut hash: u64 = FNV_OFFSET_BASIS;
+    let mut feed = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    };
+    feed(&previous_hash.to_le_bytes());
+    feed(&simulation.rows.to_le_bytes());
+    feed(&simulation.columns.to_le_bytes());
+    for (row, column) in cells {
+        feed(&row.to_le_bytes());
+        feed(&column.to_le_bytes());
+    }
+    hash
+}
+
+/// Returns a pseudo-random seed string that is the same on every platform.
+///
+/// # Description
+/// The `rand` crate does not guarantee that its generators produce the same values between
+/// versions, so the soups of the canonical scenarios come from a fixed xorshift generator
+/// instead.
+///
+/// # Arguments
+/// * `rows` - The number of rows in the seed.
+/// * `columns` - The number of columns in the seed.
+/// * `percent_alive` - The chance (out of 100) that each cell is alive.
+/// * `state` - The initial state of the generator, which must be non-zero.
+fn fixed_soup(rows: u16, columns: u16, percent_alive: u64, mut state: u64) -> String {
+    (0..rows as usize * columns as usize)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state % 100 < percent_alive {
+                ALIVE_CHAR
+            } else {
+                DEAD_CHAR
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_engine_matches_the_golden_digest() {
+        assert_eq!(check(Path::new("golden/reference_digest.txt")), Ok(None));
+    }
+
+    #[test]
+    fn digests_survive_a_round_trip_through_the_file_format() {
+        let digest: GoldenDigest = golden_run(GoldenConfig::default()).unwrap();
+        assert_eq!(GoldenDigest::parse(&digest.to_string()), Ok(digest));
+    }
+
+    #[test]
+    fn first_divergent_scenario_and_generation_are_reported() {
+        let expected: GoldenDigest =
+            GoldenDigest::load(Path::new("golden/reference_digest.txt")).unwrap();
+        let mut actual: GoldenDigest = expected.clone();
+        actual.scenarios[1].hashes[7] ^= 1;
+        actual.scenarios[2].hashes[3] ^= 1;
+        assert_eq!(
+            actual.first_divergence(&expected),
+            Some(Divergence {
+                scenario: expected.scenarios[1].name.clone(),
+                generation: 7,
+            })
+        );
+
+        // A missing scenario diverges at its seed generation
+        actual = expected.clone();
+        let missing: ScenarioDigest = actual.scenarios.remove(0);
+        assert_eq!(
+            actual.first_divergence(&expected),
+            Some(Divergence {
+                scenario: missing.name,
+                generation: 0,
+            })
+        );
+    }
+}